@@ -1,5 +1,8 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use destroyer::wipe::{Buffers, ProgressTracker, pass_zeros};
+use destroyer::rng::Seed;
+use destroyer::wipe::{
+    Buffers, ProgressTracker, default_generators, pass_random_seeded, pass_zeros,
+};
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
@@ -49,7 +52,48 @@ fn bench_pass_zeros(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_pass_zeros);
+/// Случайный проход: генерация в потоке записи (0) против конвейера.
+fn bench_pass_random(c: &mut Criterion) {
+    const FILE_SIZE: u64 = 32 * 1024 * 1024;
+    const BUF: usize = 256 * 1024;
+    let seed: Seed = Seed::from_hex("42").expect("seed");
+    let mut group = c.benchmark_group("pass_random");
+    group.throughput(criterion::Throughput::Bytes(FILE_SIZE));
+    let mut counts: Vec<usize> = vec![0, 1, default_generators()];
+    counts.dedup();
+    for generators in counts {
+        group.bench_with_input(
+            BenchmarkId::new("generators", generators),
+            &generators,
+            |b, &generators| {
+                let tmp = TempFile::new(FILE_SIZE);
+                let path: PathBuf = tmp.path().to_path_buf();
+                let mut f: File = File::options().read(true).write(true).open(&path).unwrap();
+                let mut buffers = Buffers::new(BUF, false, 4096)
+                    .expect("buffers")
+                    .with_generators(generators);
+                b.iter(|| {
+                    let mut progress = ProgressTracker::new(1, FILE_SIZE, true);
+                    pass_random_seeded(
+                        &mut f,
+                        FILE_SIZE,
+                        false,
+                        4096,
+                        path.to_str().unwrap(),
+                        &mut progress,
+                        &mut buffers,
+                        None,
+                        &seed,
+                    )
+                    .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_pass_zeros, bench_pass_random);
 criterion_main!(benches);
 
 struct TempFile {
@@ -1,14 +1,51 @@
-use crate::args::Config;
-use crate::dev::{
-    BlockSizes, SyncMode, choose_buffer_size, get_block_sizes, get_device_size_bytes,
-    open_device_writable,
+use crate::args::{ArgsError, Config, VerifyReportArgs};
+use crate::batch::{BatchDryRun, BatchJob, BatchOutcome, PreparedBatch, WipeBatch};
+use crate::confirm::{Target, ask, stdin_is_tty};
+use crate::dev::BlockSizes;
+use crate::ed25519::SigningKey;
+use crate::error::{EXIT_UNTRUSTED_KEY, Interruption, Refusal, WipeError};
+use crate::estimate::WipeEstimate;
+use crate::events::{DeviceEvents, EventStream, ProgressFormat};
+use crate::extents::ExtentSet;
+use crate::i18n::{self, Lang, Message, tr, trf};
+use crate::inuse::Usage;
+use crate::journal::{self, Journal};
+use crate::multi::{Board, BoardRow, RowState};
+use crate::partition::{Partition, PartitionTable};
+use crate::progress::{
+    PauseNoticeSink, Phase, ProgressEvent, ProgressSink, ProgressUpdate, TerminalSink, status_line,
 };
-use crate::wipe::{Buffers, ProgressTracker, pass_random, pass_zeros};
-use std::fs::File;
-use std::io::{Seek, SeekFrom};
+use crate::report::{
+    self, VerifyOutcome, load_public_key, load_signing_key, to_hex, verify_document,
+};
+use crate::signals::{self, Signals};
+use crate::verify::VerifyReport;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::wipe::DEFAULT_QUEUE_DEPTH;
+use crate::wipe::{PassData, default_generators};
+use crate::wiper::{DevicePlan, DryRun, JournalOutcome, SavedReport, WipeJob, WipeOutcome, Wiper};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::mpsc::{RecvTimeoutError, channel};
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
+/// Сколько участков показывать в `--list-partitions`.
+const LIST_MAX_EXTENTS: usize = 32;
+
+/// Сколько участков перечислять в сводке после остановки сигналом.
+const INTERRUPT_MAX_RANGES: usize = 8;
+
+/// Как часто проверять запрос состояния (SIGUSR1), пока событий нет.
+const STATUS_POLL: Duration = Duration::from_millis(200);
+
+/// Как часто проверять рабочие потоки при стирании нескольких устройств.
+const MULTI_POLL: Duration = Duration::from_millis(200);
+/// Перерисовка общей таблицы в терминале.
+const MULTI_REDRAW_TTY: Duration = Duration::from_secs(1);
+/// Вывод таблицы целиком, если stdout не терминал (лог, journald).
+const MULTI_REDRAW_LOG: Duration = Duration::from_secs(30);
+
 /// Операционная система, под которую собрана программа.
 #[derive(Clone, Copy, Debug)]
 pub enum Platform {
@@ -27,140 +64,946 @@ impl Platform {
 
 /// Точка входа для платформенного раннера.
 pub fn run(platform: Platform) {
-    let cfg: Config = Config::parse(std::env::args_os());
+    // Язык нужен раньше разбора аргументов: ошибки разбора тоже переводятся.
+    i18n::set_lang(Lang::detect(std::env::args_os().skip(1)));
+    if std::env::args_os()
+        .nth(1)
+        .is_some_and(|a| a == "verify-report")
+    {
+        let args: VerifyReportArgs = VerifyReportArgs::parse(std::env::args_os().skip(2))
+            .unwrap_or_else(|e| exit_on_args(e));
+        std::process::exit(verify_report(&args));
+    }
+    let cfg: Config = Config::parse(std::env::args_os()).unwrap_or_else(|e| exit_on_args(e));
     execute(cfg, platform);
 }
 
-fn execute(cfg: Config, platform: Platform) {
+/// Ошибка разбора аргументов: справка — код 0, остальное — код 1.
+fn exit_on_args(e: ArgsError) -> ! {
+    eprintln!("{e}");
+    std::process::exit(match e {
+        ArgsError::Help(_) => 0,
+        ArgsError::Invalid(_) => 1,
+    });
+}
+
+fn execute(mut cfg: Config, platform: Platform) {
     let started = Instant::now();
-    println!("Платформа: {}", platform.name());
+    // До первого println: в режиме событий stdout забирается под JSON.
+    let stream: Option<EventStream> = open_event_stream(&cfg);
+    println!("{}", trf("app.platform", &[&platform.name()]));
+    // Ключ читаем до стирания: ошибка в нём не должна оставить диск без отчёта.
+    let signer: Option<Arc<SigningKey>> = cfg.sign_key.as_ref().map(|path| {
+        let key: SigningKey = load_signing_key(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("{}", trf("app.sign_key_failed", &[path, &e]));
+            std::process::exit(1);
+        });
+        Arc::new(key)
+    });
+    if cfg.devices.len() > 1 {
+        execute_many(&cfg, stream, signer.as_ref());
+        return;
+    }
 
-    let device_size: u64 = match get_device_size_bytes(&cfg.device_path) {
-        Ok(s) => s,
-        Err(e) => {
-            if let Some(code) = e.raw_os_error() {
-                if code == libc::EBUSY {
-                    busy_help(&cfg.device_path);
-                }
-            }
-            eprintln!("Ошибка определения размера устройства: {e}");
+    let resumed: Option<Journal> = cfg.resume.clone().map(|path| {
+        let journal: Journal = Journal::load(Path::new(&path)).unwrap_or_else(|e| {
+            eprintln!("{}", trf("app.journal_read_failed", &[&path, &e]));
             std::process::exit(1);
+        });
+        if cfg.device_path.is_empty() {
+            cfg.device_path = journal.device_path.clone();
         }
-    };
-
-    let bs: BlockSizes = get_block_sizes(&cfg.device_path).unwrap_or_else(|_| BlockSizes {
-        logical: 512,
-        physical: 4096,
+        journal
     });
-    let buf_size: usize = choose_buffer_size(bs, cfg.buf_size);
-    let sector: usize = bs.sector() as usize;
-    let use_direct: bool = cfg.mode.is_direct();
+
+    let mut wiper: Wiper = wiper_for(
+        &cfg,
+        &cfg.device_path,
+        false,
+        stream.as_ref(),
+        signer.as_ref(),
+    );
+    if let Some(journal) = &resumed {
+        wiper = wiper.resume(journal.clone());
+    }
+    let dp: DevicePlan = wiper
+        .prepare()
+        .unwrap_or_else(|f| exit_on_failure(&cfg.device_path, f));
+    if let Some(table) = dp.table()
+        && cfg.list_partitions
+    {
+        print_partitions(table, dp.partition(), dp.extents(), dp.device_size());
+        return;
+    }
 
     println!(
-        "Размер устройства: {} байт ({:.2} GB)",
-        device_size,
-        device_size as f64 / (1024.0 * 1024.0 * 1024.0)
+        "{}",
+        trf(
+            "app.device_size",
+            &[&dp.device_size(), &format_gb(dp.device_size())]
+        )
     );
+    if let Some(identity) = dp.identity() {
+        for line in identity.lines() {
+            println!("{line}");
+        }
+    }
+    match (cfg.scheme, &resumed) {
+        (_, Some(journal)) => println!(
+            "{}",
+            trf(
+                "app.resuming",
+                &[&journal.pass, &dp.plan().len(), &journal.offset]
+            )
+        ),
+        (Some(scheme), None) => {
+            println!(
+                "{}",
+                trf("app.scheme", &[&scheme.title(), &dp.plan().len()])
+            )
+        }
+        (None, None) => println!("{}", trf("app.running_passes", &[&dp.plan().len()])),
+    }
+    println!("{}", trf("app.plan", &[&dp.plan().spec()]));
+    if let Some(p) = dp.partition() {
+        println!("{}", trf("app.partition", &[&describe_partition(p)]));
+    }
+    if cfg.unallocated {
+        println!("{}", tr("app.unallocated_only"));
+    }
+    if !dp.extents().is_full(dp.device_size()) {
+        println!("{}", trf("app.extents", &[&describe_extents(dp.extents())]));
+    }
+    println!("{}", trf("app.mode", &[&cfg.mode.label()]));
+    if resumed.is_none()
+        && let Some(seed) = cfg.seed
+    {
+        println!("{}", trf("app.seed", &[&seed.to_hex()]));
+    }
+    let bs: BlockSizes = dp.block_sizes();
     println!(
-        "Выполняется {} проходов очистки (последний — нулями)...",
-        cfg.passes
+        "{}",
+        trf("app.blocks", &[&bs.logical, &bs.physical, &dp.buf_size()])
+    );
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    if cfg.mode.is_direct() {
+        match cfg.queue_depth.unwrap_or(DEFAULT_QUEUE_DEPTH) {
+            0 => println!("{}", tr("app.uring_off")),
+            n => println!("{}", trf("app.uring_depth", &[&n])),
+        }
+    }
+    let has_random: bool = dp
+        .plan()
+        .resolve(&dp.seed())
+        .iter()
+        .any(|d| matches!(d, PassData::Random { .. }));
+    if has_random {
+        match cfg.gen_threads.unwrap_or_else(default_generators) {
+            0 => println!("{}", tr("app.gen_inline")),
+            n => println!("{}", trf("app.gen_threads", &[&n])),
+        }
+    }
+    if cfg.dry_run {
+        let dry: DryRun = wiper
+            .estimate(&dp)
+            .unwrap_or_else(|f| exit_on_failure(dp.device_path(), f));
+        for line in dry_run_lines(&dp, &dry) {
+            println!("{line}");
+        }
+        return;
+    }
+    if let Some(path) = cfg.journal.as_ref().or(cfg.resume.as_ref()) {
+        println!("{}", trf("app.journal", &[path]));
+    }
+    for u in dp.in_use() {
+        println!("{}", trf("app.in_use_forced", &[&u.device, &u.reason]));
+    }
+    println!("{}", tr("app.warning_destroy"));
+    confirm_or_exit(
+        &cfg,
+        &[Target {
+            device_path: dp.device_path(),
+            fingerprint: dp.fingerprint(),
+        }],
     );
-    println!("Режим: {}", cfg.mode.label());
+
+    let stop: Arc<Signals> = Arc::new(Signals::default());
+    if let Err(e) = signals::install(&stop) {
+        eprintln!("{}", trf("app.signals_failed", &[&e]));
+    }
+    let (tx, rx) = channel::<ProgressEvent>();
+    let job: WipeJob = wiper
+        .signals(Arc::clone(&stop))
+        .progress_events(tx)
+        .start_planned(dp.clone())
+        .unwrap_or_else(|f| exit_on_failure(dp.device_path(), f));
+    let mut printer = PassPrinter::new(&cfg, &dp);
+    loop {
+        match rx.recv_timeout(STATUS_POLL) {
+            Ok(event) => printer.event(&event),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        if let Some(u) = &printer.last
+            && stop.take_status_request()
+        {
+            eprintln!("\n{}", status_line(u));
+        }
+    }
+    let outcome: WipeOutcome = job.finish();
+    let code: i32 = outcome.exit_code();
+    if let Some(report) = &outcome.report {
+        print_report(report);
+    }
+    match outcome.result {
+        Err(f @ WipeError::Cancelled(_)) => {
+            let WipeError::Cancelled(i) = &f else {
+                unreachable!()
+            };
+            eprintln!(
+                "\n{}",
+                trf("app.interrupted", &[&signals::signal_name(i.signal)])
+            );
+            for line in interruption_lines(&dp, i) {
+                eprintln!("  {line}");
+            }
+            for line in journal_lines(outcome.journal.as_ref()) {
+                eprintln!("{line}");
+            }
+            std::process::exit(code);
+        }
+        Err(f @ WipeError::Io { pass: Some(_), .. })
+            if matches!(outcome.journal, Some(JournalOutcome::Saved(_))) =>
+        {
+            if let Some(JournalOutcome::Saved(path)) = &outcome.journal {
+                eprintln!("{f}");
+                eprintln!("{}", trf("app.resume_hint", &[&path.display()]));
+            }
+            std::process::exit(code);
+        }
+        Err(f) => exit_on_failure(dp.device_path(), f),
+        Ok(_) => {}
+    }
+    if let Some(JournalOutcome::Failed(msg)) = &outcome.journal {
+        eprintln!("{msg}");
+    }
     println!(
-        "Блоки: logical = {}B, physical = {}B; выбран буфер = {}B",
-        bs.logical, bs.physical, buf_size
+        "\n{}",
+        trf(
+            "app.wiped",
+            &[&dp.device_path(), &format_duration(started.elapsed())]
+        )
     );
-    println!("ВНИМАНИЕ: все данные на устройстве будут уничтожены!");
-    println!("Для отмены нажмите Ctrl+C в течение 5 секунд...");
-    sleep(Duration::from_secs(5));
+    if code != 0 {
+        std::process::exit(code);
+    }
+}
 
-    let mut progress: ProgressTracker = ProgressTracker::new(cfg.passes, device_size, cfg.quiet);
-    let mut buffers = Buffers::new(buf_size, use_direct, sector).unwrap_or_else(|e| {
-        eprintln!("Не удалось подготовить буфер записи: {e}");
-        std::process::exit(1);
-    });
-    let mut main_handle: File = open_device(&cfg, cfg.mode);
-    #[cfg(feature = "direct")]
-    let mut tail_handle: Option<File> = if use_direct {
-        Some(open_device(&cfg, SyncMode::Fast))
+/// `Wiper` с параметрами командной строки для одного из устройств `cfg`.
+/// При нескольких устройствах отчёт каждого — в свой файл (`report_path`).
+fn wiper_for(
+    cfg: &Config,
+    device_path: &str,
+    many: bool,
+    stream: Option<&EventStream>,
+    signer: Option<&Arc<SigningKey>>,
+) -> Wiper {
+    let mut wiper: Wiper = Wiper::new(device_path)
+        .plan(cfg.plan.clone())
+        .mode(cfg.mode)
+        .verify(cfg.verify)
+        .verify_each_pass(cfg.verify_each_pass)
+        .list_partitions(cfg.list_partitions)
+        .unallocated(cfg.unallocated)
+        .force(cfg.force)
+        .allow_system_disk(cfg.allow_system_disk)
+        .dry_run(cfg.dry_run)
+        .cancel_journal(&journal::default_path(device_path));
+    for range in &cfg.ranges {
+        wiper = wiper.range(*range);
+    }
+    for range in &cfg.excludes {
+        wiper = wiper.exclude(*range);
+    }
+    if let Some(selector) = &cfg.partition {
+        wiper = wiper.partition(selector.clone());
+    }
+    if let Some(seed) = cfg.seed {
+        wiper = wiper.seed(seed);
+    }
+    if let Some(bytes) = cfg.buf_size {
+        wiper = wiper.buf_size(bytes);
+    }
+    if let Some(threads) = cfg.gen_threads {
+        wiper = wiper.gen_threads(threads);
+    }
+    if let Some(depth) = cfg.queue_depth {
+        wiper = wiper.queue_depth(depth);
+    }
+    if let Some(path) = &cfg.pause_file {
+        wiper = wiper.pause_file(path);
+    }
+    if let Some(path) = cfg.journal.as_ref().or(cfg.resume.as_ref()) {
+        wiper = wiper.checkpoint(Path::new(path));
+    }
+    if let Some(base) = &cfg.report {
+        wiper = wiper.report(&report::report_path(Path::new(base), device_path, many));
+    }
+    if let Some(key) = signer {
+        wiper = wiper.sign_report(Arc::clone(key));
+    }
+    if let Some(name) = &cfg.operator {
+        wiper = wiper.operator(name);
+    }
+    if let Some(scheme) = cfg.scheme {
+        wiper = wiper.scheme(scheme);
+    }
+    if let Some(stream) = stream {
+        wiper = wiper.events(DeviceEvents::new(stream.clone(), device_path));
+    }
+    wiper
+}
+
+/// Вывод стирания одного устройства по событиям `Wiper`: заголовки проходов
+/// и проверок, строка статуса (с `--quiet` — только пауза).
+struct PassPrinter<'a> {
+    dp: &'a DevicePlan,
+    passes: Vec<PassData>,
+    /// Печатать сид прохода: он задан явно или нужен для разбора расхождений.
+    show_seed: bool,
+    sink: Box<dyn ProgressSink>,
+    /// Последнее состояние — для SIGUSR1.
+    last: Option<ProgressUpdate>,
+}
+
+impl<'a> PassPrinter<'a> {
+    fn new(cfg: &Config, dp: &'a DevicePlan) -> Self {
+        let sink: Box<dyn ProgressSink> = if cfg.quiet {
+            Box::new(PauseNoticeSink)
+        } else {
+            Box::new(TerminalSink::new())
+        };
+        Self {
+            dp,
+            passes: dp.plan().resolve(&dp.seed()),
+            show_seed: cfg.seed.is_some() || cfg.verify_each_pass,
+            sink,
+            last: None,
+        }
+    }
+
+    fn event(&mut self, event: &ProgressEvent) {
+        match event {
+            ProgressEvent::PassStart {
+                phase: Phase::Write,
+                pass,
+                total_passes,
+                ..
+            } => {
+                let data: &PassData = &self.passes[pass - 1];
+                let key: &str = if pass == total_passes && *total_passes > 1 {
+                    "app.final_pass"
+                } else {
+                    "app.pass"
+                };
+                println!("\n{}", trf(key, &[pass, total_passes, &data.describe()]));
+                if let PassData::Random { seed, .. } = data
+                    && self.show_seed
+                {
+                    println!("{}", trf("app.pass_seed", &[&seed.to_hex()]));
+                }
+                if *pass == self.dp.first_pass() && self.dp.first_offset() > 0 {
+                    println!("{}", trf("app.resuming_at", &[&self.dp.first_offset()]));
+                }
+            }
+            ProgressEvent::PassStart {
+                phase: Phase::Verify,
+                ..
+            } => println!("\n{}", tr("app.verify_start")),
+            ProgressEvent::Verified {
+                pass,
+                outcome: VerifyOutcome::Skipped,
+            } => {
+                if let PassData::Offload(kind) = &self.passes[pass - 1] {
+                    println!("\n{}", trf("app.verify_skipped", &[&kind.name()]));
+                }
+            }
+            ProgressEvent::Verified {
+                outcome: VerifyOutcome::Passed { bytes_checked },
+                ..
+            } => println!("{}", trf("app.verify_passed", &[bytes_checked])),
+            ProgressEvent::Progress(u) => self.last = Some(*u),
+            _ => {}
+        }
+        self.sink.event(event);
+    }
+}
+
+/// Что записано к моменту остановки: законченные проходы целиком, текущий —
+/// участками байт, уже сброшенными на носитель.
+fn interruption_lines(dp: &DevicePlan, i: &Interruption) -> Vec<String> {
+    let passes: Vec<PassData> = dp.plan().resolve(&dp.seed());
+    let total: usize = passes.len();
+    let mut lines: Vec<String> = Vec::new();
+    match i.pass.min(total + 1) - 1 {
+        0 => {}
+        1 => lines.push(trf("app.pass_complete", &[&total])),
+        n => lines.push(trf("app.passes_complete", &[&n, &total])),
+    }
+    if i.pass > total {
+        lines.push(tr("app.verify_interrupted").to_string());
+        return lines;
+    }
+    let done: ExtentSet = dp.extents().before(i.offset);
+    let describe: Message = passes[i.pass - 1].describe();
+    if done.total() == 0 {
+        lines.push(trf("app.pass_not_started", &[&i.pass, &total, &describe]));
     } else {
-        None
+        let mut ranges: Vec<String> = done
+            .extents()
+            .iter()
+            .take(INTERRUPT_MAX_RANGES)
+            .map(|e| format!("{}..{}", e.start, e.end()))
+            .collect();
+        let rest: usize = done.extents().len().saturating_sub(INTERRUPT_MAX_RANGES);
+        if rest > 0 {
+            ranges.push(trf("app.and_more", &[&rest]));
+        }
+        lines.push(trf(
+            "app.pass_partial",
+            &[
+                &i.pass,
+                &total,
+                &describe,
+                &done.total(),
+                &dp.extents().total(),
+                &ranges.join(", "),
+            ],
+        ));
+    }
+    match total - i.pass {
+        0 => {}
+        1 => lines.push(trf(
+            "app.pass_not_started",
+            &[&total, &total, &passes[total - 1].describe()],
+        )),
+        _ => lines.push(trf("app.passes_not_started", &[&(i.pass + 1), &total])),
+    }
+    lines
+}
+
+/// Журнал после остановки: где он и как продолжить.
+fn journal_lines(journal: Option<&JournalOutcome>) -> Vec<String> {
+    match journal {
+        Some(JournalOutcome::Saved(path)) => vec![
+            trf("app.journal", &[&path.display()]),
+            trf("app.resume_hint", &[&path.display()]),
+        ],
+        Some(JournalOutcome::Done) => vec![tr("app.nothing_to_resume").to_string()],
+        Some(JournalOutcome::Failed(msg)) => vec![msg.to_string()],
+        None => Vec::new(),
+    }
+}
+
+/// Где отчёт и подписан ли он; ошибка записи — в stderr.
+fn print_report(report: &Result<SavedReport, Message>) {
+    match report {
+        Ok(saved) => println!(
+            "{}",
+            trf(
+                "app.report_saved",
+                &[
+                    &saved.path.display(),
+                    &tr(if saved.signed {
+                        "app.report_signed"
+                    } else {
+                        "app.report_unsigned"
+                    }),
+                    &saved.text.display(),
+                ],
+            )
+        ),
+        Err(msg) => eprintln!("{msg}"),
+    }
+}
+
+/// `destroyer verify-report`: 0 — подпись верна и сделана доверенным ключом,
+/// `EXIT_UNTRUSTED_KEY` — верна, но ключ взят из отчёта, 1 — нет или ошибка.
+fn verify_report(args: &VerifyReportArgs) -> i32 {
+    let expected: Option<[u8; 32]> = match &args.pub_key {
+        Some(path) => match load_public_key(Path::new(path)) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                eprintln!("{}", trf("app.pub_key_failed", &[path, &e]));
+                return 1;
+            }
+        },
+        None => None,
+    };
+    let text: String = match std::fs::read_to_string(&args.report) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("{}", trf("app.report_read_failed", &[&args.report, &e]));
+            return 1;
+        }
     };
+    match verify_document(&text, expected.as_ref()) {
+        Ok(key) if expected.is_some() => {
+            println!(
+                "{}",
+                trf("app.signature_ok", &[&args.report, &to_hex(&key)])
+            );
+            0
+        }
+        Ok(key) => {
+            eprintln!(
+                "{}",
+                trf("app.signature_untrusted", &[&args.report, &to_hex(&key)])
+            );
+            EXIT_UNTRUSTED_KEY
+        }
+        Err(e) => {
+            eprintln!("{}: {e}", args.report);
+            1
+        }
+    }
+}
 
-    for pass_idx in 0..cfg.passes.saturating_sub(1) {
-        println!(
-            "\nПроход {}/{} (случайные данные)...",
-            pass_idx + 1,
-            cfg.passes
-        );
-        progress.start_pass(pass_idx + 1);
-        if let Err(e) = main_handle.seek(SeekFrom::Start(0)) {
-            eprintln!("Не удалось вернуть устройство в начало: {e}");
+/// Поток событий `--progress=json`: дескриптор `--progress-fd` или stdout.
+fn open_event_stream(cfg: &Config) -> Option<EventStream> {
+    if cfg.progress != ProgressFormat::Json {
+        return None;
+    }
+    let stream = match cfg.progress_fd {
+        Some(fd) => EventStream::from_fd(fd),
+        None => EventStream::take_stdout(),
+    };
+    match stream {
+        Ok(stream) => Some(stream),
+        Err(e) => {
+            eprintln!("{}", trf("app.events_failed", &[&e]));
             std::process::exit(1);
         }
-        let durable_mode = cfg.mode.is_durable();
-        let tail_ref: Option<&mut File> = {
-            #[cfg(feature = "direct")]
-            {
-                tail_handle.as_mut()
-            }
-            #[cfg(not(feature = "direct"))]
-            {
-                None
+    }
+}
+
+/// План `--dry-run`: проходы в байтах, итоги и оценка времени по пробному
+/// чтению начала стираемых участков.
+fn dry_run_lines(dp: &DevicePlan, dry: &DryRun) -> Vec<String> {
+    let estimate: &WipeEstimate = &dry.estimate;
+    let mut lines: Vec<String> = vec![tr("app.dry_run").to_string()];
+    let total_passes: usize = dp.plan().len();
+    for p in &estimate.passes {
+        let mut line: String = trf(
+            "app.dry_run_pass",
+            &[&p.pass_no, &total_passes, &p.describe],
+        );
+        if p.offload_bytes > 0 {
+            line.push_str(&trf("app.dry_run_offload", &[&p.offload_bytes]));
+        } else {
+            line.push_str(&trf("app.dry_run_write", &[&p.write_bytes]));
+        }
+        if p.verify_bytes > 0 {
+            line.push_str(&trf("app.dry_run_verify", &[&p.verify_bytes]));
+        }
+        lines.push(line);
+    }
+    lines.push(trf(
+        "app.dry_run_total_write",
+        &[&estimate.write_bytes(), &format_gb(estimate.write_bytes())],
+    ));
+    if estimate.offload_bytes() > 0 {
+        lines.push(trf(
+            "app.dry_run_total_offload",
+            &[&estimate.offload_bytes()],
+        ));
+    }
+    if estimate.verify_bytes() > 0 {
+        lines.push(trf(
+            "app.dry_run_total_verify",
+            &[
+                &estimate.verify_bytes(),
+                &format_gb(estimate.verify_bytes()),
+            ],
+        ));
+    }
+    match dry.probe.speed() {
+        Some(speed) => {
+            lines.push(trf(
+                "app.dry_run_speed",
+                &[
+                    &format!("{:.1}", speed / 1_000_000.0),
+                    &dry.probe.bytes,
+                    &format_duration(dry.probe.elapsed),
+                ],
+            ));
+            if let Some(eta) = estimate.duration(speed) {
+                lines.push(trf("app.dry_run_eta", &[&format_duration(eta)]));
             }
-        };
-        if let Err(e) = pass_random(
-            &mut main_handle,
-            device_size,
-            durable_mode,
-            sector,
-            &cfg.device_path,
-            &mut progress,
-            &mut buffers,
-            tail_ref,
-        ) {
-            eprintln!("Ошибка записи случайных данных: {e}");
-            std::process::exit(1);
         }
+        None => lines.push(tr("app.dry_run_no_eta").to_string()),
     }
+    lines
+}
 
-    println!("\nФинальный проход {}/{} (нули)...", cfg.passes, cfg.passes);
-    progress.start_pass(cfg.passes);
-    if let Err(e) = main_handle.seek(SeekFrom::Start(0)) {
-        eprintln!("Не удалось вернуть устройство в начало: {e}");
+/// Подтверждение перед записью: перепечатать имя или хвост серийного номера
+/// каждого устройства. С `--yes` не спрашиваем; без терминала и без `--yes`
+/// отказываемся — молча стирать из скрипта нельзя.
+fn confirm_or_exit(cfg: &Config, targets: &[Target]) {
+    if cfg.yes {
+        println!("{}", tr("app.confirm_skipped"));
+        return;
+    }
+    if !stdin_is_tty() {
+        eprintln!("{}", tr("app.confirm_no_tty"));
         std::process::exit(1);
     }
-    let durable_mode = cfg.mode.is_durable();
-    let tail_ref: Option<&mut File> = {
-        #[cfg(feature = "direct")]
-        {
-            tail_handle.as_mut()
+    match ask(targets, &mut std::io::stdin().lock()) {
+        Ok(true) => {}
+        Ok(false) => {
+            eprintln!("{}", tr("app.confirm_mismatch"));
+            std::process::exit(1);
         }
-        #[cfg(not(feature = "direct"))]
-        {
-            None
+        Err(e) => {
+            eprintln!("{}", trf("app.confirm_read_failed", &[&e]));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Ошибка устройства при одиночном запуске: подсказка для EBUSY, код выхода.
+/// События `error` и `result` к этому моменту уже записал `Wiper`.
+fn exit_on_failure(device_path: &str, failure: WipeError) -> ! {
+    match &failure {
+        WipeError::Verify(report) => print_mismatches(report),
+        WipeError::Busy(msg) => {
+            busy_help(device_path);
+            eprintln!("{msg}");
         }
+        WipeError::ReadOnly(msg) => {
+            eprintln!("{msg}");
+            eprintln!("{}", trf("app.read_only_help", &[&device_path]));
+        }
+        WipeError::Permission(msg) => {
+            eprintln!("{msg}");
+            eprintln!("{}", tr("app.permission_help"));
+        }
+        WipeError::Refused(Refusal::SystemDisk(usages)) => system_disk_help(device_path, usages),
+        WipeError::Refused(Refusal::InUse(usages)) => in_use_help(device_path, usages),
+        _ => eprintln!("{failure}"),
+    }
+    std::process::exit(failure.exit_code());
+}
+
+/// Несколько устройств: подготовить все, одно предупреждение, общая таблица
+/// прогресса и итог. Стирает и выбирает код выхода `batch::WipeBatch`.
+fn execute_many(cfg: &Config, stream: Option<EventStream>, signer: Option<&Arc<SigningKey>>) {
+    let wipers: Vec<Wiper> = cfg
+        .devices
+        .iter()
+        .map(|path| wiper_for(cfg, path, true, stream.as_ref(), signer))
+        .collect();
+    let stop: Arc<Signals> = Arc::new(Signals::default());
+    let mut batch: WipeBatch = WipeBatch::new(wipers).signals(Arc::clone(&stop));
+    if let Some(stream) = stream {
+        batch = batch.events(stream);
+    }
+    let prepared: PreparedBatch = batch.prepare();
+
+    println!("{}", trf("app.devices_parallel", &[&cfg.devices.len()]));
+    match cfg.scheme {
+        Some(scheme) => println!("{}", trf("app.scheme", &[&scheme.title(), &cfg.plan.len()])),
+        None => println!("{}", trf("app.running_passes", &[&cfg.plan.len()])),
+    }
+    println!("{}", trf("app.plan", &[&cfg.plan.spec()]));
+    println!("{}", trf("app.mode", &[&cfg.mode.label()]));
+    if let Some(seed) = cfg.seed {
+        println!("{}", trf("app.seed_shared", &[&seed.to_hex()]));
+    }
+    for dp in prepared.plans() {
+        println!(
+            "  {}",
+            trf(
+                "app.device_row",
+                &[
+                    &dp.device_path(),
+                    &dp.device_size(),
+                    &format_gb(dp.device_size()),
+                    &dp.buf_size(),
+                ],
+            )
+        );
+        if let Some(identity) = dp.identity() {
+            println!("      {}", identity.summary());
+        }
+        if let Some(p) = dp.partition() {
+            println!(
+                "      {}",
+                trf("app.device_partition", &[&describe_partition(p)])
+            );
+        }
+        if !dp.extents().is_full(dp.device_size()) {
+            println!(
+                "      {}",
+                trf("app.device_extents", &[&describe_extents(dp.extents())])
+            );
+        }
+        for u in dp.in_use() {
+            println!(
+                "      {}",
+                trf("app.device_in_use", &[&u.device, &u.reason])
+            );
+        }
+    }
+    for (path, f) in prepared.failed() {
+        println!("  {}", trf("app.device_skipped", &[path, f]));
+    }
+    if prepared.plans().is_empty() {
+        let outcome: BatchOutcome = prepared.start().finish();
+        eprintln!("{}", tr("app.no_devices_ready"));
+        std::process::exit(outcome.exit_code());
+    }
+    if cfg.dry_run {
+        let dry: BatchDryRun = prepared.estimate();
+        for (dp, result) in &dry.estimates {
+            println!("\n{}:", dp.device_path());
+            match result {
+                Ok(estimate) => dry_run_lines(dp, estimate)
+                    .iter()
+                    .for_each(|l| println!("  {l}")),
+                Err(f) => println!("  {}", trf("app.failed", &[f])),
+            }
+        }
+        std::process::exit(dry.exit_code());
+    }
+    println!(
+        "{}",
+        trf("app.warning_destroy_many", &[&prepared.plans().len()])
+    );
+    let targets: Vec<Target> = prepared
+        .plans()
+        .into_iter()
+        .map(|dp| Target {
+            device_path: dp.device_path(),
+            fingerprint: dp.fingerprint(),
+        })
+        .collect();
+    confirm_or_exit(cfg, &targets);
+    println!();
+    if let Err(e) = signals::install(&stop) {
+        eprintln!("{}", trf("app.signals_failed", &[&e]));
+    }
+
+    let mut job: BatchJob = prepared.start();
+    let rows: Vec<BoardRow> = job
+        .plans()
+        .iter()
+        .zip(job.progress())
+        .map(|(dp, progress)| BoardRow {
+            name: dp.device_path().to_string(),
+            total_passes: dp.plan().len(),
+            pass_size: dp.extents().total(),
+            progress: Arc::clone(progress),
+            state: RowState::Running,
+        })
+        .collect();
+    // SAFETY: isatty только читает свойства дескриптора.
+    let tty: bool = unsafe { libc::isatty(libc::STDOUT_FILENO) } == 1;
+    let mut board = Board::new(rows, tty);
+    let redraw_every: Duration = if tty {
+        MULTI_REDRAW_TTY
+    } else {
+        MULTI_REDRAW_LOG
     };
-    if let Err(e) = pass_zeros(
-        &mut main_handle,
-        device_size,
-        durable_mode,
-        sector,
-        &cfg.device_path,
-        &mut progress,
-        &mut buffers,
-        tail_ref,
-    ) {
-        eprintln!("Ошибка записи нулей: {e}");
-        std::process::exit(1);
+    let mut last_draw: Option<Instant> = None;
+    loop {
+        for i in job.poll() {
+            let ok: bool = job.outcome(i).is_some_and(|o| o.result.is_ok());
+            board.set_state(i, if ok { RowState::Done } else { RowState::Failed });
+        }
+        let done: bool = job.is_finished();
+        if stop.take_status_request() {
+            for line in board.render() {
+                eprintln!("{line}");
+            }
+        }
+        if !cfg.quiet && (done || last_draw.is_none_or(|t| t.elapsed() >= redraw_every)) {
+            board.draw();
+            last_draw = Some(Instant::now());
+        }
+        if done {
+            break;
+        }
+        sleep(MULTI_POLL);
+    }
+    let outcome: BatchOutcome = job.finish();
+
+    println!("\n{}", tr("app.summary"));
+    for (dp, device) in &outcome.outcomes {
+        if let Some(report) = &device.report {
+            print_report(report);
+        }
+        match &device.result {
+            Ok(summary) => println!(
+                "  {}",
+                trf(
+                    "app.device_ok",
+                    &[&dp.device_path(), &format_duration(summary.elapsed)]
+                )
+            ),
+            Err(WipeError::Cancelled(i)) => {
+                println!(
+                    "  {}",
+                    trf(
+                        "app.device_interrupted",
+                        &[&dp.device_path(), &signals::signal_name(i.signal)]
+                    )
+                );
+                for line in interruption_lines(dp, i) {
+                    println!("      {line}");
+                }
+                for line in journal_lines(device.journal.as_ref()) {
+                    println!("      {line}");
+                }
+            }
+            Err(f) => println!("  {}", trf("app.device_failed", &[&dp.device_path(), f])),
+        }
+    }
+    for (path, f) in &outcome.failed {
+        println!("  {}", trf("app.device_failed", &[path, f]));
+    }
+    if outcome.exit_code() != 0 {
+        std::process::exit(outcome.exit_code());
     }
+}
+
+/// Раздел для баннера: `N "метка": байты a..b (X GB)`.
+fn describe_partition(p: &Partition) -> String {
+    trf(
+        "app.partition_bytes",
+        &[
+            &p.number,
+            &if p.label.is_empty() {
+                String::new()
+            } else {
+                format!(" \"{}\"", p.label)
+            },
+            &p.start,
+            &(p.start + p.len),
+            &format_gb(p.len),
+        ],
+    )
+}
+
+fn describe_extents(extents: &ExtentSet) -> String {
+    trf(
+        "app.extents_summary",
+        &[
+            &extents.extents().len(),
+            &extents.total(),
+            &format_gb(extents.total()),
+        ],
+    )
+}
+
+/// Байты в GiB с двумя знаками — для подстановки в сообщения.
+fn format_gb(bytes: u64) -> String {
+    format!("{:.2}", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+}
 
-    let elapsed = started.elapsed();
+/// `--list-partitions`: таблица разделов и что будет стёрто.
+fn print_partitions(
+    table: &PartitionTable,
+    selected: Option<&Partition>,
+    extents: &ExtentSet,
+    device_size: u64,
+) {
     println!(
-        "\nУстройство {} успешно очищено (время: {})",
-        cfg.device_path,
-        format_duration(elapsed)
+        "{}{}",
+        trf("app.table", &[&table.kind.name(), &table.sector]),
+        if table.from_backup {
+            tr("app.table_backup")
+        } else {
+            ""
+        }
+    );
+    if let Some(guid) = &table.disk_guid {
+        println!("{}", trf("app.disk_guid", &[guid]));
+    }
+    println!(
+        "  {:>3}  {:>14}  {:>14}  {:>10}  {:<22}  {}",
+        tr("app.col_number"),
+        tr("app.col_start"),
+        tr("app.col_end"),
+        tr("app.col_size"),
+        tr("app.col_type"),
+        tr("app.col_label")
     );
+    for p in &table.partitions {
+        let mark: &str = if selected.is_some_and(|s| s.number == p.number) {
+            "*"
+        } else {
+            " "
+        };
+        let kind: &str = match p.type_name() {
+            "" => &p.type_id,
+            name => name,
+        };
+        println!(
+            "{mark} {:>3}  {:>14}  {:>14}  {:>8.2}GB  {:<22}  {}",
+            p.number,
+            p.start,
+            p.start + p.len,
+            p.len as f64 / (1024.0 * 1024.0 * 1024.0),
+            kind,
+            p.label
+        );
+    }
+    if table.partitions.is_empty() {
+        println!("  {}", tr("app.no_partitions"));
+    }
+    if extents.is_full(device_size) {
+        println!("{}", trf("app.whole_disk", &[&device_size]));
+        return;
+    }
+    println!(
+        "{}",
+        trf(
+            "app.will_wipe_extents",
+            &[&extents.extents().len(), &extents.total()]
+        )
+    );
+    for e in extents.extents().iter().take(LIST_MAX_EXTENTS) {
+        println!("  {}", trf("app.byte_range", &[&e.start, &e.end(), &e.len]));
+    }
+    if extents.extents().len() > LIST_MAX_EXTENTS {
+        println!(
+            "  {}",
+            trf(
+                "app.more_extents",
+                &[&(extents.extents().len() - LIST_MAX_EXTENTS)]
+            )
+        );
+    }
+}
+
+fn print_mismatches(report: &VerifyReport) {
+    eprintln!(
+        "{}",
+        trf(
+            "app.verify_failed",
+            &[&report.mismatched_bytes, &report.bytes_checked]
+        )
+    );
+    for m in &report.mismatches {
+        eprintln!(
+            "  {}",
+            trf("app.byte_range", &[&m.offset, &(m.offset + m.len), &m.len])
+        );
+    }
+    if report.truncated {
+        eprintln!("  {}", tr("app.ranges_truncated"));
+    }
 }
 
 fn format_duration(dur: Duration) -> String {
@@ -187,31 +1030,24 @@ fn format_duration(dur: Duration) -> String {
     }
 }
 
-fn open_device(cfg: &Config, mode: SyncMode) -> File {
-    match open_device_writable(&cfg.device_path, mode) {
-        Ok(file) => file,
-        Err(e) => {
-            if let Some(code) = e.raw_os_error() {
-                if code == libc::EBUSY {
-                    busy_help(&cfg.device_path);
-                }
-            }
-            eprintln!("Ошибка открытия устройства: {e}");
-            std::process::exit(1);
-        }
+/// На устройстве работающая система: что именно на нём лежит.
+fn system_disk_help(device_path: &str, usages: &[Usage]) {
+    eprintln!("{}", trf("app.system_disk", &[&device_path]));
+    for u in usages {
+        eprintln!("  {}", u.reason);
+    }
+    eprintln!("{}", tr("app.system_disk_help"));
+}
+
+/// Устройство используется системой: что именно и как освободить.
+fn in_use_help(device_path: &str, usages: &[Usage]) {
+    eprintln!("{}", trf("app.in_use", &[&device_path]));
+    for u in usages {
+        eprintln!("  {}: {}", u.device, u.reason);
     }
+    eprintln!("{}", tr("app.in_use_help"));
 }
 
 fn busy_help(device_path: &str) {
-    eprintln!(
-        "Устройство {} занято (возможно, примонтировано).",
-        device_path
-    );
-    eprintln!("macOS:  diskutil unmountDisk {}", device_path);
-    eprintln!("Linux:  sudo umount <точка_монтирования_или_/dev/..>");
-    eprintln!("        lsblk -f | grep $(basename {})", device_path);
-    eprintln!("        sudo lsof {}  | head", device_path);
-    eprintln!("        sudo fuser -mv {}", device_path);
-    eprintln!("        sudo swapoff -a");
-    eprintln!("        sudo dmsetup ls");
+    eprintln!("{}", trf("app.busy_help", &[&device_path]));
 }
@@ -1,17 +1,162 @@
 use crate::dev::SyncMode;
+use crate::events::ProgressFormat;
+use crate::extents::RangeSpec;
+use crate::i18n::{Lang, Message, tr, trf};
+use crate::partition::PartitionSelector;
+use crate::plan::{PassKind, PassPlan, Scheme};
+use crate::rng::Seed;
 use std::ffi::OsString;
 use std::iter::Peekable;
-use std::process::exit;
+
+/// Больше потоков генерации не нужно: ChaCha20 на ядро и так быстрее любого носителя.
+const MAX_GEN_THREADS: usize = 64;
+
+/// Больше буферов в полёте NVMe не нужно, а память на них растёт линейно.
+const MAX_QUEUE_DEPTH: usize = 1024;
 
 /// Конфигурация запуска.
 pub struct Config {
+    /// Первое устройство из `devices` (при `--resume` без пути — из журнала).
     pub device_path: String,
+    /// Все устройства из командной строки; если их больше одного, они стираются
+    /// параллельно, каждое в своём потоке.
+    pub devices: Vec<String>,
+    /// Число проходов плана (для `--scheme` — число проходов схемы).
     pub passes: usize,
+    /// Именованная схема санитизации, если задана через --scheme.
+    pub scheme: Option<Scheme>,
+    /// План проходов, который выполняет `app::execute`: из числа проходов,
+    /// `--scheme` или `--passes-spec` (+ `--final`).
+    pub plan: PassPlan,
     /// Пользовательский размер буфера, если задан через --buf.
     /// Если None — будет выбран автоматически по размеру блока устройства.
     pub buf_size: Option<usize>,
+    /// Потоков генерации случайных данных (`--gen-threads`, 0 — без конвейера).
+    /// Если None — `wipe::default_generators()`.
+    pub gen_threads: Option<usize>,
+    /// Сколько записей держать в полёте через io_uring в режиме direct
+    /// (`--queue-depth`, 0 — блокирующая запись). Если None — `DEFAULT_QUEUE_DEPTH`.
+    pub queue_depth: Option<usize>,
     pub mode: SyncMode,
     pub quiet: bool,
+    /// Перечитать устройство после финального прохода и сверить с ожидаемым.
+    pub verify: bool,
+    /// Проверять чтением каждый проход, включая случайные (поток воспроизводится по сиду).
+    pub verify_each_pass: bool,
+    /// Мастер-сид для воспроизводимых прогонов (QA на файлах-образах).
+    pub seed: Option<Seed>,
+    /// Куда периодически сохранять журнал контрольной точки.
+    pub journal: Option<String>,
+    /// Продолжить стирание по журналу. Устройство, план и сид берутся из него;
+    /// путь к устройству в этом случае можно не указывать.
+    pub resume: Option<String>,
+    /// Стирать только эти участки (`--range`, можно несколько). Пусто — всё устройство.
+    pub ranges: Vec<RangeSpec>,
+    /// Не трогать эти участки (`--exclude`, можно несколько).
+    pub excludes: Vec<RangeSpec>,
+    /// Стирать один раздел из таблицы GPT/MBR всего диска; `--range`/`--exclude`
+    /// тогда отсчитываются от начала раздела.
+    pub partition: Option<PartitionSelector>,
+    /// Показать таблицу разделов и цель стирания, ничего не записывая.
+    pub list_partitions: bool,
+    /// Стирать только неразмеченное место между разделами (`--unallocated`).
+    pub unallocated: bool,
+    /// Стирать, даже если устройство примонтировано, используется как подкачка
+    /// или занято dm/md/LVM/zram (`--force`).
+    pub force: bool,
+    /// Разрешить стирать диск, на котором лежат `/`, `/boot`, `/usr`, ESP
+    /// или сам destroyer (`--i-know-this-is-the-system-disk`).
+    pub allow_system_disk: bool,
+    /// Не спрашивать подтверждение (`--yes`) — для скриптов и конвейеров.
+    pub yes: bool,
+    /// Всё, кроме записи: проверки, план и оценка времени (`--dry-run`).
+    pub dry_run: bool,
+    /// Формат прогресса: текст или NDJSON-события (`--progress=json`).
+    pub progress: ProgressFormat,
+    /// Куда писать события вместо stdout (`--progress-fd N`).
+    pub progress_fd: Option<i32>,
+    /// Куда записать отчёт о стирании (`--report out.json`, рядом — `out.txt`).
+    pub report: Option<String>,
+    /// Закрытый ключ Ed25519 для подписи отчёта.
+    pub sign_key: Option<String>,
+    /// Кто выполняет стирание — для отчёта.
+    pub operator: Option<String>,
+    /// Пока файл существует, запись стоит на паузе (`--pause-file`).
+    pub pause_file: Option<String>,
+    /// Язык сообщений (`--lang`, иначе из окружения).
+    pub lang: Lang,
+}
+
+/// Почему разбор аргументов не дал конфигурацию. Процесс по ней завершает
+/// только `app::run`.
+#[derive(Debug)]
+pub enum ArgsError {
+    /// `--help`: текст справки, код выхода 0.
+    Help(String),
+    /// Неверные аргументы, код выхода 1.
+    Invalid(Message),
+}
+
+impl std::fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::Help(usage) => f.write_str(usage),
+            ArgsError::Invalid(msg) => msg.fmt(f),
+        }
+    }
+}
+
+/// Аргументы `destroyer verify-report FILE [--pub-key FILE]`.
+pub struct VerifyReportArgs {
+    pub report: String,
+    /// Доверенный открытый ключ; без него проверяется только целостность.
+    pub pub_key: Option<String>,
+}
+
+impl VerifyReportArgs {
+    /// Разобрать аргументы после имени подкоманды.
+    pub fn parse<I>(args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = OsString>,
+    {
+        let mut report: Option<String> = None;
+        let mut pub_key: Option<String> = None;
+        let mut iter = args.into_iter();
+        while let Some(arg) = iter.next() {
+            let arg: String = arg.into_string().map_err(|_| {
+                ArgsError::Invalid(trf("args.utf8_only", &[&"verify-report"]).into())
+            })?;
+            match arg.as_str() {
+                "--pub-key" => {
+                    pub_key = Some(
+                        iter.next()
+                            .and_then(|v| v.into_string().ok())
+                            .ok_or_else(|| {
+                                ArgsError::Invalid(tr("args.pub_key_required").into())
+                            })?,
+                    );
+                }
+                "--lang" => {
+                    iter.next();
+                }
+                _ if arg.starts_with("--lang=") => {}
+                _ if report.is_none() && !arg.starts_with('-') => report = Some(arg),
+                _ => {
+                    return Err(ArgsError::Invalid(
+                        format!(
+                            "{}\n{}",
+                            trf("args.verify_report_unknown", &[&arg]),
+                            tr("args.verify_report_usage")
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+        let report: String =
+            report.ok_or_else(|| ArgsError::Invalid(tr("args.verify_report_usage").into()))?;
+        Ok(Self { report, pub_key })
+    }
 }
 
 impl Config {
@@ -20,7 +165,8 @@ impl Config {
     /// Форматы:
     ///   destroyer <device> [passes]
     ///   destroyer <device> [passes] --mode fast|durable|direct [--buf BYTES]
-    pub fn parse<I>(args: I) -> Self
+    ///   destroyer <device> <device>... [passes]
+    pub fn parse<I>(args: I) -> Result<Self, ArgsError>
     where
         I: IntoIterator<Item = OsString>,
     {
@@ -28,32 +174,66 @@ impl Config {
         let prog = iter
             .next()
             .and_then(|s| s.into_string().ok())
-            .unwrap_or_else(|| {
-                eprintln!("{}", Self::usage("destroyer"));
-                exit(1);
-            });
+            .ok_or_else(|| ArgsError::Invalid(Self::usage("destroyer").into()))?;
 
         let mut device_path: Option<String> = None;
+        let mut extra_devices: Vec<String> = Vec::new();
         let mut passes: Option<usize> = None;
         let mut buf_size: Option<usize> = None;
+        let mut gen_threads: Option<usize> = None;
+        let mut queue_depth: Option<usize> = None;
         let mut mode: SyncMode = SyncMode::Fast;
         let mut quiet: bool = false;
+        let mut verify: bool = false;
+        let mut verify_each_pass: bool = false;
+        let mut seed: Option<Seed> = None;
+        let mut scheme: Option<Scheme> = None;
+        let mut journal: Option<String> = None;
+        let mut resume: Option<String> = None;
+        let mut ranges: Vec<RangeSpec> = Vec::new();
+        let mut excludes: Vec<RangeSpec> = Vec::new();
+        let mut partition: Option<PartitionSelector> = None;
+        let mut list_partitions: bool = false;
+        let mut unallocated: bool = false;
+        let mut force: bool = false;
+        let mut allow_system_disk: bool = false;
+        let mut yes: bool = false;
+        let mut dry_run: bool = false;
+        let mut progress: ProgressFormat = ProgressFormat::Text;
+        let mut progress_fd: Option<i32> = None;
+        let mut report: Option<String> = None;
+        let mut sign_key: Option<String> = None;
+        let mut operator: Option<String> = None;
+        let mut pause_file: Option<String> = None;
+        let mut lang: Lang = crate::i18n::lang();
+        let mut passes_spec: Option<PassPlan> = None;
+        // None — не задан, Some(None) — `--final none`.
+        let mut final_pass: Option<Option<PassKind>> = None;
 
         while let Some(arg) = iter.next() {
             match arg.to_str() {
                 Some("--help") | Some("-h") => {
-                    eprintln!("{}", Self::usage(&prog));
-                    exit(0);
+                    return Err(ArgsError::Help(Self::usage(&prog)));
+                }
+                Some(flag) if flag == "--lang" || flag.starts_with("--lang=") => {
+                    let val: String = match flag.strip_prefix("--lang=") {
+                        Some(v) => v.to_string(),
+                        None => iter
+                            .next()
+                            .and_then(|v| v.into_string().ok())
+                            .unwrap_or_default(),
+                    };
+                    lang = Lang::parse(&val).ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.lang_invalid", &[&val]).into())
+                    })?;
                 }
                 Some("--mode") => {
-                    let val = iter.next().unwrap_or_else(|| {
-                        eprintln!("--mode требует аргумент: fast|durable|direct");
-                        exit(1);
-                    });
-                    let val_str = val.to_str().unwrap_or_else(|| {
-                        eprintln!("--mode принимает только UTF-8 значения");
-                        exit(1);
-                    });
+                    let val = iter
+                        .next()
+                        .ok_or_else(|| ArgsError::Invalid(tr("args.mode_required").into()))?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--mode"]).into())
+                    })?;
                     mode = match val_str {
                         "fast" => SyncMode::Fast,
                         "durable" => {
@@ -63,8 +243,9 @@ impl Config {
                             }
                             #[cfg(not(feature = "durable"))]
                             {
-                                eprintln!("Режим 'durable' недоступен в текущей сборке.");
-                                exit(1);
+                                return Err(ArgsError::Invalid(
+                                    tr("args.durable_unavailable").into(),
+                                ));
                             }
                         }
                         "direct" => {
@@ -74,105 +255,452 @@ impl Config {
                             }
                             #[cfg(not(all(feature = "direct", target_os = "linux")))]
                             {
-                                eprintln!(
-                                    "Режим 'direct' поддерживается только на Linux и при включённом флаге сборки."
-                                );
-                                exit(1);
+                                return Err(ArgsError::Invalid(
+                                    tr("args.direct_unavailable").into(),
+                                ));
                             }
                         }
                         other => {
-                            eprintln!(
-                                "Неизвестное значение --mode: {other}. Ожидается fast|durable|direct"
-                            );
-                            exit(1);
+                            return Err(ArgsError::Invalid(
+                                trf("args.mode_invalid", &[&other]).into(),
+                            ));
                         }
                     };
                 }
                 Some("--buf") => {
-                    let val = iter.next().unwrap_or_else(|| {
-                        eprintln!("--buf требует число байт");
-                        exit(1);
-                    });
-                    let val_str = val.to_str().unwrap_or_else(|| {
-                        eprintln!("--buf принимает только UTF-8 значения");
-                        exit(1);
-                    });
-                    let parsed: usize = val_str.parse::<usize>().unwrap_or_else(|_| {
-                        eprintln!("Некорректное значение для --buf");
-                        exit(1);
-                    });
+                    let val = iter
+                        .next()
+                        .ok_or_else(|| ArgsError::Invalid(tr("args.buf_required").into()))?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--buf"]).into())
+                    })?;
+                    let parsed: usize = val_str
+                        .parse::<usize>()
+                        .map_err(|_| ArgsError::Invalid(tr("args.buf_invalid").into()))?;
                     if parsed == 0 {
-                        eprintln!("--buf должен быть > 0");
-                        exit(1);
+                        return Err(ArgsError::Invalid(tr("args.buf_zero").into()));
                     }
                     buf_size = Some(parsed);
                 }
+                Some("--queue-depth") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(tr("args.queue_depth_required").into())
+                    })?;
+                    let parsed: Option<usize> = val.to_str().and_then(|v| v.parse::<usize>().ok());
+                    match parsed {
+                        Some(n) if n <= MAX_QUEUE_DEPTH => queue_depth = Some(n),
+                        _ => {
+                            return Err(ArgsError::Invalid(
+                                trf("args.range_expected", &[&"--queue-depth", &MAX_QUEUE_DEPTH])
+                                    .into(),
+                            ));
+                        }
+                    }
+                }
+                Some("--gen-threads") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(tr("args.gen_threads_required").into())
+                    })?;
+                    let parsed: Option<usize> = val.to_str().and_then(|v| v.parse::<usize>().ok());
+                    match parsed {
+                        Some(n) if n <= MAX_GEN_THREADS => gen_threads = Some(n),
+                        _ => {
+                            return Err(ArgsError::Invalid(
+                                trf("args.range_expected", &[&"--gen-threads", &MAX_GEN_THREADS])
+                                    .into(),
+                            ));
+                        }
+                    }
+                }
                 Some("--quiet") => {
                     quiet = true;
                 }
+                Some("--scheme") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(
+                            trf("args.scheme_required", &[&Self::scheme_names()]).into(),
+                        )
+                    })?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--scheme"]).into())
+                    })?;
+                    scheme = Some(Scheme::parse(val_str).ok_or_else(|| {
+                        ArgsError::Invalid(
+                            trf("args.scheme_invalid", &[&val_str, &Self::scheme_names()]).into(),
+                        )
+                    })?);
+                }
+                Some("--passes-spec") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(tr("args.passes_spec_required").into())
+                    })?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--passes-spec"]).into())
+                    })?;
+                    passes_spec = Some(PassPlan::parse_spec(val_str).map_err(|e| {
+                        ArgsError::Invalid(
+                            trf("args.invalid_value", &[&"--passes-spec", &e]).into(),
+                        )
+                    })?);
+                }
+                Some("--final") => {
+                    let val = iter
+                        .next()
+                        .ok_or_else(|| ArgsError::Invalid(tr("args.final_required").into()))?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--final"]).into())
+                    })?;
+                    final_pass = Some(match val_str {
+                        "none" => None,
+                        other => Some(PassKind::parse(other).map_err(|e| {
+                            ArgsError::Invalid(trf("args.invalid_value", &[&"--final", &e]).into())
+                        })?),
+                    });
+                }
+                Some("--journal") | Some("--resume") => {
+                    let flag: &str = if arg == "--journal" {
+                        "--journal"
+                    } else {
+                        "--resume"
+                    };
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.journal_required", &[&flag]).into())
+                    })?;
+                    let val_str: String = val
+                        .into_string()
+                        .map_err(|_| ArgsError::Invalid(trf("args.utf8_only", &[&flag]).into()))?;
+                    if flag == "--journal" {
+                        journal = Some(val_str);
+                    } else {
+                        resume = Some(val_str);
+                    }
+                }
+                Some("--report") | Some("--sign-key") | Some("--operator") => {
+                    let flag: String = arg.to_string_lossy().into_owned();
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(
+                            trf(
+                                if flag == "--operator" {
+                                    "args.name_required"
+                                } else {
+                                    "args.file_required"
+                                },
+                                &[&flag],
+                            )
+                            .into(),
+                        )
+                    })?;
+                    let val_str: String = val
+                        .into_string()
+                        .map_err(|_| ArgsError::Invalid(trf("args.utf8_only", &[&flag]).into()))?;
+                    match flag.as_str() {
+                        "--report" => report = Some(val_str),
+                        "--sign-key" => sign_key = Some(val_str),
+                        _ => operator = Some(val_str),
+                    }
+                }
+                Some("--pause-file") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.file_required", &[&"--pause-file"]).into())
+                    })?;
+                    pause_file = Some(val.into_string().map_err(|_| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--pause-file"]).into())
+                    })?);
+                }
+                Some("--range") | Some("--exclude") => {
+                    let flag: &str = if arg == "--range" {
+                        "--range"
+                    } else {
+                        "--exclude"
+                    };
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.range_required", &[&flag]).into())
+                    })?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&flag]).into())
+                    })?;
+                    let range: RangeSpec = RangeSpec::parse(val_str).map_err(|e| {
+                        ArgsError::Invalid(trf("args.invalid_value", &[&flag, &e]).into())
+                    })?;
+                    if flag == "--range" {
+                        ranges.push(range);
+                    } else {
+                        excludes.push(range);
+                    }
+                }
+                Some("--partition") | Some("--partition-label") => {
+                    let flag: &str = if arg == "--partition" {
+                        "--partition"
+                    } else {
+                        "--partition-label"
+                    };
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.partition_required", &[&flag]).into())
+                    })?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&flag]).into())
+                    })?;
+                    if partition.is_some() {
+                        return Err(ArgsError::Invalid(tr("args.partition_twice").into()));
+                    }
+                    partition = Some(if flag == "--partition" {
+                        match val_str.parse::<u32>() {
+                            Ok(n) if n > 0 => PartitionSelector::Number(n),
+                            _ => {
+                                return Err(ArgsError::Invalid(tr("args.partition_number").into()));
+                            }
+                        }
+                    } else {
+                        PartitionSelector::Label(val_str.to_string())
+                    });
+                }
+                Some("--list-partitions") => {
+                    list_partitions = true;
+                }
+                Some("--unallocated") => {
+                    unallocated = true;
+                }
+                Some("--force") => {
+                    force = true;
+                }
+                Some(flag) if flag == "--progress" || flag.starts_with("--progress=") => {
+                    let val: String = match flag.strip_prefix("--progress=") {
+                        Some(v) => v.to_string(),
+                        None => iter
+                            .next()
+                            .and_then(|v| v.into_string().ok())
+                            .unwrap_or_default(),
+                    };
+                    progress = ProgressFormat::parse(&val).ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.progress_invalid", &[&val]).into())
+                    })?;
+                }
+                Some("--progress-fd") => {
+                    let val = iter.next().ok_or_else(|| {
+                        ArgsError::Invalid(tr("args.progress_fd_required").into())
+                    })?;
+                    let parsed: Option<i32> = val.to_str().and_then(|v| v.parse::<i32>().ok());
+                    match parsed {
+                        Some(fd) if fd >= 1 => progress_fd = Some(fd),
+                        _ => {
+                            return Err(ArgsError::Invalid(tr("args.progress_fd_invalid").into()));
+                        }
+                    }
+                }
+                Some("--dry-run") => {
+                    dry_run = true;
+                }
+                Some("--yes") | Some("-y") => {
+                    yes = true;
+                }
+                Some("--i-know-this-is-the-system-disk") => {
+                    allow_system_disk = true;
+                }
+                Some("--verify") => {
+                    verify = true;
+                }
+                Some("--verify-each-pass") => {
+                    verify_each_pass = true;
+                }
+                Some("--seed") => {
+                    let val = iter
+                        .next()
+                        .ok_or_else(|| ArgsError::Invalid(tr("args.seed_required").into()))?;
+                    let val_str = val.to_str().ok_or_else(|| {
+                        ArgsError::Invalid(trf("args.utf8_only", &[&"--seed"]).into())
+                    })?;
+                    seed = Some(
+                        Seed::from_hex(val_str)
+                            .ok_or_else(|| ArgsError::Invalid(tr("args.seed_invalid").into()))?,
+                    );
+                }
                 Some(s) if s.starts_with("--") => {
-                    eprintln!("Неизвестный флаг: {s}");
-                    exit(1);
+                    return Err(ArgsError::Invalid(trf("args.unknown_flag", &[&s]).into()));
                 }
                 Some(other) => {
                     if device_path.is_none() {
                         device_path = Some(other.to_string());
+                    } else if other.contains('/') {
+                        // Следующие устройства отличаем от числа проходов по пути.
+                        extra_devices.push(other.to_string());
                     } else if passes.is_none() {
-                        let p: usize = other.parse::<usize>().unwrap_or_else(|_| {
-                            eprintln!("Число проходов должно быть положительным целым");
-                            exit(1);
-                        });
+                        let p: usize = other
+                            .parse::<usize>()
+                            .map_err(|_| ArgsError::Invalid(tr("args.passes_integer").into()))?;
                         if p == 0 {
-                            eprintln!("Число проходов должно быть >= 1");
-                            exit(1);
+                            return Err(ArgsError::Invalid(tr("args.passes_zero").into()));
                         }
                         passes = Some(p);
                     } else {
-                        eprintln!("Лишний позиционный аргумент: {other}");
-                        exit(1);
+                        return Err(ArgsError::Invalid(
+                            trf("args.extra_positional", &[&other]).into(),
+                        ));
                     }
                 }
                 None => {
-                    eprintln!("Аргументы должны быть валидным UTF-8");
-                    exit(1);
+                    return Err(ArgsError::Invalid(tr("args.not_utf8").into()));
                 }
             }
         }
 
-        let device_path: String = device_path.unwrap_or_else(|| {
-            eprintln!("{}", Self::usage(&prog));
-            exit(1);
-        });
+        let device_path: String = match (device_path, &resume) {
+            (Some(path), _) => path,
+            // При --resume путь к устройству берётся из журнала.
+            (None, Some(_)) => String::new(),
+            (None, None) => {
+                return Err(ArgsError::Invalid(Self::usage(&prog).into()));
+            }
+        };
+
+        if resume.is_some()
+            && (passes.is_some()
+                || scheme.is_some()
+                || passes_spec.is_some()
+                || final_pass.is_some()
+                || seed.is_some())
+        {
+            return Err(ArgsError::Invalid(tr("args.resume_plan").into()));
+        }
+        if resume.is_some() && (!ranges.is_empty() || !excludes.is_empty()) {
+            return Err(ArgsError::Invalid(
+                trf("args.resume_extents", &[&"--range/--exclude"]).into(),
+            ));
+        }
+        if resume.is_some() && (partition.is_some() || list_partitions || unallocated) {
+            return Err(ArgsError::Invalid(
+                trf("args.resume_extents", &[&"--partition/--unallocated"]).into(),
+            ));
+        }
+        if unallocated && partition.is_some() {
+            return Err(ArgsError::Invalid(
+                trf("args.exclusive", &[&"--unallocated", &"--partition"]).into(),
+            ));
+        }
+        if report.is_none() && (sign_key.is_some() || operator.is_some()) {
+            return Err(ArgsError::Invalid(
+                trf("args.requires", &[&"--sign-key / --operator", &"--report"]).into(),
+            ));
+        }
+        if progress_fd.is_some() && progress != ProgressFormat::Json {
+            return Err(ArgsError::Invalid(
+                trf("args.requires", &[&"--progress-fd", &"--progress=json"]).into(),
+            ));
+        }
+        if queue_depth.is_some() {
+            if !cfg!(all(target_os = "linux", feature = "io-uring")) {
+                return Err(ArgsError::Invalid(tr("args.no_uring").into()));
+            }
+            if !mode.is_direct() {
+                return Err(ArgsError::Invalid(
+                    trf("args.requires", &[&"--queue-depth", &"--mode direct"]).into(),
+                ));
+            }
+        }
+        let mut devices: Vec<String> = Vec::with_capacity(1 + extra_devices.len());
+        if !device_path.is_empty() {
+            devices.push(device_path.clone());
+        }
+        for dev in extra_devices {
+            if devices.contains(&dev) {
+                return Err(ArgsError::Invalid(trf("args.device_twice", &[&dev]).into()));
+            }
+            devices.push(dev);
+        }
+        if devices.len() > 1 && (journal.is_some() || resume.is_some()) {
+            return Err(ArgsError::Invalid(
+                trf("args.single_device", &[&"--journal / --resume"]).into(),
+            ));
+        }
+        if devices.len() > 1 && list_partitions {
+            return Err(ArgsError::Invalid(
+                trf("args.single_device", &[&"--list-partitions"]).into(),
+            ));
+        }
 
-        let passes: usize = passes.unwrap_or(8);
+        let plan: PassPlan = match (scheme, passes_spec) {
+            (Some(_), Some(_)) => {
+                return Err(ArgsError::Invalid(
+                    trf("args.exclusive", &[&"--scheme", &"--passes-spec"]).into(),
+                ));
+            }
+            (Some(s), None) => {
+                if passes.is_some() {
+                    return Err(ArgsError::Invalid(
+                        trf("args.plan_sets_passes", &[&"--scheme"]).into(),
+                    ));
+                }
+                if final_pass.is_some() {
+                    return Err(ArgsError::Invalid(tr("args.final_with_scheme").into()));
+                }
+                s.plan()
+            }
+            (None, Some(spec)) => {
+                if passes.is_some() {
+                    return Err(ArgsError::Invalid(
+                        trf("args.plan_sets_passes", &[&"--passes-spec"]).into(),
+                    ));
+                }
+                match final_pass {
+                    Some(Some(kind)) => spec.with_final(kind),
+                    Some(None) => {
+                        return Err(ArgsError::Invalid(tr("args.final_none_with_spec").into()));
+                    }
+                    None => spec,
+                }
+            }
+            (None, None) => {
+                let count: usize = passes.unwrap_or(8);
+                match final_pass {
+                    Some(last) => PassPlan::standard_with_final(count, last),
+                    None => PassPlan::standard(count),
+                }
+            }
+        };
+        if plan.passes().first() == Some(&PassKind::Complement) {
+            return Err(ArgsError::Invalid(tr("plan.complement_first").into()));
+        }
 
-        Self {
+        Ok(Self {
             device_path,
-            passes,
+            devices,
+            passes: plan.len(),
+            scheme,
+            plan,
             buf_size,
+            gen_threads,
+            queue_depth,
             mode,
             quiet,
-        }
+            verify,
+            verify_each_pass,
+            seed,
+            journal,
+            resume,
+            ranges,
+            excludes,
+            partition,
+            list_partitions,
+            unallocated,
+            force,
+            allow_system_disk,
+            yes,
+            dry_run,
+            progress,
+            progress_fd,
+            report,
+            sign_key,
+            operator,
+            pause_file,
+            lang,
+        })
+    }
+
+    fn scheme_names() -> String {
+        let names: Vec<&str> = Scheme::ALL.iter().map(|s| s.name()).collect();
+        names.join("|")
     }
 
     pub fn usage(prog: &str) -> String {
-        format!(
-"Использование:
-  {prog} <устройство> [проходы] [--mode fast|durable|direct] [--buf BYTES] [--quiet]
-
-Примеры:
-  sudo {prog} /dev/sdX 8
-  sudo {prog} /dev/sdX 8 --mode durable --buf 65536
-  sudo {prog} /dev/sdX 8 --mode direct
-  sudo {prog} /dev/diskN 3 --mode fast
-
-Пояснения:
-  <устройство>     Путь к блочному девайсу (Linux: /dev/sdX|nvme0n1; macOS: /dev/diskN)
-  [проходы]        Количество проходов (последний — нулями). По умолчанию 8
-  --mode           fast (быстро) | durable (максимум надёжности) | direct (Linux, O_DIRECT — без page cache)
-  --buf BYTES      Размер буфера. Если не указан — выбирается автоматически
-                   по размеру блока устройства (кратно сектору, целимся ~64 KiB)
-  --quiet          Не выводить строку прогресса (ускоряет работу)."
-        )
+        trf("args.usage", &[&prog])
     }
 }
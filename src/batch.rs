@@ -0,0 +1,302 @@
+//! Несколько устройств за один запуск: подготовить все, стирать параллельно
+//! (по потоку на устройство, флаги остановки общие) и подвести итог с одним
+//! кодом выхода. Ошибка одного устройства не останавливает остальные.
+
+use crate::error::{EXIT_FAILURE, EXIT_VERIFY_FAILED, WipeError};
+use crate::events::{EventStream, Json};
+use crate::signals::{self, Signals};
+use crate::wipe::SharedProgress;
+use crate::wiper::{DevicePlan, DryRun, WipeJob, WipeOutcome, Wiper};
+use std::sync::Arc;
+
+/// Устройства для стирания; у каждого свой `Wiper` (отчёт, события, журнал).
+pub struct WipeBatch {
+    wipers: Vec<Wiper>,
+    events: Option<EventStream>,
+    signals: Arc<Signals>,
+}
+
+impl WipeBatch {
+    pub fn new(wipers: Vec<Wiper>) -> Self {
+        Self {
+            wipers,
+            events: None,
+            signals: Arc::new(Signals::default()),
+        }
+    }
+
+    /// Писать итоговое событие `summary` в этот поток; события устройств
+    /// задаются в их `Wiper::events`.
+    pub fn events(mut self, stream: EventStream) -> Self {
+        self.events = Some(stream);
+        self
+    }
+
+    /// Флаги остановки и паузы, общие для всех устройств (и для обработчиков
+    /// сигналов `signals::install`).
+    pub fn signals(mut self, signals: Arc<Signals>) -> Self {
+        self.signals = signals;
+        self
+    }
+
+    /// Проверить все устройства (`Wiper::prepare`); неподготовленные
+    /// откладываются с ошибкой и дальше не участвуют.
+    pub fn prepare(self) -> PreparedBatch {
+        let devices: usize = self.wipers.len();
+        let mut ready: Vec<(Wiper, DevicePlan)> = Vec::with_capacity(devices);
+        let mut failed: Vec<(String, WipeError)> = Vec::new();
+        for wiper in self.wipers {
+            match wiper.prepare() {
+                Ok(dp) => ready.push((wiper, dp)),
+                Err(f) => failed.push((wiper.device_path().to_string(), f)),
+            }
+        }
+        PreparedBatch {
+            ready,
+            failed,
+            devices,
+            events: self.events,
+            signals: self.signals,
+        }
+    }
+}
+
+/// Устройства после проверки: готовые с планами и отложенные с ошибками.
+pub struct PreparedBatch {
+    ready: Vec<(Wiper, DevicePlan)>,
+    failed: Vec<(String, WipeError)>,
+    devices: usize,
+    events: Option<EventStream>,
+    signals: Arc<Signals>,
+}
+
+impl PreparedBatch {
+    /// Планы готовых устройств в порядке добавления.
+    pub fn plans(&self) -> Vec<&DevicePlan> {
+        self.ready.iter().map(|(_, dp)| dp).collect()
+    }
+
+    /// Устройства, не прошедшие проверку.
+    pub fn failed(&self) -> &[(String, WipeError)] {
+        &self.failed
+    }
+
+    /// Пробный запуск всех готовых устройств (`Wiper::estimate`).
+    pub fn estimate(self) -> BatchDryRun {
+        let estimates: Vec<(DevicePlan, Result<DryRun, WipeError>)> = self
+            .ready
+            .into_iter()
+            .map(|(wiper, dp)| {
+                let dry = wiper.estimate(&dp);
+                (dp, dry)
+            })
+            .collect();
+        // Код первой ошибки: сначала проверки, потом пробного чтения.
+        let exit_code: i32 = self
+            .failed
+            .iter()
+            .map(|(_, f)| f)
+            .chain(estimates.iter().filter_map(|(_, r)| r.as_ref().err()))
+            .next()
+            .map_or(0, WipeError::exit_code);
+        let ok: usize = estimates.iter().filter(|(_, r)| r.is_ok()).count();
+        emit_summary(self.events.as_ref(), self.devices, ok, exit_code);
+        BatchDryRun {
+            estimates,
+            failed: self.failed,
+            exit_code,
+        }
+    }
+
+    /// Запустить все готовые устройства. Если готовых нет, `finish` сразу
+    /// вернёт итог с кодом первой ошибки проверки.
+    pub fn start(self) -> BatchJob {
+        let mut failed: Vec<(String, WipeError)> = self.failed;
+        let mut plans: Vec<DevicePlan> = Vec::with_capacity(self.ready.len());
+        let mut jobs: Vec<Option<WipeJob>> = Vec::with_capacity(self.ready.len());
+        for (wiper, dp) in self.ready {
+            let path: String = dp.device_path().to_string();
+            match wiper
+                .signals(Arc::clone(&self.signals))
+                .start_planned(dp.clone())
+            {
+                Ok(job) => {
+                    plans.push(dp);
+                    jobs.push(Some(job));
+                }
+                Err(f) => failed.push((path, f)),
+            }
+        }
+        BatchJob {
+            plans,
+            progress: jobs
+                .iter()
+                .flatten()
+                .map(WipeJob::shared_progress)
+                .collect(),
+            outcomes: jobs.iter().map(|_| None).collect(),
+            jobs,
+            failed,
+            devices: self.devices,
+            events: self.events,
+            signals: self.signals,
+        }
+    }
+}
+
+/// Итог пробного запуска нескольких устройств.
+pub struct BatchDryRun {
+    pub estimates: Vec<(DevicePlan, Result<DryRun, WipeError>)>,
+    pub failed: Vec<(String, WipeError)>,
+    exit_code: i32,
+}
+
+impl BatchDryRun {
+    /// Код первой ошибки или 0.
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+}
+
+/// Параллельное стирание, запущенное `PreparedBatch::start`.
+pub struct BatchJob {
+    plans: Vec<DevicePlan>,
+    jobs: Vec<Option<WipeJob>>,
+    progress: Vec<Arc<SharedProgress>>,
+    outcomes: Vec<Option<WipeOutcome>>,
+    failed: Vec<(String, WipeError)>,
+    devices: usize,
+    events: Option<EventStream>,
+    signals: Arc<Signals>,
+}
+
+impl BatchJob {
+    /// Планы запущенных устройств; номера те же, что у `progress` и `poll`.
+    pub fn plans(&self) -> &[DevicePlan] {
+        &self.plans
+    }
+
+    /// Счётчики прогресса устройств — строки `multi::Board`.
+    pub fn progress(&self) -> &[Arc<SharedProgress>] {
+        &self.progress
+    }
+
+    /// Забрать итоги закончивших устройств; возвращает их номера.
+    pub fn poll(&mut self) -> Vec<usize> {
+        let mut finished: Vec<usize> = Vec::new();
+        for (i, slot) in self.jobs.iter_mut().enumerate() {
+            if slot.as_ref().is_some_and(WipeJob::is_finished) {
+                let job: WipeJob = slot.take().expect("job");
+                self.outcomes[i] = Some(job.finish());
+                finished.push(i);
+            }
+        }
+        finished
+    }
+
+    /// Итог устройства `index`, если оно уже закончило (после `poll`).
+    pub fn outcome(&self, index: usize) -> Option<&WipeOutcome> {
+        self.outcomes[index].as_ref()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.jobs.iter().all(Option::is_none)
+    }
+
+    /// Остановить все устройства на границе чанка.
+    pub fn cancel(&self) {
+        self.signals.cancel();
+    }
+
+    pub fn pause(&self) {
+        self.signals.pause();
+    }
+
+    pub fn resume(&self) {
+        self.signals.resume();
+    }
+
+    /// Дождаться всех устройств, записать событие `summary` и выбрать код выхода.
+    pub fn finish(mut self) -> BatchOutcome {
+        self.jobs
+            .iter_mut()
+            .zip(&mut self.outcomes)
+            .for_each(|(job, outcome)| {
+                if let Some(job) = job.take() {
+                    *outcome = Some(job.finish());
+                }
+            });
+        let outcomes: Vec<(DevicePlan, WipeOutcome)> = self
+            .plans
+            .into_iter()
+            .zip(self.outcomes.into_iter().flatten())
+            .collect();
+        let exit_code: i32 = exit_code(&outcomes, &self.failed);
+        let ok: usize = outcomes.iter().filter(|(_, o)| o.result.is_ok()).count();
+        emit_summary(self.events.as_ref(), self.devices, ok, exit_code);
+        BatchOutcome {
+            outcomes,
+            failed: self.failed,
+            exit_code,
+        }
+    }
+}
+
+/// Итог стирания нескольких устройств.
+pub struct BatchOutcome {
+    /// Запущенные устройства в порядке добавления.
+    pub outcomes: Vec<(DevicePlan, WipeOutcome)>,
+    /// Не прошедшие проверку.
+    pub failed: Vec<(String, WipeError)>,
+    exit_code: i32,
+}
+
+impl BatchOutcome {
+    /// Код выхода по старшинству: остановка сигналом, первая ошибка
+    /// устройства, ошибка записи отчёта, расхождения проверки, иначе 0.
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+}
+
+fn exit_code(outcomes: &[(DevicePlan, WipeOutcome)], failed: &[(String, WipeError)]) -> i32 {
+    // Код первой ошибки устройства (кроме расхождений проверки).
+    let mut device_failed: Option<i32> = failed.first().map(|(_, f)| f.exit_code());
+    let mut report_failed: bool = false;
+    let mut verify_failed: bool = false;
+    // Код выхода по сигналу, если остановлено хоть одно устройство.
+    let mut interrupted: Option<i32> = None;
+    for (_, outcome) in outcomes {
+        report_failed |= matches!(outcome.report, Some(Err(_)));
+        match &outcome.result {
+            Ok(_) => {}
+            Err(WipeError::Cancelled(i)) => interrupted = Some(signals::exit_code(i.signal)),
+            Err(WipeError::Verify(_)) => verify_failed = true,
+            Err(f) => {
+                device_failed.get_or_insert(f.exit_code());
+            }
+        }
+    }
+    if let Some(code) = interrupted.or(device_failed) {
+        code
+    } else if report_failed {
+        EXIT_FAILURE
+    } else if verify_failed {
+        EXIT_VERIFY_FAILED
+    } else {
+        0
+    }
+}
+
+/// Событие `summary` после всех устройств.
+fn emit_summary(stream: Option<&EventStream>, devices: usize, ok: usize, exit_code: i32) {
+    if let Some(stream) = stream {
+        stream.emit(
+            Json::event("summary")
+                .num("devices", devices as u64)
+                .num("ok", ok as u64)
+                .num("failed", (devices - ok) as u64)
+                .raw("exit_code", &exit_code.to_string()),
+        );
+    }
+}
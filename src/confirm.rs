@@ -0,0 +1,81 @@
+use crate::i18n::trf;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Сколько последних символов серийного номера достаточно ввести.
+pub const SERIAL_TAIL: usize = 4;
+
+/// Что пользователь должен перепечатать, чтобы подтвердить стирание одного устройства.
+pub struct Target<'a> {
+    pub device_path: &'a str,
+    /// Отпечаток из `journal::device_fingerprint`; серийный номер — после `serial:`.
+    pub fingerprint: &'a str,
+}
+
+impl Target<'_> {
+    /// Имя устройства без каталога: `sdb`, `disk4`, `disk.img`.
+    pub fn name(&self) -> &str {
+        Path::new(self.device_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(self.device_path)
+    }
+
+    /// Последние `SERIAL_TAIL` символов серийного номера, если он известен.
+    pub fn serial_tail(&self) -> Option<&str> {
+        let serial: &str = self.fingerprint.strip_prefix("serial:")?;
+        let start: usize = serial
+            .char_indices()
+            .rev()
+            .nth(SERIAL_TAIL - 1)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        Some(&serial[start..])
+    }
+
+    /// Подсказка в приглашении.
+    pub fn prompt(&self) -> String {
+        match self.serial_tail() {
+            Some(tail) => trf(
+                "confirm.prompt_serial",
+                &[&self.name(), &SERIAL_TAIL, &tail],
+            ),
+            None => trf("confirm.prompt", &[&self.name()]),
+        }
+    }
+
+    /// Совпадает ли ответ: имя, полный путь или хвост серийного номера
+    /// (без учёта регистра). Пустой ответ не подходит никогда.
+    pub fn accepts(&self, answer: &str) -> bool {
+        let answer: &str = answer.trim();
+        if answer.is_empty() {
+            return false;
+        }
+        answer == self.name()
+            || answer == self.device_path
+            || self
+                .serial_tail()
+                .is_some_and(|tail| answer.eq_ignore_ascii_case(tail))
+    }
+}
+
+/// Спросить подтверждение для каждого устройства по очереди.
+/// `Ok(false)` — ответ не совпал или ввод закрыт.
+pub fn ask(targets: &[Target], input: &mut impl BufRead) -> io::Result<bool> {
+    let mut stdout = io::stdout();
+    for target in targets {
+        print!("{}", target.prompt());
+        stdout.flush()?;
+        let mut answer: String = String::new();
+        if input.read_line(&mut answer)? == 0 || !target.accepts(&answer) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Стандартный ввод — терминал, есть кого спросить.
+pub fn stdin_is_tty() -> bool {
+    // SAFETY: isatty только читает состояние дескриптора.
+    unsafe { libc::isatty(libc::STDIN_FILENO) == 1 }
+}
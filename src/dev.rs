@@ -107,7 +107,60 @@ pub fn open_device_writable(dev_path: &str, mode: SyncMode) -> io::Result<File>
     {
         Err(io::Error::new(
             io::ErrorKind::Other,
-            "Поддерживаются только Linux и macOS",
+            crate::i18n::Message::new("dev.unsupported_os"),
+        ))
+    }
+}
+
+/// Открыть устройство на чтение (для проверки после стирания).
+/// В режиме direct (Linux) используем O_DIRECT, иначе сбрасываем page cache
+/// устройства, чтобы читать с носителя, а не из памяти.
+pub fn open_device_readable(dev_path: &str, mode: SyncMode) -> io::Result<File> {
+    #[cfg(target_os = "linux")]
+    {
+        use libc::{O_DIRECT, O_RDONLY, POSIX_FADV_DONTNEED, open, posix_fadvise};
+        let c: CString = path_to_cstring(dev_path)?;
+        let flags: c_int = if mode.is_direct() {
+            O_RDONLY | O_DIRECT
+        } else {
+            O_RDONLY
+        };
+        let fd: c_int = unsafe { open(c.as_ptr(), flags, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let f: File = unsafe { File::from_raw_fd(fd) };
+        if !mode.is_direct() {
+            // Чистые страницы после sync выбрасываются; ошибка не критична.
+            unsafe {
+                let _ = posix_fadvise(f.as_raw_fd(), 0, 0, POSIX_FADV_DONTNEED);
+            }
+        }
+        Ok(f)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use libc::{F_NOCACHE, O_RDONLY, fcntl, open};
+        let c: CString = path_to_cstring(dev_path)?;
+        let fd: c_int = unsafe { open(c.as_ptr(), O_RDONLY, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let f: File = unsafe { File::from_raw_fd(fd) };
+        unsafe {
+            let _ = fcntl(f.as_raw_fd(), F_NOCACHE, 1);
+        }
+        let _: SyncMode = mode;
+        Ok(f)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = (dev_path, mode);
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            crate::i18n::Message::new("dev.unsupported_os"),
         ))
     }
 }
@@ -163,7 +216,7 @@ pub fn full_sync(file: &File) -> io::Result<()> {
     {
         Err(io::Error::new(
             io::ErrorKind::Other,
-            "Поддерживаются только Linux и macOS",
+            crate::i18n::Message::new("dev.unsupported_os"),
         ))
     }
 }
@@ -214,6 +267,19 @@ fn path_to_cstring(dev_path: &str) -> io::Result<CString> {
     })
 }
 
+/// Разложить номер устройства (`st_rdev`/`st_dev`) на major и minor.
+#[cfg(target_os = "linux")]
+pub fn dev_major_minor(dev: u64) -> (u32, u32) {
+    let major: u64 = ((dev >> 8) & 0xfff) | ((dev >> 32) & !0xfff);
+    let minor: u64 = (dev & 0xff) | ((dev >> 12) & !0xff);
+    (major as u32, minor as u32)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn dev_major_minor(dev: u64) -> (u32, u32) {
+    (((dev >> 24) & 0xff) as u32, (dev & 0xff_ffff) as u32)
+}
+
 /// Размеры блока (логический и физический) в байтах.
 #[derive(Debug, Clone, Copy)]
 pub struct BlockSizes {
@@ -240,8 +306,14 @@ pub fn get_block_sizes(dev_path: &str) -> io::Result<BlockSizes> {
         .to_string_lossy()
         .into_owned();
 
-    let l_path: String = format!("/sys/class/block/{}/queue/logical_block_size", dev_name);
-    let p_path: String = format!("/sys/class/block/{}/queue/physical_block_size", dev_name);
+    // У раздела нет своего queue/ — размеры блоков берём у родительского диска.
+    let base: String = if Path::new(&format!("/sys/class/block/{}/partition", dev_name)).exists() {
+        format!("/sys/class/block/{}/..", dev_name)
+    } else {
+        format!("/sys/class/block/{}", dev_name)
+    };
+    let l_path: String = format!("{}/queue/logical_block_size", base);
+    let p_path: String = format!("{}/queue/physical_block_size", base);
 
     let logical: u32 = std::fs::read_to_string(&l_path)
         .ok()
@@ -279,7 +351,7 @@ pub fn get_block_sizes(dev_path: &str) -> io::Result<BlockSizes> {
 pub fn get_block_sizes(_dev_path: &str) -> io::Result<BlockSizes> {
     Err(io::Error::new(
         io::ErrorKind::Other,
-        "Поддерживаются только Linux и macOS",
+        crate::i18n::Message::new("dev.unsupported_os"),
     ))
 }
 
@@ -318,6 +390,13 @@ pub fn get_device_size_bytes(dev_path: &str) -> std::io::Result<u64> {
     // BLKGETSIZE64 = _IOR(0x12, 114, size_t) -> 0x80081272 на Linux
     const BLKGETSIZE64: c_ulong = 0x8008_1272;
 
+    // 0) Файл-образ: размер берём из метаданных.
+    if let Ok(meta) = std::fs::metadata(dev_path)
+        && meta.is_file()
+    {
+        return Ok(meta.len());
+    }
+
     // 1) Пытаемся через ioctl
     if let Ok(f) = File::open(dev_path) {
         let fd = f.as_raw_fd();
@@ -355,6 +434,10 @@ pub fn get_device_size_bytes(dev_path: &str) -> io::Result<u64> {
     const DKIOCGETBLOCKCOUNT: c_ulong = 0x4008_6419; // _IOR('d', 25, u64)
 
     let f: File = File::open(dev_path)?;
+    let meta = f.metadata()?;
+    if meta.is_file() {
+        return Ok(meta.len());
+    }
     let fd = f.as_raw_fd();
 
     let mut block_size: u32 = 0;
@@ -375,6 +458,6 @@ pub fn get_device_size_bytes(dev_path: &str) -> io::Result<u64> {
 pub fn get_device_size_bytes(_dev_path: &str) -> io::Result<u64> {
     Err(io::Error::new(
         io::ErrorKind::Other,
-        "Поддерживаются только Linux и macOS",
+        crate::i18n::Message::new("dev.unsupported_os"),
     ))
 }
@@ -0,0 +1,716 @@
+//! Подпись Ed25519 (RFC 8032) и SHA-512 (FIPS 180-4) для отчётов `--report`.
+//!
+//! Своя реализация, чтобы не тянуть зависимости: поле GF(2^255 - 19) в пяти
+//! 51-битных лимбах, точки в расширенных координатах, скаляры по модулю L.
+//! Умножение на секретный скаляр идёт без ветвлений по его битам.
+
+/// Длина ключа (сида) и открытого ключа.
+pub const KEY_LEN: usize = 32;
+/// Длина подписи: R (32 байта) + S (32 байта).
+pub const SIGNATURE_LEN: usize = 64;
+
+// ---------- SHA-512 ----------
+
+const K512: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// Потоковый SHA-512.
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    block: [u8; 128],
+    /// Сколько байт уже лежит в `block`.
+    filled: usize,
+    /// Всего байт сообщения.
+    len: u128,
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha512 {
+    pub fn new() -> Self {
+        Self {
+            state: [
+                0x6a09e667f3bcc908,
+                0xbb67ae8584caa73b,
+                0x3c6ef372fe94f82b,
+                0xa54ff53a5f1d36f1,
+                0x510e527fade682d1,
+                0x9b05688c2b3e6c1f,
+                0x1f83d9abfb41bd6b,
+                0x5be0cd19137e2179,
+            ],
+            block: [0u8; 128],
+            filled: 0,
+            len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u128;
+        while !data.is_empty() {
+            let take: usize = (128 - self.filled).min(data.len());
+            self.block[self.filled..self.filled + take].copy_from_slice(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == 128 {
+                let block: [u8; 128] = self.block;
+                self.compress(&block);
+                self.filled = 0;
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> [u8; 64] {
+        let bits: u128 = self.len * 8;
+        let mut tail = [0u8; 256];
+        tail[..self.filled].copy_from_slice(&self.block[..self.filled]);
+        tail[self.filled] = 0x80;
+        let total: usize = if self.filled < 112 { 128 } else { 256 };
+        tail[total - 16..total].copy_from_slice(&bits.to_be_bytes());
+        for chunk in tail[..total].chunks_exact(128) {
+            let block: [u8; 128] = chunk.try_into().expect("128 байт");
+            self.compress(&block);
+        }
+        let mut out = [0u8; 64];
+        for (dst, word) in out.chunks_exact_mut(8).zip(self.state) {
+            dst.copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+
+    fn compress(&mut self, block: &[u8; 128]) {
+        let mut w = [0u64; 80];
+        for (i, chunk) in block.chunks_exact(8).enumerate() {
+            w[i] = u64::from_be_bytes(chunk.try_into().expect("8 байт"));
+        }
+        for i in 16..80 {
+            let s0: u64 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1: u64 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for i in 0..80 {
+            let s1: u64 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch: u64 = (e & f) ^ (!e & g);
+            let t1: u64 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K512[i])
+                .wrapping_add(w[i]);
+            let s0: u64 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj: u64 = (a & b) ^ (a & c) ^ (b & c);
+            let t2: u64 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+/// SHA-512 от сообщения целиком.
+pub fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut h = Sha512::new();
+    h.update(data);
+    h.finish()
+}
+
+// ---------- Поле GF(2^255 - 19) ----------
+
+const MASK51: u64 = (1 << 51) - 1;
+
+/// Элемент поля: пять 51-битных лимбов (после операций — чуть больше, до 2^54).
+#[derive(Clone, Copy)]
+struct Fe([u64; 5]);
+
+impl Fe {
+    const ZERO: Fe = Fe([0; 5]);
+    const ONE: Fe = Fe([1, 0, 0, 0, 0]);
+
+    fn from_u64(v: u64) -> Fe {
+        Fe([v & MASK51, v >> 51, 0, 0, 0])
+    }
+
+    /// Из 32 байт little-endian; старший бит игнорируется.
+    fn from_bytes(b: &[u8; 32]) -> Fe {
+        let w = |i: usize| u64::from_le_bytes(b[i * 8..i * 8 + 8].try_into().expect("8 байт"));
+        let (w0, w1, w2, w3) = (w(0), w(1), w(2), w(3));
+        Fe([
+            w0 & MASK51,
+            ((w0 >> 51) | (w1 << 13)) & MASK51,
+            ((w1 >> 38) | (w2 << 26)) & MASK51,
+            ((w2 >> 25) | (w3 << 39)) & MASK51,
+            (w3 >> 12) & MASK51,
+        ])
+    }
+
+    /// Каноническое представление (значение < p).
+    fn to_bytes(self) -> [u8; 32] {
+        let mut l: [u64; 5] = self.carry().0;
+        let mut q: u64 = (l[0] + 19) >> 51;
+        q = (l[1] + q) >> 51;
+        q = (l[2] + q) >> 51;
+        q = (l[3] + q) >> 51;
+        q = (l[4] + q) >> 51;
+        l[0] += 19 * q;
+        l[1] += l[0] >> 51;
+        l[0] &= MASK51;
+        l[2] += l[1] >> 51;
+        l[1] &= MASK51;
+        l[3] += l[2] >> 51;
+        l[2] &= MASK51;
+        l[4] += l[3] >> 51;
+        l[3] &= MASK51;
+        l[4] &= MASK51;
+        let mut out = [0u8; 32];
+        out[0..8].copy_from_slice(&(l[0] | (l[1] << 51)).to_le_bytes());
+        out[8..16].copy_from_slice(&((l[1] >> 13) | (l[2] << 38)).to_le_bytes());
+        out[16..24].copy_from_slice(&((l[2] >> 26) | (l[3] << 25)).to_le_bytes());
+        out[24..32].copy_from_slice(&((l[3] >> 39) | (l[4] << 12)).to_le_bytes());
+        out
+    }
+
+    /// Перенос между лимбами: каждый снова укладывается в ~51 бит.
+    fn carry(self) -> Fe {
+        let mut l: [u64; 5] = self.0;
+        let c0: u64 = l[0] >> 51;
+        let c1: u64 = l[1] >> 51;
+        let c2: u64 = l[2] >> 51;
+        let c3: u64 = l[3] >> 51;
+        let c4: u64 = l[4] >> 51;
+        l[0] &= MASK51;
+        l[1] &= MASK51;
+        l[2] &= MASK51;
+        l[3] &= MASK51;
+        l[4] &= MASK51;
+        l[0] += c4 * 19;
+        l[1] += c0;
+        l[2] += c1;
+        l[3] += c2;
+        l[4] += c3;
+        Fe(l)
+    }
+
+    fn add(self, rhs: Fe) -> Fe {
+        let mut l: [u64; 5] = self.0;
+        for (a, b) in l.iter_mut().zip(rhs.0) {
+            *a += b;
+        }
+        Fe(l)
+    }
+
+    /// Вычитание через `self + 16p - rhs`, чтобы лимбы не уходили в минус.
+    fn sub(self, rhs: Fe) -> Fe {
+        const P16_0: u64 = 36028797018963664;
+        const P16: u64 = 36028797018963952;
+        let a: [u64; 5] = self.0;
+        let b: [u64; 5] = rhs.0;
+        Fe([
+            (a[0] + P16_0) - b[0],
+            (a[1] + P16) - b[1],
+            (a[2] + P16) - b[2],
+            (a[3] + P16) - b[3],
+            (a[4] + P16) - b[4],
+        ])
+        .carry()
+    }
+
+    fn neg(self) -> Fe {
+        Fe::ZERO.sub(self)
+    }
+
+    fn mul(self, rhs: Fe) -> Fe {
+        let a: [u64; 5] = self.0;
+        let b: [u64; 5] = rhs.0;
+        let m = |x: u64, y: u64| -> u128 { x as u128 * y as u128 };
+        let (b1, b2, b3, b4) = (b[1] * 19, b[2] * 19, b[3] * 19, b[4] * 19);
+        let c0: u128 = m(a[0], b[0]) + m(a[4], b1) + m(a[3], b2) + m(a[2], b3) + m(a[1], b4);
+        let mut c1: u128 = m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2) + m(a[3], b3) + m(a[2], b4);
+        let mut c2: u128 =
+            m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3) + m(a[3], b4);
+        let mut c3: u128 =
+            m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4);
+        let mut c4: u128 =
+            m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]);
+        c1 += c0 >> 51;
+        c2 += c1 >> 51;
+        c3 += c2 >> 51;
+        c4 += c3 >> 51;
+        let mut l: [u64; 5] = [
+            c0 as u64 & MASK51,
+            c1 as u64 & MASK51,
+            c2 as u64 & MASK51,
+            c3 as u64 & MASK51,
+            c4 as u64 & MASK51,
+        ];
+        l[0] += (c4 >> 51) as u64 * 19;
+        l[1] += l[0] >> 51;
+        l[0] &= MASK51;
+        Fe(l)
+    }
+
+    fn square(self) -> Fe {
+        self.mul(self)
+    }
+
+    /// Возведение в степень с открытым показателем (little-endian).
+    fn pow(self, exp: &[u8; 32]) -> Fe {
+        let mut acc: Fe = Fe::ONE;
+        for i in (0..256).rev() {
+            acc = acc.square();
+            if (exp[i / 8] >> (i % 8)) & 1 == 1 {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+
+    /// Обратный элемент: a^(p-2).
+    fn invert(self) -> Fe {
+        let mut exp = [0xffu8; 32];
+        exp[0] = 0xeb;
+        exp[31] = 0x7f;
+        self.pow(&exp)
+    }
+
+    /// a^((p-5)/8) — для извлечения корня при распаковке точки.
+    fn pow_p58(self) -> Fe {
+        let mut exp = [0xffu8; 32];
+        exp[0] = 0xfd;
+        exp[31] = 0x0f;
+        self.pow(&exp)
+    }
+
+    fn is_negative(self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    fn is_zero(self) -> bool {
+        self.to_bytes() == [0u8; 32]
+    }
+
+    fn equals(self, rhs: Fe) -> bool {
+        self.to_bytes() == rhs.to_bytes()
+    }
+
+    /// `b`, если `flag`, иначе `a` — без ветвления.
+    fn select(a: Fe, b: Fe, flag: u64) -> Fe {
+        let mask: u64 = 0u64.wrapping_sub(flag);
+        let mut l: [u64; 5] = a.0;
+        for (x, y) in l.iter_mut().zip(b.0) {
+            *x ^= mask & (*x ^ y);
+        }
+        Fe(l)
+    }
+}
+
+/// d = -121665/121666.
+fn curve_d() -> Fe {
+    Fe::from_u64(121665)
+        .neg()
+        .mul(Fe::from_u64(121666).invert())
+}
+
+/// √-1 = 2^((p-1)/4).
+fn sqrt_m1() -> Fe {
+    let mut exp = [0xffu8; 32];
+    exp[0] = 0xfb;
+    exp[31] = 0x1f;
+    Fe::from_u64(2).pow(&exp)
+}
+
+// ---------- Точки кривой ----------
+
+/// Точка в расширенных координатах: x = X/Z, y = Y/Z, xy = T/Z.
+#[derive(Clone, Copy)]
+struct Point {
+    x: Fe,
+    y: Fe,
+    z: Fe,
+    t: Fe,
+}
+
+/// Сжатое представление базовой точки B (y = 4/5, x чётный).
+const BASE_POINT: [u8; 32] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+
+impl Point {
+    const IDENTITY: Point = Point {
+        x: Fe::ZERO,
+        y: Fe::ONE,
+        z: Fe::ONE,
+        t: Fe::ZERO,
+    };
+
+    fn base() -> Point {
+        Point::decompress(&BASE_POINT).expect("базовая точка")
+    }
+
+    fn add(&self, q: &Point, d2: Fe) -> Point {
+        let a: Fe = self.y.sub(self.x).mul(q.y.sub(q.x));
+        let b: Fe = self.y.add(self.x).mul(q.y.add(q.x));
+        let c: Fe = self.t.mul(d2).mul(q.t);
+        let d: Fe = self.z.add(self.z).mul(q.z);
+        let e: Fe = b.sub(a);
+        let f: Fe = d.sub(c);
+        let g: Fe = d.add(c);
+        let h: Fe = b.add(a);
+        Point {
+            x: e.mul(f),
+            y: g.mul(h),
+            z: f.mul(g),
+            t: e.mul(h),
+        }
+    }
+
+    fn double(&self) -> Point {
+        let a: Fe = self.x.square();
+        let b: Fe = self.y.square();
+        let zz: Fe = self.z.square();
+        let c: Fe = zz.add(zz);
+        let h: Fe = a.add(b);
+        let e: Fe = h.sub(self.x.add(self.y).square());
+        let g: Fe = a.sub(b);
+        let f: Fe = c.add(g);
+        Point {
+            x: e.mul(f),
+            y: g.mul(h),
+            z: f.mul(g),
+            t: e.mul(h),
+        }
+    }
+
+    fn neg(&self) -> Point {
+        Point {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+
+    /// [k]P: на каждом бите и удвоение, и сложение, результат выбирается маской.
+    fn mul(&self, k: &[u8; 32]) -> Point {
+        let d2: Fe = {
+            let d: Fe = curve_d();
+            d.add(d)
+        };
+        let mut acc: Point = Point::IDENTITY;
+        for i in (0..256).rev() {
+            acc = acc.double();
+            let sum: Point = acc.add(self, d2);
+            let bit: u64 = ((k[i / 8] >> (i % 8)) & 1) as u64;
+            acc = Point {
+                x: Fe::select(acc.x, sum.x, bit),
+                y: Fe::select(acc.y, sum.y, bit),
+                z: Fe::select(acc.z, sum.z, bit),
+                t: Fe::select(acc.t, sum.t, bit),
+            };
+        }
+        acc
+    }
+
+    fn compress(&self) -> [u8; 32] {
+        let zi: Fe = self.z.invert();
+        let x: Fe = self.x.mul(zi);
+        let y: Fe = self.y.mul(zi);
+        let mut out: [u8; 32] = y.to_bytes();
+        out[31] ^= (x.is_negative() as u8) << 7;
+        out
+    }
+
+    /// Распаковка по RFC 8032 §5.1.3; неканоническое y и точки вне кривой — `None`.
+    fn decompress(bytes: &[u8; 32]) -> Option<Point> {
+        let sign: bool = bytes[31] >> 7 == 1;
+        let mut y_bytes: [u8; 32] = *bytes;
+        y_bytes[31] &= 0x7f;
+        let y: Fe = Fe::from_bytes(&y_bytes);
+        if y.to_bytes() != y_bytes {
+            return None;
+        }
+        let yy: Fe = y.square();
+        let u: Fe = yy.sub(Fe::ONE);
+        let v: Fe = curve_d().mul(yy).add(Fe::ONE);
+        let v3: Fe = v.square().mul(v);
+        let v7: Fe = v3.square().mul(v);
+        let mut x: Fe = u.mul(v3).mul(u.mul(v7).pow_p58());
+        let vxx: Fe = v.mul(x.square());
+        if !vxx.equals(u) {
+            if vxx.equals(u.neg()) {
+                x = x.mul(sqrt_m1());
+            } else {
+                return None;
+            }
+        }
+        if x.is_zero() && sign {
+            return None;
+        }
+        if x.is_negative() != sign {
+            x = x.neg();
+        }
+        Some(Point {
+            x,
+            y,
+            z: Fe::ONE,
+            t: x.mul(y),
+        })
+    }
+}
+
+// ---------- Скаляры по модулю L = 2^252 + 27742317777372353535851937790883648493 ----------
+
+const L: [u64; 4] = [
+    0x5812631a5cf5d3ed,
+    0x14def9dea2f79cd6,
+    0,
+    0x1000000000000000,
+];
+
+/// Остаток от деления 512-битного числа (little-endian) на L: сдвиг и
+/// вычитание по битам, вычитание выбирается маской.
+fn reduce_wide(wide: &[u64; 8]) -> [u8; 32] {
+    let mut r = [0u64; 4];
+    for i in (0..512).rev() {
+        let bit: u64 = (wide[i / 64] >> (i % 64)) & 1;
+        r[3] = (r[3] << 1) | (r[2] >> 63);
+        r[2] = (r[2] << 1) | (r[1] >> 63);
+        r[1] = (r[1] << 1) | (r[0] >> 63);
+        r[0] = (r[0] << 1) | bit;
+        let mut diff = [0u64; 4];
+        let mut borrow: u64 = 0;
+        for j in 0..4 {
+            let (d1, b1) = r[j].overflowing_sub(L[j]);
+            let (d2, b2) = d1.overflowing_sub(borrow);
+            diff[j] = d2;
+            borrow = (b1 | b2) as u64;
+        }
+        // Заём — значит r < L, оставляем как было.
+        let keep: u64 = 0u64.wrapping_sub(borrow);
+        for j in 0..4 {
+            r[j] = (r[j] & keep) | (diff[j] & !keep);
+        }
+    }
+    let mut out = [0u8; 32];
+    for (dst, limb) in out.chunks_exact_mut(8).zip(r) {
+        dst.copy_from_slice(&limb.to_le_bytes());
+    }
+    out
+}
+
+fn limbs(bytes: &[u8]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    for (limb, chunk) in out.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("8 байт"));
+    }
+    out
+}
+
+/// 64-байтный хэш как скаляр по модулю L.
+fn scalar_from_hash(hash: &[u8; 64]) -> [u8; 32] {
+    let mut wide = [0u64; 8];
+    for (limb, chunk) in wide.iter_mut().zip(hash.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("8 байт"));
+    }
+    reduce_wide(&wide)
+}
+
+/// (a·b + c) mod L.
+fn scalar_mul_add(a: &[u8; 32], b: &[u8; 32], c: &[u8; 32]) -> [u8; 32] {
+    let (a, b, c) = (limbs(a), limbs(b), limbs(c));
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry: u128 = 0;
+        for j in 0..4 {
+            let t: u128 = wide[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+            wide[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        wide[i + 4] = carry as u64;
+    }
+    let mut carry: u128 = 0;
+    for (i, limb) in wide.iter_mut().enumerate() {
+        let t: u128 = *limb as u128 + if i < 4 { c[i] as u128 } else { 0 } + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    reduce_wide(&wide)
+}
+
+/// Скаляр подписи канонический: S < L.
+fn scalar_is_canonical(s: &[u8; 32]) -> bool {
+    let s: [u64; 4] = limbs(s);
+    for j in (0..4).rev() {
+        if s[j] != L[j] {
+            return s[j] < L[j];
+        }
+    }
+    false
+}
+
+// ---------- Ключи и подписи ----------
+
+/// Закрытый ключ Ed25519: 32-байтный сид и выведенные из него скаляр и префикс.
+pub struct SigningKey {
+    scalar: [u8; 32],
+    prefix: [u8; 32],
+    public: [u8; KEY_LEN],
+}
+
+impl SigningKey {
+    pub fn from_seed(seed: &[u8; KEY_LEN]) -> Self {
+        let h: [u8; 64] = sha512(seed);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&h[..32]);
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+        let public: [u8; 32] = Point::base().mul(&scalar).compress();
+        Self {
+            scalar,
+            prefix,
+            public,
+        }
+    }
+
+    pub fn public_key(&self) -> [u8; KEY_LEN] {
+        self.public
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; SIGNATURE_LEN] {
+        let mut h = Sha512::new();
+        h.update(&self.prefix);
+        h.update(message);
+        let r: [u8; 32] = scalar_from_hash(&h.finish());
+        let big_r: [u8; 32] = Point::base().mul(&r).compress();
+        let k: [u8; 32] = challenge(&big_r, &self.public, message);
+        let s: [u8; 32] = scalar_mul_add(&k, &self.scalar, &r);
+        let mut sig = [0u8; SIGNATURE_LEN];
+        sig[..32].copy_from_slice(&big_r);
+        sig[32..].copy_from_slice(&s);
+        sig
+    }
+}
+
+/// k = SHA-512(R || A || M) mod L.
+fn challenge(r: &[u8; 32], public: &[u8; KEY_LEN], message: &[u8]) -> [u8; 32] {
+    let mut h = Sha512::new();
+    h.update(r);
+    h.update(public);
+    h.update(message);
+    scalar_from_hash(&h.finish())
+}
+
+/// Проверить подпись: [S]B == R + [k]A.
+pub fn verify(public: &[u8; KEY_LEN], message: &[u8], signature: &[u8; SIGNATURE_LEN]) -> bool {
+    let Some(a) = Point::decompress(public) else {
+        return false;
+    };
+    let r: [u8; 32] = signature[..32].try_into().expect("32 байта");
+    let s: [u8; 32] = signature[32..].try_into().expect("32 байта");
+    if !scalar_is_canonical(&s) {
+        return false;
+    }
+    let k: [u8; 32] = challenge(&r, public, message);
+    let d: Fe = curve_d();
+    let check: Point = Point::base().mul(&s).add(&a.neg().mul(&k), d.add(d));
+    check.compress() == r
+}
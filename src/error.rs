@@ -0,0 +1,255 @@
+//! Ошибки стирания и коды выхода. Коды и поле `kind` — часть интерфейса
+//! для скриптов (таблица в README, «Коды выхода»): новые виды ошибок
+//! получают новые коды, старые не меняются.
+
+use crate::events::{Json, quote};
+use crate::i18n::{Message, neutral, trf};
+use crate::inuse::Usage;
+use crate::signals;
+use crate::verify::VerifyReport;
+use std::io;
+
+/// Неверные аргументы или параметры, отказ в подтверждении и прочие ошибки
+/// вне устройства (отчёт, ключ подписи, поток событий).
+pub const EXIT_FAILURE: i32 = 1;
+/// Проверка чтением нашла расхождения.
+pub const EXIT_VERIFY_FAILED: i32 = 2;
+/// Устройство не открывается: нет такого пути, не устройство.
+pub const EXIT_OPEN: i32 = 3;
+/// Не удалось определить размер устройства.
+pub const EXIT_SIZE: i32 = 4;
+/// Устройство занято (EBUSY).
+pub const EXIT_BUSY: i32 = 5;
+/// Устройство только для чтения (EROFS).
+pub const EXIT_READ_ONLY: i32 = 6;
+/// Нет прав (EACCES/EPERM).
+pub const EXIT_PERMISSION: i32 = 7;
+/// Ошибка ввода-вывода при записи или чтении.
+pub const EXIT_IO: i32 = 8;
+/// Системный ГСЧ не дал сид.
+pub const EXIT_RNG: i32 = 9;
+/// Отказ по соображениям безопасности: системный диск, устройство занято системой.
+pub const EXIT_REFUSED: i32 = 10;
+/// `verify-report`: подпись верна, но ключ взят из самого отчёта — без
+/// доверенного ключа (`--pub-key`) это не доказывает, кто подписал.
+pub const EXIT_UNTRUSTED_KEY: i32 = 11;
+
+/// Почему не удалось стереть устройство.
+#[derive(Clone, Debug)]
+pub enum WipeError {
+    /// Устройство не открывается: нет такого пути, не устройство и т.п.
+    Open(Message),
+    /// Не удалось определить размер устройства.
+    Size(Message),
+    /// Устройство занято (EBUSY) — например, примонтировано.
+    Busy(Message),
+    /// Устройство только для чтения (EROFS): защита от записи, `blockdev --setro`.
+    ReadOnly(Message),
+    /// Нет прав (EACCES/EPERM) — обычно запуск без sudo.
+    Permission(Message),
+    /// Ошибка ввода-вывода. `pass` — номер прохода, `offset` — байт, с которого
+    /// запись (или чтение при проверке) не подтверждена; `None`, если ошибка
+    /// вне прохода. С журналом стирание можно продолжить.
+    Io {
+        pass: Option<usize>,
+        offset: Option<u64>,
+        message: Message,
+    },
+    /// Системный ГСЧ не дал сид.
+    Rng(Message),
+    /// Проверка чтением нашла расхождения.
+    Verify(VerifyReport),
+    /// Остановлено SIGINT/SIGTERM или `WipeJob::cancel`; записанное сброшено
+    /// на носитель.
+    Cancelled(Interruption),
+    /// Стирать небезопасно, и флаг, разрешающий это, не задан.
+    Refused(Refusal),
+    /// Параметры не подходят к устройству: участки, раздел, журнал.
+    Invalid(Message),
+}
+
+/// Где стирание остановилось: первый незаконченный проход и
+/// смещение, до которого он записан. Проход после последнего — все проходы
+/// записаны, прервана только проверка.
+#[derive(Clone, Copy, Debug)]
+pub struct Interruption {
+    /// `None` — остановлено `WipeJob::cancel`.
+    pub signal: Option<i32>,
+    pub pass: usize,
+    pub offset: u64,
+}
+
+/// Почему стирание отклонено до записи.
+#[derive(Clone, Debug)]
+pub enum Refusal {
+    /// На устройстве работающая система (без `--i-know-this-is-the-system-disk`).
+    SystemDisk(Vec<Usage>),
+    /// Устройство или стираемый раздел используется системой (без `--force`).
+    InUse(Vec<Usage>),
+    /// Проверку выполнить не удалось, а без неё стирать нельзя.
+    Unchecked(Message),
+}
+
+impl Refusal {
+    fn reason(&self) -> &'static str {
+        match self {
+            Refusal::SystemDisk(_) => "system_disk",
+            Refusal::InUse(_) => "in_use",
+            Refusal::Unchecked(_) => "unchecked",
+        }
+    }
+
+    fn usages(&self) -> &[Usage] {
+        match self {
+            Refusal::SystemDisk(usages) | Refusal::InUse(usages) => usages,
+            Refusal::Unchecked(_) => &[],
+        }
+    }
+}
+
+impl std::fmt::Display for WipeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WipeError::Open(msg)
+            | WipeError::Size(msg)
+            | WipeError::Busy(msg)
+            | WipeError::ReadOnly(msg)
+            | WipeError::Permission(msg)
+            | WipeError::Io { message: msg, .. }
+            | WipeError::Rng(msg)
+            | WipeError::Invalid(msg)
+            | WipeError::Refused(Refusal::Unchecked(msg)) => msg.fmt(f),
+            WipeError::Refused(Refusal::SystemDisk(usages)) => {
+                let what: Vec<String> = usages.iter().map(|u| u.reason.to_string()).collect();
+                f.write_str(&trf("error.system_disk", &[&what.join("; ")]))
+            }
+            WipeError::Refused(Refusal::InUse(usages)) => {
+                let what: Vec<String> = usages
+                    .iter()
+                    .map(|u| format!("{} {}", u.device, u.reason))
+                    .collect();
+                f.write_str(&trf("error.in_use", &[&what.join("; ")]))
+            }
+            WipeError::Verify(report) => f.write_str(&trf(
+                "error.verify",
+                &[&report.mismatched_bytes, &report.bytes_checked],
+            )),
+            WipeError::Cancelled(i) => f.write_str(&trf(
+                "error.cancelled",
+                &[&signals::signal_name(i.signal), &i.pass, &i.offset],
+            )),
+        }
+    }
+}
+
+impl std::error::Error for WipeError {}
+
+impl WipeError {
+    /// Ошибка вне прохода: подготовка, буферы.
+    pub fn io(message: Message) -> Self {
+        WipeError::Io {
+            pass: None,
+            offset: None,
+            message,
+        }
+    }
+
+    /// Ошибка открытия или размера по errno: занято, только чтение и нет прав
+    /// отдельно, отсутствующее устройство — `Open`, прочее — `fallback`.
+    pub fn from_os(context: Message, e: io::Error, fallback: fn(Message) -> WipeError) -> Self {
+        let errno: Option<i32> = e.raw_os_error();
+        let msg: Message = Message::new("error.context").arg(context).arg(e);
+        match errno {
+            Some(libc::EBUSY) => WipeError::Busy(msg),
+            Some(libc::EROFS) => WipeError::ReadOnly(msg),
+            Some(libc::EACCES | libc::EPERM) => WipeError::Permission(msg),
+            Some(libc::ENOENT | libc::ENXIO | libc::ENODEV | libc::ENOTDIR) => WipeError::Open(msg),
+            _ => fallback(msg),
+        }
+    }
+
+    /// Вид ошибки: поле `kind` в JSON.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WipeError::Open(_) => "open",
+            WipeError::Size(_) => "size",
+            WipeError::Busy(_) => "busy",
+            WipeError::ReadOnly(_) => "read_only",
+            WipeError::Permission(_) => "permission",
+            WipeError::Io { .. } => "io",
+            WipeError::Rng(_) => "rng",
+            WipeError::Verify(_) => "verify",
+            WipeError::Cancelled(_) => "cancelled",
+            WipeError::Refused(_) => "refused",
+            WipeError::Invalid(_) => "invalid",
+        }
+    }
+
+    /// Статус в событии `result` и в отчёте.
+    pub(crate) fn status(&self) -> &'static str {
+        match self {
+            WipeError::Verify(_) => "verify_failed",
+            WipeError::Cancelled(_) => "interrupted",
+            _ => "failed",
+        }
+    }
+
+    /// Код выхода CLI; после сигнала — 128 + номер сигнала, как у оболочки.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            WipeError::Open(_) => EXIT_OPEN,
+            WipeError::Size(_) => EXIT_SIZE,
+            WipeError::Busy(_) => EXIT_BUSY,
+            WipeError::ReadOnly(_) => EXIT_READ_ONLY,
+            WipeError::Permission(_) => EXIT_PERMISSION,
+            WipeError::Io { .. } => EXIT_IO,
+            WipeError::Rng(_) => EXIT_RNG,
+            WipeError::Verify(_) => EXIT_VERIFY_FAILED,
+            WipeError::Cancelled(i) => signals::exit_code(i.signal),
+            WipeError::Refused(_) => EXIT_REFUSED,
+            WipeError::Invalid(_) => EXIT_FAILURE,
+        }
+    }
+
+    /// Поля объекта ошибки: `kind`, `exit_code`, `message` и подробности вида.
+    pub fn json_fields(&self, json: Json) -> Json {
+        let json: Json = json
+            .str("kind", self.kind())
+            .raw("exit_code", &self.exit_code().to_string())
+            .str("message", &neutral(self));
+        let opt = |v: Option<u64>| v.map_or("null".to_string(), |v| v.to_string());
+        match self {
+            WipeError::Io { pass, offset, .. } => json
+                .raw("pass", &opt(pass.map(|p| p as u64)))
+                .raw("offset", &opt(*offset)),
+            WipeError::Verify(report) => json
+                .num("bytes_checked", report.bytes_checked)
+                .num("mismatched_bytes", report.mismatched_bytes),
+            WipeError::Cancelled(i) => json
+                .raw("signal", &opt(i.signal.map(|s| s as u64)))
+                .num("pass", i.pass as u64)
+                .num("offset", i.offset),
+            WipeError::Refused(refusal) => {
+                let usages: Vec<String> = refusal
+                    .usages()
+                    .iter()
+                    .map(|u| {
+                        format!(
+                            "{{\"device\":{},\"reason\":{}}}",
+                            quote(&u.device),
+                            quote(&neutral(&u.reason))
+                        )
+                    })
+                    .collect();
+                json.str("reason", refusal.reason())
+                    .raw("usages", &format!("[{}]", usages.join(",")))
+            }
+            _ => json,
+        }
+    }
+
+    /// Объект ошибки одной строкой JSON.
+    pub fn to_json(&self) -> String {
+        self.json_fields(Json::object()).finish()
+    }
+}
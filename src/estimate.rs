@@ -0,0 +1,94 @@
+use crate::extents::ExtentSet;
+use crate::i18n::Message;
+use crate::wipe::PassData;
+use std::time::Duration;
+
+/// Что сделает один проход: сколько записать и сколько потом перечитать.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassEstimate {
+    pub pass_no: usize,
+    pub describe: Message,
+    /// Байт, которые запишет программа (для прохода силами ядра — 0).
+    pub write_bytes: u64,
+    /// Байт, которые обработает ядро (zeroout/discard).
+    pub offload_bytes: u64,
+    /// Байт проверки чтением после прохода.
+    pub verify_bytes: u64,
+}
+
+/// План стирания в байтах — для `--dry-run`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WipeEstimate {
+    pub passes: Vec<PassEstimate>,
+}
+
+/// Что известно о запуске помимо проходов и участков.
+pub struct EstimateOptions {
+    /// С какого прохода (1-based) и смещения продолжаем (`--resume`).
+    pub first_pass: usize,
+    pub first_offset: u64,
+    pub verify: bool,
+    pub verify_each_pass: bool,
+    /// Цель — обычный файл: после discard в нём дыра, её тоже проверяем.
+    pub target_is_file: bool,
+}
+
+impl WipeEstimate {
+    /// Разложить оставшиеся проходы по байтам так же, как их выполнит `wipe_device`.
+    pub fn new(passes: &[PassData], extents: &ExtentSet, opts: &EstimateOptions) -> Self {
+        let total: u64 = extents.total();
+        let count: usize = passes.len();
+        let passes: Vec<PassEstimate> = passes
+            .iter()
+            .enumerate()
+            .skip(opts.first_pass.saturating_sub(1))
+            .map(|(i, data)| {
+                let pass_no: usize = i + 1;
+                let bytes: u64 = if pass_no == opts.first_pass && opts.first_offset > 0 {
+                    total - extents.bytes_before(opts.first_offset)
+                } else {
+                    total
+                };
+                let verified: bool = opts.verify_each_pass || (opts.verify && pass_no == count);
+                let (write_bytes, offload_bytes, checkable): (u64, u64, bool) = match data {
+                    PassData::Offload(kind) => (
+                        0,
+                        bytes,
+                        kind.leaves_zeros_on_device() || opts.target_is_file,
+                    ),
+                    _ => (bytes, 0, true),
+                };
+                PassEstimate {
+                    pass_no,
+                    describe: data.describe(),
+                    write_bytes,
+                    offload_bytes,
+                    verify_bytes: if verified && checkable { total } else { 0 },
+                }
+            })
+            .collect();
+        Self { passes }
+    }
+
+    pub fn write_bytes(&self) -> u64 {
+        self.passes.iter().map(|p| p.write_bytes).sum()
+    }
+
+    pub fn offload_bytes(&self) -> u64 {
+        self.passes.iter().map(|p| p.offload_bytes).sum()
+    }
+
+    pub fn verify_bytes(&self) -> u64 {
+        self.passes.iter().map(|p| p.verify_bytes).sum()
+    }
+
+    /// Оценка длительности по скорости чтения (байт/с). Запись редко бывает
+    /// быстрее чтения, так что это нижняя граница; проходы ядра не учитываются.
+    pub fn duration(&self, read_speed: f64) -> Option<Duration> {
+        if read_speed <= 0.0 {
+            return None;
+        }
+        let bytes: u64 = self.write_bytes() + self.verify_bytes();
+        Some(Duration::from_secs_f64(bytes as f64 / read_speed))
+    }
+}
@@ -0,0 +1,232 @@
+//! Поток событий `--progress=json`: одна JSON-строка на событие (NDJSON).
+//! Схема описана в README («JSON-прогресс»); несовместимые изменения
+//! повышают `SCHEMA_VERSION`, новые поля и события — нет.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Имя схемы в поле `schema` каждого события.
+pub const SCHEMA: &str = "destroyer.progress";
+/// Версия схемы в поле `v`.
+pub const SCHEMA_VERSION: u32 = 3;
+/// События `progress` — не чаще раза в этот интервал на устройство.
+pub const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Формат вывода прогресса (`--progress`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProgressFormat {
+    /// Строка статуса для человека.
+    #[default]
+    Text,
+    /// NDJSON-события по схеме `SCHEMA` версии `SCHEMA_VERSION`.
+    Json,
+}
+
+impl ProgressFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(ProgressFormat::Text),
+            "json" => Some(ProgressFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Одна JSON-строка события. Поля пишутся в порядке вызовов.
+pub struct Json {
+    buf: String,
+}
+
+impl Json {
+    /// Начать событие `name`: `schema`, `v`, `event`, `ts` (мс Unix-времени).
+    pub fn event(name: &str) -> Self {
+        let ts: u128 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let mut json = Self {
+            buf: String::with_capacity(256),
+        };
+        json.buf.push('{');
+        json.key("schema");
+        push_str(&mut json.buf, SCHEMA);
+        json.num("v", SCHEMA_VERSION as u64)
+            .str("event", name)
+            .raw("ts", &ts.to_string())
+    }
+
+    /// Объект без полей события — например, объект ошибки (`WipeError::to_json`).
+    pub fn object() -> Self {
+        Self {
+            buf: String::from("{"),
+        }
+    }
+
+    fn key(&mut self, key: &str) {
+        if self.buf.len() > 1 {
+            self.buf.push(',');
+        }
+        push_str(&mut self.buf, key);
+        self.buf.push(':');
+    }
+
+    pub fn str(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        push_str(&mut self.buf, value);
+        self
+    }
+
+    pub fn num(self, key: &str, value: u64) -> Self {
+        self.raw(key, &value.to_string())
+    }
+
+    /// Дробное число; NaN/бесконечность — `null`.
+    pub fn float(self, key: &str, value: f64) -> Self {
+        if value.is_finite() {
+            self.raw(key, &format!("{value:.3}"))
+        } else {
+            self.raw(key, "null")
+        }
+    }
+
+    /// Секунды с точностью до миллисекунд; `None` — `null` (ETA ещё неизвестен).
+    pub fn secs(self, key: &str, value: Option<Duration>) -> Self {
+        match value {
+            Some(d) => self.float(key, d.as_secs_f64()),
+            None => self.raw(key, "null"),
+        }
+    }
+
+    pub fn bool(self, key: &str, value: bool) -> Self {
+        self.raw(key, if value { "true" } else { "false" })
+    }
+
+    /// Готовый JSON-фрагмент (массив, объект) как значение поля.
+    pub fn raw(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        self.buf.push_str(value);
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        self.buf.push('}');
+        self.buf
+    }
+}
+
+/// Строка JSON в кавычках с экранированием.
+pub fn quote(s: &str) -> String {
+    let mut out: String = String::with_capacity(s.len() + 2);
+    push_str(&mut out, s);
+    out
+}
+
+fn push_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Куда пишутся события; клонируется между потоками стирания.
+#[derive(Clone)]
+pub struct EventStream {
+    out: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl EventStream {
+    pub fn new(out: impl Write + Send + 'static) -> Self {
+        Self {
+            out: Arc::new(Mutex::new(Box::new(out))),
+        }
+    }
+
+    /// События в stdout. Чтобы JSON не смешивался с текстом, исходный stdout
+    /// дублируется под события, а дескриптор 1 перенаправляется в stderr:
+    /// баннер, подсказки и приглашение подтверждения уходят туда.
+    pub fn take_stdout() -> io::Result<Self> {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: dup/dup2 над стандартными дескрипторами процесса.
+        let fd: libc::c_int = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: fd только что получен от dup и больше никому не принадлежит.
+        Ok(Self::new(unsafe { File::from_raw_fd(fd) }))
+    }
+
+    /// События в уже открытый дескриптор (`--progress-fd N`, например `3>events.jsonl`).
+    pub fn from_fd(fd: i32) -> io::Result<Self> {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: fcntl только проверяет, что дескриптор открыт.
+        if unsafe { libc::fcntl(fd, libc::F_GETFD) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: дескриптор передан нам вызывающим процессом для записи событий.
+        Ok(Self::new(unsafe { File::from_raw_fd(fd) }))
+    }
+
+    /// Записать событие строкой целиком. Ошибки записи (закрытый конвейер)
+    /// не останавливают стирание.
+    pub fn emit(&self, event: Json) {
+        let mut line: String = event.finish();
+        line.push('\n');
+        if let Ok(mut out) = self.out.lock() {
+            let _ = out.write_all(line.as_bytes());
+            let _ = out.flush();
+        }
+    }
+}
+
+/// События одного устройства: поле `device` подставляется само.
+#[derive(Clone)]
+pub struct DeviceEvents {
+    stream: EventStream,
+    device: String,
+    started: Instant,
+}
+
+impl DeviceEvents {
+    pub fn new(stream: EventStream, device: &str) -> Self {
+        Self {
+            stream,
+            device: device.to_string(),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn event(&self, name: &str) -> Json {
+        Json::event(name).str("device", &self.device)
+    }
+
+    pub fn emit(&self, event: Json) {
+        self.stream.emit(event);
+    }
+
+    /// Итог по устройству: `status` (`ok`, `failed`, `verify_failed`, `dry_run`),
+    /// код выхода и время с начала работы с устройством.
+    pub fn result(&self, status: &str, exit_code: i32) {
+        self.emit(
+            self.event("result")
+                .str("status", status)
+                .raw("exit_code", &exit_code.to_string())
+                .secs("elapsed_s", Some(self.started.elapsed())),
+        );
+    }
+}
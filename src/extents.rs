@@ -0,0 +1,320 @@
+use crate::i18n::Message;
+
+/// Непрерывный участок устройства `[start, start + len)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Extent {
+    pub start: u64,
+    pub len: u64,
+}
+
+impl Extent {
+    pub fn end(&self) -> u64 {
+        self.start + self.len
+    }
+
+    /// Выровненная по сектору середина участка (для O_DIRECT). Если участок
+    /// не содержит ни одного целого сектора — середины нет, весь он считается краем.
+    pub fn aligned_middle(&self, sector: u64) -> (u64, u64) {
+        let start: u64 = self.start.div_ceil(sector) * sector;
+        let end: u64 = self.end() - self.end() % sector;
+        if start >= end {
+            (self.end(), self.end())
+        } else {
+            (start, end)
+        }
+    }
+}
+
+/// Диапазон из `--range`/`--exclude` до привязки к размеру устройства.
+/// Отрицательное начало отсчитывается от конца устройства (`-64M:64M` — последние 64 MiB).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeSpec {
+    pub from_end: bool,
+    pub start: u64,
+    pub len: u64,
+}
+
+impl RangeSpec {
+    /// Разобрать `START:LEN`, например `0:64M`, `-64MiB:64MiB`, `2048s:1000s`.
+    pub fn parse(s: &str) -> Result<Self, Message> {
+        let (start, len) = s
+            .split_once(':')
+            .ok_or_else(|| Message::new("extents.expected_range").arg(s.to_string()))?;
+        let (from_end, start) = match start.trim().strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, start.trim()),
+        };
+        let start: u64 = parse_size(start)?;
+        let len: u64 = parse_size(len.trim())?;
+        if len == 0 {
+            return Err(Message::new("extents.zero_len").arg(s.to_string()));
+        }
+        if from_end && start == 0 {
+            return Err(Message::new("extents.zero_from_end").arg(s.to_string()));
+        }
+        if !from_end && start.checked_add(len).is_none() {
+            return Err(Message::new("extents.too_large").arg(s.to_string()));
+        }
+        // `-START:LEN` кончается на LEN - START байт после конца устройства.
+        if from_end && len > start {
+            return Err(Message::new("extents.from_end_past_end").arg(s.to_string()));
+        }
+        Ok(Self {
+            from_end,
+            start,
+            len,
+        })
+    }
+
+    /// Привязать к устройству размером `device_size`; выход за конец — ошибка.
+    pub fn resolve(&self, device_size: u64) -> Result<Extent, Message> {
+        let start: u64 = if self.from_end {
+            device_size.checked_sub(self.start).ok_or_else(|| {
+                Message::new("extents.from_end_too_far")
+                    .arg(self.start)
+                    .arg(device_size)
+            })?
+        } else {
+            self.start
+        };
+        let extent = Extent {
+            start,
+            len: self.len,
+        };
+        let end: u64 = start.checked_add(self.len).ok_or_else(|| {
+            Message::new("extents.too_large").arg(format!("{}:{}", start, self.len))
+        })?;
+        if end > device_size {
+            return Err(Message::new("extents.past_end")
+                .arg(extent.start)
+                .arg(extent.end())
+                .arg(device_size));
+        }
+        Ok(extent)
+    }
+}
+
+/// Разобрать размер с единицами: `4096`, `64K`/`64KiB`, `1M`/`1MiB`, `2G`, `1T`
+/// (двоичные), `1KB`/`1MB`/`1GB`/`1TB` (десятичные), `2048s` — 512-байтные сектора (LBA).
+pub fn parse_size(s: &str) -> Result<u64, Message> {
+    let digits_end: usize = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (num, unit) = s.split_at(digits_end);
+    if num.is_empty() {
+        return Err(Message::new("extents.expected_number").arg(s.to_string()));
+    }
+    let n: u64 = num
+        .parse::<u64>()
+        .map_err(|_| Message::new("extents.bad_number").arg(s.to_string()))?;
+    let mult: u64 = match unit {
+        "" | "B" => 1,
+        "s" => 512,
+        "K" | "k" | "KiB" => 1 << 10,
+        "M" | "MiB" => 1 << 20,
+        "G" | "GiB" => 1 << 30,
+        "T" | "TiB" => 1 << 40,
+        "KB" | "kB" => 1_000,
+        "MB" => 1_000_000,
+        "GB" => 1_000_000_000,
+        "TB" => 1_000_000_000_000,
+        other => {
+            return Err(Message::new("extents.unknown_unit")
+                .arg(other.to_string())
+                .arg(s.to_string()));
+        }
+    };
+    n.checked_mul(mult)
+        .ok_or_else(|| Message::new("extents.overflow").arg(s.to_string()))
+}
+
+/// Отсортированный набор непересекающихся участков, которые стираются.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtentSet {
+    extents: Vec<Extent>,
+}
+
+impl ExtentSet {
+    /// Всё устройство целиком.
+    pub fn full(device_size: u64) -> Self {
+        Self::from_extents(vec![Extent {
+            start: 0,
+            len: device_size,
+        }])
+    }
+
+    /// Нормализовать: отсортировать, склеить пересекающиеся и соседние участки,
+    /// выбросить пустые.
+    pub fn from_extents(mut extents: Vec<Extent>) -> Self {
+        extents.retain(|e| e.len > 0);
+        extents.sort_by_key(|e| e.start);
+        let mut merged: Vec<Extent> = Vec::with_capacity(extents.len());
+        for e in extents {
+            match merged.last_mut() {
+                Some(last) if e.start <= last.end() => {
+                    last.len = last.len.max(e.end() - last.start);
+                }
+                _ => merged.push(e),
+            }
+        }
+        Self { extents: merged }
+    }
+
+    /// Собрать набор из `--range` (нет ни одного — всё устройство) минус `--exclude`.
+    pub fn build(
+        device_size: u64,
+        ranges: &[RangeSpec],
+        excludes: &[RangeSpec],
+    ) -> Result<Self, Message> {
+        let mut set: ExtentSet = if ranges.is_empty() {
+            Self::full(device_size)
+        } else {
+            let resolved: Vec<Extent> = ranges
+                .iter()
+                .map(|r| r.resolve(device_size))
+                .collect::<Result<_, _>>()?;
+            Self::from_extents(resolved)
+        };
+        for ex in excludes {
+            set = set.subtract(ex.resolve(device_size)?);
+        }
+        if set.total() == 0 {
+            return Err(Message::new("extents.nothing_left"));
+        }
+        Ok(set)
+    }
+
+    /// Набор без участка `cut`.
+    pub fn subtract(&self, cut: Extent) -> Self {
+        let mut out: Vec<Extent> = Vec::with_capacity(self.extents.len() + 1);
+        for e in &self.extents {
+            if cut.end() <= e.start || cut.start >= e.end() {
+                out.push(*e);
+                continue;
+            }
+            if cut.start > e.start {
+                out.push(Extent {
+                    start: e.start,
+                    len: cut.start - e.start,
+                });
+            }
+            if cut.end() < e.end() {
+                out.push(Extent {
+                    start: cut.end(),
+                    len: e.end() - cut.end(),
+                });
+            }
+        }
+        Self { extents: out }
+    }
+
+    /// Пересечение двух наборов.
+    pub fn intersect(&self, other: &ExtentSet) -> Self {
+        let mut out: Vec<Extent> = Vec::new();
+        let (mut i, mut j): (usize, usize) = (0, 0);
+        while i < self.extents.len() && j < other.extents.len() {
+            let (a, b) = (self.extents[i], other.extents[j]);
+            let start: u64 = a.start.max(b.start);
+            let end: u64 = a.end().min(b.end());
+            if start < end {
+                out.push(Extent {
+                    start,
+                    len: end - start,
+                });
+            }
+            if a.end() <= b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { extents: out }
+    }
+
+    /// Сдвинуть все участки на `base` (диапазоны внутри раздела → смещения на диске).
+    pub fn offset_by(&self, base: u64) -> Self {
+        let extents: Vec<Extent> = self
+            .extents
+            .iter()
+            .map(|e| Extent {
+                start: e.start + base,
+                len: e.len,
+            })
+            .collect();
+        Self { extents }
+    }
+
+    pub fn extents(&self) -> &[Extent] {
+        &self.extents
+    }
+
+    /// Сколько байт стирается за один проход.
+    pub fn total(&self) -> u64 {
+        self.extents.iter().map(|e| e.len).sum()
+    }
+
+    /// Набор покрывает всё устройство `0..device_size`.
+    pub fn is_full(&self, device_size: u64) -> bool {
+        self.extents.len() == 1 && self.extents[0].start == 0 && self.extents[0].len == device_size
+    }
+
+    /// Сколько байт набора лежит до смещения `offset` (прогресс прохода,
+    /// дошедшего до `offset`).
+    pub fn bytes_before(&self, offset: u64) -> u64 {
+        self.extents
+            .iter()
+            .map(|e| offset.clamp(e.start, e.end()) - e.start)
+            .sum()
+    }
+
+    /// Участки до смещения `offset`; последний может быть обрезан справа.
+    pub fn before(&self, offset: u64) -> ExtentSet {
+        ExtentSet::from_extents(
+            self.extents
+                .iter()
+                .filter(|e| e.start < offset)
+                .map(|e| Extent {
+                    start: e.start,
+                    len: e.end().min(offset) - e.start,
+                })
+                .collect(),
+        )
+    }
+
+    /// Участки после смещения `offset`; первый может быть обрезан слева.
+    pub fn remaining_from(&self, offset: u64) -> impl Iterator<Item = Extent> + '_ {
+        self.extents.iter().filter_map(move |e| {
+            if e.end() <= offset {
+                None
+            } else if e.start >= offset {
+                Some(*e)
+            } else {
+                Some(Extent {
+                    start: offset,
+                    len: e.end() - offset,
+                })
+            }
+        })
+    }
+
+    /// Запись для журнала: `START:LEN` в байтах через запятую.
+    pub fn to_spec(&self) -> String {
+        let items: Vec<String> = self
+            .extents
+            .iter()
+            .map(|e| format!("{}:{}", e.start, e.len))
+            .collect();
+        items.join(",")
+    }
+
+    /// Разобрать запись `to_spec` и проверить, что она укладывается в устройство.
+    pub fn parse_spec(spec: &str, device_size: u64) -> Result<Self, Message> {
+        let mut extents: Vec<Extent> = Vec::new();
+        for item in spec.split(',') {
+            let r: RangeSpec = RangeSpec::parse(item.trim())?;
+            if r.from_end {
+                return Err(Message::new("extents.expected_absolute").arg(item.to_string()));
+            }
+            extents.push(r.resolve(device_size)?);
+        }
+        Ok(Self::from_extents(extents))
+    }
+}
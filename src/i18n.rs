@@ -0,0 +1,3541 @@
+//! Каталог сообщений CLI на трёх языках: русском, английском и китайском
+//! (zh-CN). Язык выбирается `--lang`, иначе по `LC_ALL`/`LC_MESSAGES`/`LANG`.
+//! Переводится только текст для людей: имена событий и ключи JSON, `kind`
+//! ошибок, статусы и коды выхода от языка не зависят. Поля `message`,
+//! `describe` и `reason` в JSON всегда по-английски: ошибки хранят
+//! `Message`, который переводится при выводе, а JSON выводится под `with_lang`.
+
+use std::cell::Cell;
+use std::fmt::{Display, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Язык сообщений.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lang {
+    Ru = 0,
+    En = 1,
+    Zh = 2,
+}
+
+/// Язык по умолчанию — русский, как было до каталога; CLI выбирает свой
+/// при запуске (`Lang::detect`).
+static CURRENT: AtomicU8 = AtomicU8::new(Lang::Ru as u8);
+
+thread_local! {
+    /// Язык, заданный `with_lang` для текущего потока поверх общего.
+    static OVERRIDE: Cell<Option<Lang>> = const { Cell::new(None) };
+}
+
+impl Lang {
+    pub const ALL: [Lang; 3] = [Lang::Ru, Lang::En, Lang::Zh];
+
+    /// `ru`, `en`, `zh-CN` или локаль вида `ru_RU.UTF-8`, `zh_CN`, `en_US@euro`.
+    /// `C` и `POSIX` — английский.
+    pub fn parse(s: &str) -> Option<Lang> {
+        let base: &str = s.split(['.', '@']).next().unwrap_or("");
+        if base == "C" || base == "POSIX" {
+            return Some(Lang::En);
+        }
+        let lang: String = base
+            .split(['_', '-'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match lang.as_str() {
+            "ru" => Some(Lang::Ru),
+            "en" => Some(Lang::En),
+            // Других китайских каталогов нет: zh_TW и zh_HK ближе всего к zh-CN.
+            "zh" => Some(Lang::Zh),
+            _ => None,
+        }
+    }
+
+    /// Код языка для `--lang`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Ru => "ru",
+            Lang::En => "en",
+            Lang::Zh => "zh-CN",
+        }
+    }
+
+    /// Язык из окружения: первая из `LC_ALL`, `LC_MESSAGES`, `LANG`, которую
+    /// удалось разобрать; незнакомая локаль (`de_DE`) передаёт ход следующей.
+    /// Если подходящей нет — английский.
+    pub fn from_env() -> Lang {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Lang {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| var(name))
+            .find_map(|value| Lang::parse(&value))
+            .unwrap_or(Lang::En)
+    }
+
+    /// Язык запуска: последнее значение `--lang` (`--lang X` или `--lang=X`),
+    /// которое разбирается, иначе окружение. Неверное значение `--lang`
+    /// сообщает разбор аргументов — уже на языке окружения.
+    pub fn detect<I, S>(args: I) -> Lang
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<std::ffi::OsStr>,
+    {
+        let mut args = args.into_iter();
+        let mut chosen: Option<Lang> = None;
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref().to_string_lossy().into_owned();
+            let value: Option<String> = match arg.strip_prefix("--lang=") {
+                Some(v) => Some(v.to_string()),
+                None if arg == "--lang" => args
+                    .next()
+                    .map(|v| v.as_ref().to_string_lossy().into_owned()),
+                None => None,
+            };
+            if let Some(lang) = value.as_deref().and_then(Lang::parse) {
+                chosen = Some(lang);
+            }
+        }
+        chosen.unwrap_or_else(Lang::from_env)
+    }
+}
+
+/// Выбрать язык сообщений для всего процесса.
+pub fn set_lang(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+pub fn lang() -> Lang {
+    if let Some(lang) = OVERRIDE.with(Cell::get) {
+        return lang;
+    }
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        2 => Lang::Zh,
+        _ => Lang::Ru,
+    }
+}
+
+/// Выполнить `f`, выводя сообщения этого потока на языке `lang`; остальные
+/// потоки и общий язык не меняются.
+pub fn with_lang<T>(lang: Lang, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Lang>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|o| o.set(self.0));
+        }
+    }
+    let _restore = Restore(OVERRIDE.with(|o| o.replace(Some(lang))));
+    f()
+}
+
+/// Текст для JSON: значение, выведенное по-английски.
+pub fn neutral(value: &dyn Display) -> String {
+    with_lang(Lang::En, || value.to_string())
+}
+
+/// Сообщение `key` на языке `lang`. Цепочка замен: выбранный язык →
+/// английский → русский (исходный текст есть всегда). Неизвестный ключ
+/// возвращается как есть — так пропуск в каталоге виден, но не роняет вывод.
+pub fn text(lang: Lang, key: &str) -> &str {
+    let Some((_, texts)) = CATALOG.iter().find(|(k, _)| *k == key) else {
+        return key;
+    };
+    [lang, Lang::En, Lang::Ru]
+        .iter()
+        .map(|l| texts[*l as usize])
+        .find(|t| !t.is_empty())
+        .unwrap_or(key)
+}
+
+/// Сообщение на текущем языке.
+pub fn tr(key: &str) -> &str {
+    text(lang(), key)
+}
+
+/// Сообщение с подстановкой: `{0}`, `{1}`… заменяются аргументами.
+/// Порядок в переводе может отличаться от исходного.
+pub fn trf(key: &str, args: &[&dyn Display]) -> String {
+    fill(tr(key), args)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out: String = String::with_capacity(template.len() + 16);
+    let mut rest: &str = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after: &str = &rest[open + 1..];
+        let arg: Option<(usize, &dyn Display)> = after.find('}').and_then(|close| {
+            let idx: usize = after[..close].parse().ok()?;
+            Some((close, *args.get(idx)?))
+        });
+        match arg {
+            Some((close, value)) => {
+                let _ = write!(out, "{value}");
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Сообщение, которое переводится при выводе, а не при создании: ключ
+/// каталога и аргументы, которые тоже выводятся лениво (вложенные `Message`,
+/// `io::Error`). Так одну ошибку можно показать человеку на его языке и
+/// записать в JSON по-английски.
+#[derive(Clone)]
+pub struct Message(MessageInner);
+
+#[derive(Clone)]
+enum MessageInner {
+    Catalog {
+        key: &'static str,
+        args: Vec<Arc<dyn Display + Send + Sync>>,
+    },
+    /// Значение, которое само выводится на текущем языке (ошибка и т.п.).
+    Value(Arc<dyn Display + Send + Sync>),
+    /// Готовый текст, без перевода.
+    Text(String),
+}
+
+impl Message {
+    pub fn new(key: &'static str) -> Self {
+        Message(MessageInner::Catalog {
+            key,
+            args: Vec::new(),
+        })
+    }
+
+    /// Добавить аргумент для следующей подстановки `{N}`.
+    pub fn arg(mut self, value: impl Display + Send + Sync + 'static) -> Self {
+        if let MessageInner::Catalog { args, .. } = &mut self.0 {
+            args.push(Arc::new(value));
+        }
+        self
+    }
+
+    /// Обернуть значение, которое переводит себя само при выводе, например ошибку.
+    pub fn wrap(value: impl Display + Send + Sync + 'static) -> Self {
+        Message(MessageInner::Value(Arc::new(value)))
+    }
+
+    /// Ключ каталога; `None` у готового текста и обёрнутого значения.
+    pub fn key(&self) -> Option<&'static str> {
+        match &self.0 {
+            MessageInner::Catalog { key, .. } => Some(key),
+            MessageInner::Value(_) | MessageInner::Text(_) => None,
+        }
+    }
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Message(MessageInner::Text(text))
+    }
+}
+
+impl From<&str> for Message {
+    fn from(text: &str) -> Self {
+        Message(MessageInner::Text(text.to_string()))
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            MessageInner::Catalog { key, args } => {
+                let args: Vec<&dyn Display> = args.iter().map(|a| a.as_ref() as _).collect();
+                f.write_str(&fill(tr(key), &args))
+            }
+            MessageInner::Value(value) => value.fmt(f),
+            MessageInner::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl std::fmt::Debug for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl std::error::Error for Message {}
+
+/// Сообщения равны, если одинаково выглядят по-английски.
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key() && neutral(self) == neutral(other)
+    }
+}
+
+impl Eq for Message {}
+
+/// Номера подстановок `{N}` в шаблоне — для проверки каталога.
+pub fn placeholders(template: &str) -> Vec<usize> {
+    let mut found: Vec<usize> = Vec::new();
+    let mut rest: &str = template;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        if let Some(close) = rest.find('}')
+            && let Ok(idx) = rest[..close].parse::<usize>()
+        {
+            found.push(idx);
+        }
+    }
+    found.sort_unstable();
+    found.dedup();
+    found
+}
+
+/// Ключ и тексты `[ru, en, zh-CN]`. Пустая строка — перевода нет, берётся
+/// следующий язык цепочки.
+pub static CATALOG: &[(&str, [&str; 3])] = &[
+    (
+        "app.platform",
+        [
+            "Платформа: {0}",
+            "Platform: {0}",
+            "平台：{0}",
+        ],
+    ),
+    (
+        "app.sign_key_failed",
+        [
+            "Не удалось прочитать ключ подписи {0}: {1}",
+            "Cannot read signing key {0}: {1}",
+            "无法读取签名密钥 {0}：{1}",
+        ],
+    ),
+    (
+        "app.journal_read_failed",
+        [
+            "Не удалось прочитать журнал {0}: {1}",
+            "Cannot read journal {0}: {1}",
+            "无法读取日志 {0}：{1}",
+        ],
+    ),
+    (
+        "app.device_size",
+        [
+            "Размер устройства: {0} байт ({1} GB)",
+            "Device size: {0} bytes ({1} GB)",
+            "设备大小：{0} 字节（{1} GB）",
+        ],
+    ),
+    (
+        "app.resuming",
+        [
+            "Возобновление: проход {0}/{1}, записано {2} байт прохода",
+            "Resuming: pass {0}/{1}, {2} bytes of the pass already written",
+            "恢复：第 {0}/{1} 遍，本遍已写入 {2} 字节",
+        ],
+    ),
+    (
+        "app.scheme",
+        [
+            "Схема: {0} — {1} проходов",
+            "Scheme: {0} — {1} passes",
+            "方案：{0} — {1} 遍",
+        ],
+    ),
+    (
+        "app.running_passes",
+        [
+            "Выполняется {0} проходов очистки...",
+            "Running {0} wipe passes...",
+            "正在执行 {0} 遍擦除...",
+        ],
+    ),
+    (
+        "app.plan",
+        [
+            "План: {0}",
+            "Plan: {0}",
+            "计划：{0}",
+        ],
+    ),
+    (
+        "app.partition",
+        [
+            "Раздел {0}",
+            "Partition {0}",
+            "分区 {0}",
+        ],
+    ),
+    (
+        "app.unallocated_only",
+        [
+            "Только неразмеченное место: разделы и служебные области таблицы не трогаются",
+            "Unallocated space only: partitions and partition table structures are left untouched",
+            "仅擦除未分配空间：分区及分区表结构不受影响",
+        ],
+    ),
+    (
+        "app.extents",
+        [
+            "Участки: {0}",
+            "Ranges: {0}",
+            "区段：{0}",
+        ],
+    ),
+    (
+        "app.mode",
+        [
+            "Режим: {0}",
+            "Mode: {0}",
+            "模式：{0}",
+        ],
+    ),
+    (
+        "app.seed",
+        [
+            "Сид: {0} (задан через --seed)",
+            "Seed: {0} (set with --seed)",
+            "种子：{0}（由 --seed 指定）",
+        ],
+    ),
+    (
+        "app.seed_shared",
+        [
+            "Сид: {0} (задан через --seed, общий для всех устройств)",
+            "Seed: {0} (set with --seed, shared by all devices)",
+            "种子：{0}（由 --seed 指定，所有设备共用）",
+        ],
+    ),
+    (
+        "app.blocks",
+        [
+            "Блоки: logical = {0}B, physical = {1}B; выбран буфер = {2}B",
+            "Blocks: logical = {0}B, physical = {1}B; buffer = {2}B",
+            "块：logical = {0}B，physical = {1}B；缓冲区 = {2}B",
+        ],
+    ),
+    (
+        "app.uring_off",
+        [
+            "io_uring: выключен, запись блокирующая",
+            "io_uring: off, blocking writes",
+            "io_uring：已关闭，使用阻塞写入",
+        ],
+    ),
+    (
+        "app.uring_depth",
+        [
+            "io_uring: до {0} записей в полёте",
+            "io_uring: up to {0} writes in flight",
+            "io_uring：最多 {0} 个写入同时进行",
+        ],
+    ),
+    (
+        "app.gen_inline",
+        [
+            "Генерация случайных данных: в потоке записи",
+            "Random data generation: on the writer thread",
+            "随机数据生成：在写入线程中",
+        ],
+    ),
+    (
+        "app.gen_threads",
+        [
+            "Генерация случайных данных: {0} поток(а), параллельно с записью",
+            "Random data generation: {0} thread(s), in parallel with writing",
+            "随机数据生成：{0} 个线程，与写入并行",
+        ],
+    ),
+    (
+        "app.final_pass",
+        [
+            "Финальный проход {0}/{1} ({2})...",
+            "Final pass {0}/{1} ({2})...",
+            "最终遍 {0}/{1}（{2}）...",
+        ],
+    ),
+    (
+        "app.pass",
+        [
+            "Проход {0}/{1} ({2})...",
+            "Pass {0}/{1} ({2})...",
+            "第 {0}/{1} 遍（{2}）...",
+        ],
+    ),
+    (
+        "app.pass_seed",
+        [
+            "Сид прохода: {0}",
+            "Pass seed: {0}",
+            "本遍种子：{0}",
+        ],
+    ),
+    (
+        "app.resuming_at",
+        [
+            "Продолжаем с байта {0}",
+            "Resuming at byte {0}",
+            "从字节 {0} 继续",
+        ],
+    ),
+    (
+        "app.verify_skipped",
+        [
+            "Проверка пропущена: после {0} содержимое устройства не определено",
+            "Verification skipped: after {0} the device contents are undefined",
+            "跳过校验：{0} 之后设备内容不确定",
+        ],
+    ),
+    (
+        "app.verify_start",
+        [
+            "Проверка: чтение устройства и сверка с записанным...",
+            "Verification: reading the device and comparing with what was written...",
+            "校验：读取设备并与写入内容比对...",
+        ],
+    ),
+    (
+        "app.verify_passed",
+        [
+            "Проверка пройдена: {0} байт совпадают с ожидаемым",
+            "Verification passed: {0} bytes match the expected data",
+            "校验通过：{0} 字节与预期一致",
+        ],
+    ),
+    (
+        "app.journal",
+        [
+            "Журнал: {0}",
+            "Journal: {0}",
+            "日志：{0}",
+        ],
+    ),
+    (
+        "app.in_use_forced",
+        [
+            "ВНИМАНИЕ (--force): {0} {1}",
+            "WARNING (--force): {0} {1}",
+            "警告（--force）：{0} {1}",
+        ],
+    ),
+    (
+        "app.warning_destroy",
+        [
+            "ВНИМАНИЕ: все данные на устройстве будут уничтожены!",
+            "WARNING: all data on the device will be destroyed!",
+            "警告：设备上的所有数据都将被销毁！",
+        ],
+    ),
+    (
+        "app.warning_destroy_many",
+        [
+            "ВНИМАНИЕ: все данные на {0} устройствах будут уничтожены!",
+            "WARNING: all data on {0} devices will be destroyed!",
+            "警告：{0} 个设备上的所有数据都将被销毁！",
+        ],
+    ),
+    (
+        "app.signals_failed",
+        [
+            "Не удалось поставить обработчики сигналов: {0}",
+            "Cannot install signal handlers: {0}",
+            "无法安装信号处理程序：{0}",
+        ],
+    ),
+    (
+        "app.interrupted",
+        [
+            "Прервано {0}: текущий чанк дописан, записанное сброшено на носитель.",
+            "Interrupted by {0}: the current chunk was finished and written data flushed to the medium.",
+            "被 {0} 中断：当前块已写完，已写入的数据已刷新到介质。",
+        ],
+    ),
+    (
+        "app.resume_hint",
+        [
+            "Продолжить: destroyer --resume {0}",
+            "To continue: destroyer --resume {0}",
+            "继续：destroyer --resume {0}",
+        ],
+    ),
+    (
+        "app.wiped",
+        [
+            "Устройство {0} успешно очищено (время: {1})",
+            "Device {0} wiped successfully (time: {1})",
+            "设备 {0} 已成功擦除（用时：{1}）",
+        ],
+    ),
+    (
+        "app.pass_complete",
+        [
+            "проход 1/{0}: записан полностью",
+            "pass 1/{0}: fully written",
+            "第 1/{0} 遍：已全部写入",
+        ],
+    ),
+    (
+        "app.passes_complete",
+        [
+            "проходы 1–{0} из {1}: записаны полностью",
+            "passes 1–{0} of {1}: fully written",
+            "第 1–{0} 遍（共 {1} 遍）：已全部写入",
+        ],
+    ),
+    (
+        "app.verify_interrupted",
+        [
+            "прервана проверка после последнего прохода",
+            "verification after the last pass was interrupted",
+            "最后一遍之后的校验被中断",
+        ],
+    ),
+    (
+        "app.pass_not_started",
+        [
+            "проход {0}/{1} ({2}): не начат",
+            "pass {0}/{1} ({2}): not started",
+            "第 {0}/{1} 遍（{2}）：未开始",
+        ],
+    ),
+    (
+        "app.passes_not_started",
+        [
+            "проходы {0}–{1} из {1}: не начаты",
+            "passes {0}–{1} of {1}: not started",
+            "第 {0}–{1} 遍（共 {1} 遍）：未开始",
+        ],
+    ),
+    (
+        "app.and_more",
+        [
+            "и ещё {0}",
+            "and {0} more",
+            "另有 {0} 个",
+        ],
+    ),
+    (
+        "app.pass_partial",
+        [
+            "проход {0}/{1} ({2}): записано {3} из {4} байт, участки {5}",
+            "pass {0}/{1} ({2}): {3} of {4} bytes written, ranges {5}",
+            "第 {0}/{1} 遍（{2}）：已写入 {3}/{4} 字节，区段 {5}",
+        ],
+    ),
+    (
+        "app.nothing_to_resume",
+        [
+            "Все проходы записаны, продолжать нечего",
+            "All passes are written, nothing to resume",
+            "所有遍次均已写入，无需继续",
+        ],
+    ),
+    (
+        "app.report_saved",
+        [
+            "Отчёт: {0} ({1}), текст: {2}",
+            "Report: {0} ({1}), text: {2}",
+            "报告：{0}（{1}），文本：{2}",
+        ],
+    ),
+    (
+        "app.report_signed",
+        [
+            "подписан Ed25519",
+            "signed with Ed25519",
+            "已用 Ed25519 签名",
+        ],
+    ),
+    (
+        "app.report_unsigned",
+        [
+            "без подписи",
+            "unsigned",
+            "未签名",
+        ],
+    ),
+    (
+        "app.pub_key_failed",
+        [
+            "Не удалось прочитать открытый ключ {0}: {1}",
+            "Cannot read public key {0}: {1}",
+            "无法读取公钥 {0}：{1}",
+        ],
+    ),
+    (
+        "app.report_read_failed",
+        [
+            "Не удалось прочитать отчёт {0}: {1}",
+            "Cannot read report {0}: {1}",
+            "无法读取报告 {0}：{1}",
+        ],
+    ),
+    (
+        "app.signature_ok",
+        [
+            "{0}: подпись верна (Ed25519, ключ {1})",
+            "{0}: signature is valid (Ed25519, key {1})",
+            "{0}：签名有效（Ed25519，密钥 {1}）",
+        ],
+    ),
+    (
+        "app.signature_untrusted",
+        [
+            "{0}: подпись верна, но ключ {1} взят из самого отчёта и не проверен — укажите доверенный ключ через --pub-key",
+            "{0}: signature is valid, but key {1} was taken from the report itself and is not trusted — pass a trusted key with --pub-key",
+            "{0}：签名有效，但密钥 {1} 取自报告本身，未经信任——请通过 --pub-key 指定可信密钥",
+        ],
+    ),
+    (
+        "app.events_failed",
+        [
+            "Не удалось открыть поток событий: {0}",
+            "Cannot open the event stream: {0}",
+            "无法打开事件流：{0}",
+        ],
+    ),
+    (
+        "app.dry_run",
+        [
+            "Пробный запуск (--dry-run): на запись ничего не открывается",
+            "Dry run (--dry-run): nothing is opened for writing",
+            "试运行（--dry-run）：不会以写入方式打开任何设备",
+        ],
+    ),
+    (
+        "app.dry_run_pass",
+        [
+            "Проход {0}/{1} ({2}): ",
+            "Pass {0}/{1} ({2}): ",
+            "第 {0}/{1} 遍（{2}）：",
+        ],
+    ),
+    (
+        "app.dry_run_offload",
+        [
+            "силами ядра {0} байт",
+            "{0} bytes by the kernel",
+            "由内核处理 {0} 字节",
+        ],
+    ),
+    (
+        "app.dry_run_write",
+        [
+            "запись {0} байт",
+            "write {0} bytes",
+            "写入 {0} 字节",
+        ],
+    ),
+    (
+        "app.dry_run_verify",
+        [
+            ", проверка {0} байт",
+            ", verify {0} bytes",
+            "，校验 {0} 字节",
+        ],
+    ),
+    (
+        "app.dry_run_total_write",
+        [
+            "Всего записать: {0} байт ({1} GB)",
+            "Total to write: {0} bytes ({1} GB)",
+            "总写入量：{0} 字节（{1} GB）",
+        ],
+    ),
+    (
+        "app.dry_run_total_offload",
+        [
+            "Силами ядра: {0} байт (время не оценивается)",
+            "By the kernel: {0} bytes (time not estimated)",
+            "由内核处理：{0} 字节（不估算时间）",
+        ],
+    ),
+    (
+        "app.dry_run_total_verify",
+        [
+            "Проверить чтением: {0} байт ({1} GB)",
+            "To verify by reading: {0} bytes ({1} GB)",
+            "读取校验：{0} 字节（{1} GB）",
+        ],
+    ),
+    (
+        "app.dry_run_speed",
+        [
+            "Скорость чтения: {0} MB/s (проба: {1} байт за {2})",
+            "Read speed: {0} MB/s (probe: {1} bytes in {2})",
+            "读取速度：{0} MB/s（试读：{2} 内 {1} 字节）",
+        ],
+    ),
+    (
+        "app.dry_run_eta",
+        [
+            "Оценка времени: не меньше {0} (запись обычно не быстрее чтения)",
+            "Estimated time: at least {0} (writing is rarely faster than reading)",
+            "预计时间：至少 {0}（写入通常不比读取快）",
+        ],
+    ),
+    (
+        "app.dry_run_no_eta",
+        [
+            "Оценка времени: нет данных (прочитать ничего не удалось)",
+            "Estimated time: no data (nothing could be read)",
+            "预计时间：无数据（未能读取任何内容）",
+        ],
+    ),
+    (
+        "app.confirm_skipped",
+        [
+            "Подтверждение пропущено (--yes)",
+            "Confirmation skipped (--yes)",
+            "已跳过确认（--yes）",
+        ],
+    ),
+    (
+        "app.confirm_no_tty",
+        [
+            "Стандартный ввод не терминал — подтвердить стирание некому.
+Для запуска из скриптов добавьте --yes",
+            "Standard input is not a terminal — nobody can confirm the wipe.
+Add --yes to run from scripts",
+            "标准输入不是终端——无人可以确认擦除。
+在脚本中运行请添加 --yes",
+        ],
+    ),
+    (
+        "app.confirm_mismatch",
+        [
+            "Подтверждение не совпало — стирание отменено",
+            "Confirmation did not match — wipe cancelled",
+            "确认不匹配——已取消擦除",
+        ],
+    ),
+    (
+        "app.confirm_read_failed",
+        [
+            "Не удалось прочитать подтверждение: {0}",
+            "Cannot read confirmation: {0}",
+            "无法读取确认：{0}",
+        ],
+    ),
+    (
+        "app.read_only_help",
+        [
+            "Устройство доступно только для чтения: проверьте переключатель защиты от записи или снимите флаг (Linux: sudo blockdev --setrw {0})",
+            "The device is read-only: check the write-protect switch or clear the flag (Linux: sudo blockdev --setrw {0})",
+            "设备为只读：请检查写保护开关或清除只读标志（Linux：sudo blockdev --setrw {0}）",
+        ],
+    ),
+    (
+        "app.permission_help",
+        [
+            "Нет прав на устройство: запустите через sudo",
+            "No permission for the device: run with sudo",
+            "没有设备权限：请使用 sudo 运行",
+        ],
+    ),
+    (
+        "app.devices_parallel",
+        [
+            "Устройств: {0} — стираются параллельно, каждое в своём потоке",
+            "Devices: {0} — wiped in parallel, each on its own thread",
+            "设备：{0} 个——并行擦除，每个设备一个线程",
+        ],
+    ),
+    (
+        "app.device_row",
+        [
+            "{0}: {1} байт ({2} GB), буфер {3}B",
+            "{0}: {1} bytes ({2} GB), buffer {3}B",
+            "{0}：{1} 字节（{2} GB），缓冲区 {3}B",
+        ],
+    ),
+    (
+        "app.device_partition",
+        [
+            "раздел {0}",
+            "partition {0}",
+            "分区 {0}",
+        ],
+    ),
+    (
+        "app.device_extents",
+        [
+            "участки: {0}",
+            "ranges: {0}",
+            "区段：{0}",
+        ],
+    ),
+    (
+        "app.device_in_use",
+        [
+            "используется (--force): {0} {1}",
+            "in use (--force): {0} {1}",
+            "正在使用（--force）：{0} {1}",
+        ],
+    ),
+    (
+        "app.device_skipped",
+        [
+            "{0}: ПРОПУСКАЕТСЯ — {1}",
+            "{0}: SKIPPED — {1}",
+            "{0}：已跳过——{1}",
+        ],
+    ),
+    (
+        "app.no_devices_ready",
+        [
+            "Ни одно устройство не готово к стиранию",
+            "No device is ready to be wiped",
+            "没有可以擦除的设备",
+        ],
+    ),
+    (
+        "app.failed",
+        [
+            "ОШИБКА — {0}",
+            "FAILED — {0}",
+            "失败——{0}",
+        ],
+    ),
+    (
+        "app.summary",
+        [
+            "Итог:",
+            "Summary:",
+            "汇总：",
+        ],
+    ),
+    (
+        "app.device_ok",
+        [
+            "{0}: OK, очищено (время: {1})",
+            "{0}: OK, wiped (time: {1})",
+            "{0}：OK，已擦除（用时：{1}）",
+        ],
+    ),
+    (
+        "app.device_interrupted",
+        [
+            "{0}: ПРЕРВАНО {1}",
+            "{0}: INTERRUPTED by {1}",
+            "{0}：被 {1} 中断",
+        ],
+    ),
+    (
+        "app.device_failed",
+        [
+            "{0}: ОШИБКА — {1}",
+            "{0}: FAILED — {1}",
+            "{0}：失败——{1}",
+        ],
+    ),
+    (
+        "app.partition_bytes",
+        [
+            "{0}{1}: байты {2}..{3} ({4} GB)",
+            "{0}{1}: bytes {2}..{3} ({4} GB)",
+            "{0}{1}：字节 {2}..{3}（{4} GB）",
+        ],
+    ),
+    (
+        "app.extents_summary",
+        [
+            "{0} шт., {1} байт за проход ({2} GB)",
+            "{0} ranges, {1} bytes per pass ({2} GB)",
+            "{0} 个，每遍 {1} 字节（{2} GB）",
+        ],
+    ),
+    (
+        "app.table",
+        [
+            "Таблица разделов: {0}, сектор {1}B",
+            "Partition table: {0}, sector {1}B",
+            "分区表：{0}，扇区 {1}B",
+        ],
+    ),
+    (
+        "app.table_backup",
+        [
+            " (основной заголовок повреждён, прочитана резервная копия)",
+            " (primary header is damaged, read the backup copy)",
+            "（主表头已损坏，已读取备份副本）",
+        ],
+    ),
+    (
+        "app.disk_guid",
+        [
+            "GUID диска: {0}",
+            "Disk GUID: {0}",
+            "磁盘 GUID：{0}",
+        ],
+    ),
+    (
+        "app.col_number",
+        [
+            "№",
+            "#",
+            "编号",
+        ],
+    ),
+    (
+        "app.col_start",
+        [
+            "Начало",
+            "Start",
+            "起始",
+        ],
+    ),
+    (
+        "app.col_end",
+        [
+            "Конец",
+            "End",
+            "结束",
+        ],
+    ),
+    (
+        "app.col_size",
+        [
+            "Размер",
+            "Size",
+            "大小",
+        ],
+    ),
+    (
+        "app.col_type",
+        [
+            "Тип",
+            "Type",
+            "类型",
+        ],
+    ),
+    (
+        "app.col_label",
+        [
+            "Метка",
+            "Label",
+            "标签",
+        ],
+    ),
+    (
+        "app.no_partitions",
+        [
+            "(разделов нет)",
+            "(no partitions)",
+            "（没有分区）",
+        ],
+    ),
+    (
+        "app.whole_disk",
+        [
+            "Будет стёрт весь диск: {0} байт",
+            "The whole disk will be wiped: {0} bytes",
+            "将擦除整个磁盘：{0} 字节",
+        ],
+    ),
+    (
+        "app.will_wipe_extents",
+        [
+            "Будет стёрто {0} участков, {1} байт:",
+            "{0} ranges will be wiped, {1} bytes:",
+            "将擦除 {0} 个区段，共 {1} 字节：",
+        ],
+    ),
+    (
+        "app.byte_range",
+        [
+            "байты {0}..{1} ({2} байт)",
+            "bytes {0}..{1} ({2} bytes)",
+            "字节 {0}..{1}（{2} 字节）",
+        ],
+    ),
+    (
+        "app.more_extents",
+        [
+            "... ещё {0} участков",
+            "... {0} more ranges",
+            "... 另有 {0} 个区段",
+        ],
+    ),
+    (
+        "app.verify_failed",
+        [
+            "ПРОВЕРКА НЕ ПРОЙДЕНА: {0} байт из {1} не совпадают с ожидаемым",
+            "VERIFICATION FAILED: {0} of {1} bytes do not match the expected data",
+            "校验失败：{1} 字节中有 {0} 字节与预期不符",
+        ],
+    ),
+    (
+        "app.ranges_truncated",
+        [
+            "... список диапазонов обрезан",
+            "... range list truncated",
+            "... 区间列表已截断",
+        ],
+    ),
+    (
+        "app.system_disk",
+        [
+            "{0} — диск работающей системы, стирание отменено:",
+            "{0} is the running system's disk, wipe cancelled:",
+            "{0} 是正在运行的系统所在磁盘，已取消擦除：",
+        ],
+    ),
+    (
+        "app.system_disk_help",
+        [
+            "Проверьте путь: возможно, имелся в виду другой диск (lsblk -o NAME,SIZE,MODEL,MOUNTPOINTS).
+Стереть именно этот диск: --i-know-this-is-the-system-disk",
+            "Check the path: you may have meant another disk (lsblk -o NAME,SIZE,MODEL,MOUNTPOINTS).
+To wipe this very disk: --i-know-this-is-the-system-disk",
+            "请检查路径：可能指的是另一块磁盘（lsblk -o NAME,SIZE,MODEL,MOUNTPOINTS）。
+确实要擦除此磁盘：--i-know-this-is-the-system-disk",
+        ],
+    ),
+    (
+        "app.in_use",
+        [
+            "Устройство {0} используется — стирание отменено:",
+            "Device {0} is in use — wipe cancelled:",
+            "设备 {0} 正在使用——已取消擦除：",
+        ],
+    ),
+    (
+        "app.in_use_help",
+        [
+            "Отмонтируйте разделы (umount), отключите подкачку (swapoff),
+остановите LVM/RAID/dm (vgchange -an, mdadm --stop, dmsetup remove)
+или отсоедините zram/loop, затем повторите.
+Стереть несмотря ни на что: --force",
+            "Unmount the partitions (umount), turn off swap (swapoff),
+stop LVM/RAID/dm (vgchange -an, mdadm --stop, dmsetup remove)
+or detach zram/loop, then try again.
+To wipe regardless: --force",
+            "请卸载分区（umount），关闭交换空间（swapoff），
+停止 LVM/RAID/dm（vgchange -an、mdadm --stop、dmsetup remove）
+或分离 zram/loop，然后重试。
+无论如何都要擦除：--force",
+        ],
+    ),
+    (
+        "app.busy_help",
+        [
+            "Устройство {0} занято (возможно, примонтировано).
+macOS:  diskutil unmountDisk {0}
+Linux:  sudo umount <точка_монтирования_или_/dev/..>
+        lsblk -f | grep $(basename {0})
+        sudo lsof {0}  | head
+        sudo fuser -mv {0}
+        sudo swapoff -a
+        sudo dmsetup ls",
+            "Device {0} is busy (probably mounted).
+macOS:  diskutil unmountDisk {0}
+Linux:  sudo umount <mount_point_or_/dev/..>
+        lsblk -f | grep $(basename {0})
+        sudo lsof {0}  | head
+        sudo fuser -mv {0}
+        sudo swapoff -a
+        sudo dmsetup ls",
+            "设备 {0} 正忙（可能已挂载）。
+macOS:  diskutil unmountDisk {0}
+Linux:  sudo umount <挂载点或_/dev/..>
+        lsblk -f | grep $(basename {0})
+        sudo lsof {0}  | head
+        sudo fuser -mv {0}
+        sudo swapoff -a
+        sudo dmsetup ls",
+        ],
+    ),
+    (
+        "args.utf8_only",
+        [
+            "{0} принимает только UTF-8 значения",
+            "{0} accepts UTF-8 values only",
+            "{0} 只接受 UTF-8 值",
+        ],
+    ),
+    (
+        "args.pub_key_required",
+        [
+            "--pub-key требует путь к открытому ключу",
+            "--pub-key requires a path to the public key",
+            "--pub-key 需要公钥文件路径",
+        ],
+    ),
+    (
+        "args.verify_report_unknown",
+        [
+            "Неизвестный аргумент verify-report: {0}",
+            "Unknown verify-report argument: {0}",
+            "未知的 verify-report 参数：{0}",
+        ],
+    ),
+    (
+        "args.verify_report_usage",
+        [
+            "Использование: destroyer verify-report FILE [--pub-key FILE]",
+            "Usage: destroyer verify-report FILE [--pub-key FILE]",
+            "用法：destroyer verify-report FILE [--pub-key FILE]",
+        ],
+    ),
+    (
+        "args.lang_invalid",
+        [
+            "Неизвестный язык --lang: {0}. Ожидается ru|en|zh-CN",
+            "Unknown --lang language: {0}. Expected ru|en|zh-CN",
+            "未知的 --lang 语言：{0}。应为 ru|en|zh-CN",
+        ],
+    ),
+    (
+        "args.mode_required",
+        [
+            "--mode требует аргумент: fast|durable|direct",
+            "--mode requires a value: fast|durable|direct",
+            "--mode 需要参数：fast|durable|direct",
+        ],
+    ),
+    (
+        "args.durable_unavailable",
+        [
+            "Режим 'durable' недоступен в текущей сборке.",
+            "Mode 'durable' is not available in this build.",
+            "当前构建不支持 'durable' 模式。",
+        ],
+    ),
+    (
+        "args.direct_unavailable",
+        [
+            "Режим 'direct' поддерживается только на Linux и при включённом флаге сборки.",
+            "Mode 'direct' is supported only on Linux with the build feature enabled.",
+            "'direct' 模式仅在 Linux 上且启用构建特性时可用。",
+        ],
+    ),
+    (
+        "args.mode_invalid",
+        [
+            "Неизвестное значение --mode: {0}. Ожидается fast|durable|direct",
+            "Unknown --mode value: {0}. Expected fast|durable|direct",
+            "未知的 --mode 值：{0}。应为 fast|durable|direct",
+        ],
+    ),
+    (
+        "args.buf_required",
+        [
+            "--buf требует число байт",
+            "--buf requires a number of bytes",
+            "--buf 需要字节数",
+        ],
+    ),
+    (
+        "args.buf_invalid",
+        [
+            "Некорректное значение для --buf",
+            "Invalid value for --buf",
+            "--buf 的值无效",
+        ],
+    ),
+    (
+        "args.buf_zero",
+        [
+            "--buf должен быть > 0",
+            "--buf must be > 0",
+            "--buf 必须 > 0",
+        ],
+    ),
+    (
+        "args.queue_depth_required",
+        [
+            "--queue-depth требует число",
+            "--queue-depth requires a number",
+            "--queue-depth 需要一个数字",
+        ],
+    ),
+    (
+        "args.gen_threads_required",
+        [
+            "--gen-threads требует число потоков",
+            "--gen-threads requires a number of threads",
+            "--gen-threads 需要线程数",
+        ],
+    ),
+    (
+        "args.range_expected",
+        [
+            "Некорректное значение для {0}: ожидается 0..={1}",
+            "Invalid value for {0}: expected 0..={1}",
+            "{0} 的值无效：应为 0..={1}",
+        ],
+    ),
+    (
+        "args.scheme_required",
+        [
+            "--scheme требует имя схемы: {0}",
+            "--scheme requires a scheme name: {0}",
+            "--scheme 需要方案名称：{0}",
+        ],
+    ),
+    (
+        "args.scheme_invalid",
+        [
+            "Неизвестная схема: {0}. Ожидается {1}",
+            "Unknown scheme: {0}. Expected {1}",
+            "未知方案：{0}。应为 {1}",
+        ],
+    ),
+    (
+        "args.passes_spec_required",
+        [
+            "--passes-spec требует список проходов, например random*3,0x55,zero",
+            "--passes-spec requires a list of passes, e.g. random*3,0x55,zero",
+            "--passes-spec 需要遍次列表，例如 random*3,0x55,zero",
+        ],
+    ),
+    (
+        "args.invalid_value",
+        [
+            "Некорректный {0}: {1}",
+            "Invalid {0}: {1}",
+            "无效的 {0}：{1}",
+        ],
+    ),
+    (
+        "args.final_required",
+        [
+            "--final требует проход: zero|random|0xNN|zeroout|discard|…|none",
+            "--final requires a pass: zero|random|0xNN|zeroout|discard|…|none",
+            "--final 需要一个遍次：zero|random|0xNN|zeroout|discard|…|none",
+        ],
+    ),
+    (
+        "args.journal_required",
+        [
+            "{0} требует путь к файлу журнала",
+            "{0} requires a path to the journal file",
+            "{0} 需要日志文件路径",
+        ],
+    ),
+    (
+        "args.name_required",
+        [
+            "{0} требует имя",
+            "{0} requires a name",
+            "{0} 需要名称",
+        ],
+    ),
+    (
+        "args.file_required",
+        [
+            "{0} требует путь к файлу",
+            "{0} requires a file path",
+            "{0} 需要文件路径",
+        ],
+    ),
+    (
+        "args.range_required",
+        [
+            "{0} требует диапазон START:LEN, например 0:64M",
+            "{0} requires a START:LEN range, e.g. 0:64M",
+            "{0} 需要 START:LEN 区间，例如 0:64M",
+        ],
+    ),
+    (
+        "args.partition_required",
+        [
+            "{0} требует номер или метку раздела",
+            "{0} requires a partition number or label",
+            "{0} 需要分区编号或标签",
+        ],
+    ),
+    (
+        "args.partition_twice",
+        [
+            "Раздел можно выбрать только один раз",
+            "Only one partition can be selected",
+            "只能选择一个分区",
+        ],
+    ),
+    (
+        "args.partition_number",
+        [
+            "Номер раздела должен быть положительным целым",
+            "The partition number must be a positive integer",
+            "分区编号必须是正整数",
+        ],
+    ),
+    (
+        "args.progress_invalid",
+        [
+            "Некорректное значение --progress: {0}. Ожидается text|json",
+            "Invalid --progress value: {0}. Expected text|json",
+            "--progress 的值无效：{0}。应为 text|json",
+        ],
+    ),
+    (
+        "args.progress_fd_required",
+        [
+            "--progress-fd требует номер дескриптора",
+            "--progress-fd requires a descriptor number",
+            "--progress-fd 需要描述符编号",
+        ],
+    ),
+    (
+        "args.progress_fd_invalid",
+        [
+            "Некорректное значение для --progress-fd: ожидается число >= 1",
+            "Invalid value for --progress-fd: expected a number >= 1",
+            "--progress-fd 的值无效：应为 >= 1 的数字",
+        ],
+    ),
+    (
+        "args.seed_required",
+        [
+            "--seed требует hex-значение",
+            "--seed requires a hex value",
+            "--seed 需要十六进制值",
+        ],
+    ),
+    (
+        "args.seed_invalid",
+        [
+            "Некорректное значение для --seed: ожидается до 64 hex-символов",
+            "Invalid value for --seed: expected up to 64 hex characters",
+            "--seed 的值无效：应为最多 64 个十六进制字符",
+        ],
+    ),
+    (
+        "args.unknown_flag",
+        [
+            "Неизвестный флаг: {0}",
+            "Unknown flag: {0}",
+            "未知选项：{0}",
+        ],
+    ),
+    (
+        "args.passes_integer",
+        [
+            "Число проходов должно быть положительным целым",
+            "The number of passes must be a positive integer",
+            "遍数必须是正整数",
+        ],
+    ),
+    (
+        "args.passes_zero",
+        [
+            "Число проходов должно быть >= 1",
+            "The number of passes must be >= 1",
+            "遍数必须 >= 1",
+        ],
+    ),
+    (
+        "args.extra_positional",
+        [
+            "Лишний позиционный аргумент: {0}",
+            "Unexpected positional argument: {0}",
+            "多余的位置参数：{0}",
+        ],
+    ),
+    (
+        "args.not_utf8",
+        [
+            "Аргументы должны быть валидным UTF-8",
+            "Arguments must be valid UTF-8",
+            "参数必须是有效的 UTF-8",
+        ],
+    ),
+    (
+        "args.resume_plan",
+        [
+            "При --resume план и сид берутся из журнала; уберите их из аргументов",
+            "With --resume the plan and seed come from the journal; remove them from the arguments",
+            "使用 --resume 时方案和种子取自日志；请从参数中删除它们",
+        ],
+    ),
+    (
+        "args.resume_extents",
+        [
+            "При --resume участки берутся из журнала; уберите {0}",
+            "With --resume the ranges come from the journal; remove {0}",
+            "使用 --resume 时区段取自日志；请删除 {0}",
+        ],
+    ),
+    (
+        "args.exclusive",
+        [
+            "{0} и {1} взаимоисключающие",
+            "{0} and {1} are mutually exclusive",
+            "{0} 与 {1} 互斥",
+        ],
+    ),
+    (
+        "args.requires",
+        [
+            "{0} работает только с {1}",
+            "{0} works only with {1}",
+            "{0} 只能与 {1} 一起使用",
+        ],
+    ),
+    (
+        "args.no_uring",
+        [
+            "--queue-depth: программа собрана без io_uring (cargo feature io-uring)",
+            "--queue-depth: built without io_uring (cargo feature io-uring)",
+            "--queue-depth：程序构建时未启用 io_uring（cargo feature io-uring）",
+        ],
+    ),
+    (
+        "args.device_twice",
+        [
+            "Устройство указано дважды: {0}",
+            "Device given twice: {0}",
+            "设备重复指定：{0}",
+        ],
+    ),
+    (
+        "args.single_device",
+        [
+            "{0} работает только с одним устройством",
+            "{0} works only with a single device",
+            "{0} 只能用于单个设备",
+        ],
+    ),
+    (
+        "args.plan_sets_passes",
+        [
+            "{0} задаёт проходы сам; уберите число проходов",
+            "{0} defines the passes itself; remove the number of passes",
+            "{0} 自带遍次定义；请删除遍数",
+        ],
+    ),
+    (
+        "args.final_with_scheme",
+        [
+            "--final нельзя менять у именованной схемы",
+            "--final cannot change a named scheme",
+            "--final 不能修改命名方案",
+        ],
+    ),
+    (
+        "args.usage",
+        [
+            "Использование:
+  {0} <устройство>... [проходы] [--mode fast|durable|direct] [--buf BYTES] [--quiet]
+      [--gen-threads N] [--queue-depth N]
+      [--scheme NAME | --passes-spec SPEC] [--final PASS]
+      [--verify] [--verify-each-pass] [--seed HEX]
+      [--journal FILE] [--resume FILE]
+      [--range START:LEN]... [--exclude START:LEN]...
+      [--partition N | --partition-label LABEL | --unallocated] [--list-partitions]
+      [--force] [--i-know-this-is-the-system-disk] [--yes] [--dry-run]
+      [--progress text|json] [--progress-fd N]
+      [--report FILE [--sign-key FILE] [--operator NAME]] [--pause-file FILE]
+      [--lang ru|en|zh-CN]
+  {0} verify-report FILE [--pub-key FILE]
+
+Примеры:
+  sudo {0} /dev/sdX 8
+  sudo {0} /dev/sdX --scheme dod3 --verify --dry-run
+  sudo {0} /dev/sdX 8 --mode durable --buf 65536
+  sudo {0} /dev/sdX 8 --mode direct --verify
+  sudo {0} /dev/diskN 3 --mode fast
+  {0} ./disk.img 3 --seed 2a --verify-each-pass
+  {0} ./disk.img 1 --yes
+  sudo {0} /dev/sdX --scheme dod3 --verify
+  sudo {0} /dev/sdX --passes-spec \"random*2,0x55,0xAA,0x92:0x49:0x24,zero\"
+  sudo {0} /dev/sdX 3 --final random
+  sudo {0} /dev/nvme0n1 --passes-spec random,zeroout --verify
+  sudo {0} /dev/sdX 1 --final secdiscard
+  sudo {0} /dev/sdX --scheme dod7 --journal /root/sdX.journal
+  sudo {0} --resume /root/sdX.journal
+  sudo {0} /dev/sdX 1 --range 0:64M --range -64M:64M
+  sudo {0} /dev/sdX 3 --exclude -20GiB:20GiB
+  sudo {0} /dev/sdX --list-partitions
+  sudo {0} /dev/sdX 3 --partition-label DATA
+  sudo {0} /dev/sdX 1 --unallocated --verify
+  sudo {0} /dev/sdb /dev/sdc /dev/sdd 3 --verify
+  sudo {0} /dev/sdX --scheme nist-clear --verify --report sdX.json --sign-key key.pem
+  sudo {0} /dev/sdX 3 --pause-file /run/destroyer.pause
+  {0} verify-report sdX.json --pub-key pub.pem
+
+Пояснения:
+  <устройство>     Путь к блочному девайсу (Linux: /dev/sdX|nvme0n1; macOS: /dev/diskN).
+                   Можно указать несколько (путями со «/») — они стираются параллельно,
+                   с одним предупреждением, общей таблицей прогресса и итогом по каждому;
+                   ошибка на одном диске не останавливает остальные
+  [проходы]        Количество проходов (последний — нулями). По умолчанию 8
+  --mode           fast (быстро) | durable (максимум надёжности) | direct (Linux, O_DIRECT — без page cache)
+  --buf BYTES      Размер буфера. Если не указан — выбирается автоматически
+                   по размеру блока устройства (кратно сектору, целимся ~64 KiB)
+  --quiet          Не выводить строку прогресса (ускоряет работу).
+  --gen-threads N  Сколько потоков генерируют случайные данные, пока основной пишет
+                   (0 — генерировать и писать по очереди). По умолчанию — по числу
+                   ядер минус одно, от 1 до 4
+  --queue-depth N  Linux, --mode direct, сборка с feature io-uring: держать N записей
+                   в полёте через io_uring (по умолчанию 32; 0 — блокирующая запись)
+  --scheme NAME    Именованная схема вместо числа проходов:
+                   dod3       DoD 5220.22-M (E): 0x00, дополнение, случайные
+                   dod7       DoD 5220.22-M (ECE): 7 проходов
+                   gutmann    Гутман, 35 проходов
+                   nist-clear NIST SP 800-88 Clear: один проход нулями
+                   vsitr      BSI VSITR: 0x00/0xFF ×3, затем 0xAA
+                   schneier   Шнайер: 0xFF, 0x00, 5 случайных
+                   rcmp       RCMP TSSIT OPS-II: 0x00/0xFF ×3, затем случайные
+  --passes-spec SPEC
+                   Произвольный список проходов через запятую:
+                   random | zero | one | complement | 0xNN | 0xNN:0xNN:...
+                   с необязательным повтором *N (например random*3).
+                   Проходы силами ядра (Linux): zeroout (BLKZEROOUT), discard
+                   (BLKDISCARD), secdiscard (BLKSECDISCARD); для файла-образа —
+                   fallocate ZERO_RANGE/PUNCH_HOLE. Если не поддерживается — пишутся нули
+  --final PASS     Финальный проход: для числа проходов заменяет нули
+                   (none — все проходы случайные), для --passes-spec дописывается в конец (без none)
+  --verify         После финального прохода перечитать всё устройство и сверить с записанным
+                   (в режиме direct — чтением через O_DIRECT, мимо page cache).
+  --verify-each-pass
+                   Проверять чтением каждый проход: случайные проходы воспроизводятся
+                   по сиду прохода, шаблонные — с шаблоном.
+  --seed HEX       Мастер-сид (до 64 hex-символов) для воспроизводимых прогонов;
+                   без него сид берётся из системного ГСЧ.
+  --journal FILE   Раз в 10 секунд сохранять журнал: устройство, план, сид,
+                   текущий проход и смещение, уже записанное на носитель
+  --resume FILE    Продолжить прерванное стирание по журналу (устройство можно
+                   не указывать; сверяются размер и серийный номер)
+  --range START:LEN
+                   Стирать только этот участок; можно указать несколько раз.
+                   Единицы: без суффикса — байты, K/M/G/T (KiB…TiB) — двоичные,
+                   KB/MB/GB/TB — десятичные, s — 512-байтные сектора (LBA).
+                   START с минусом отсчитывается от конца устройства (-64M:64M)
+  --exclude START:LEN
+                   Не трогать участок (например, раздел восстановления); можно несколько
+  --partition N    Стереть раздел N из таблицы GPT/MBR всего диска (нумерация как
+                   у ядра: sdX3 — это 3). Запись идёт через дескриптор всего диска,
+                   --range/--exclude отсчитываются от начала раздела
+  --partition-label LABEL
+                   То же, но раздел выбирается по имени GPT
+  --unallocated    Стереть только неразмеченное место: промежутки между разделами,
+                   до первого и после последнего. Разделы, заголовки и массивы
+                   записей GPT (основные и резервные) не трогаются
+  --list-partitions
+                   Показать таблицу разделов и что будет стёрто, ничего не записывая
+  --force          Стирать, даже если устройство или стираемый раздел примонтирован,
+                   используется как подкачка или занят dm/md/LVM/zram (Linux).
+                   Без флага такое устройство не трогается
+  --yes, -y        Не спрашивать подтверждение. Без него нужно перепечатать имя
+                   устройства (sdX) или последние 4 символа его серийного номера;
+                   если стандартный ввод не терминал, стирание без --yes отменяется
+  --progress text|json
+                   json — вместо строки статуса NDJSON-события (plan, pass_start,
+                   progress, pass_end, verify_result, error, result) по схеме
+                   destroyer.progress версии 3; весь текст тогда уходит в stderr
+  --progress-fd N  Писать события в дескриптор N (например, 3>events.jsonl),
+                   stdout остаётся для текста
+  --report FILE    Записать сертификат стирания по полям NIST SP 800-88: носитель
+                   (производитель, модель, серийный номер), метод и план, каждый
+                   проход со временем начала/конца и скоростью, итог проверки,
+                   хост, оператор и версия. FILE — JSON, рядом текстовый вид (.txt).
+                   Для нескольких устройств — FILE-<устройство>.json
+  --sign-key FILE  Подписать JSON-отчёт ключом Ed25519: 64 hex-символа сида или
+                   PEM (openssl genpkey -algorithm ed25519)
+  --operator NAME  Кто выполняет стирание (по умолчанию SUDO_USER/USER)
+  verify-report FILE [--pub-key FILE]
+                   Проверить подпись отчёта; --pub-key — доверенный открытый ключ
+                   (hex или PEM). Код 0 — подпись верна
+  --pause-file FILE
+                   Пока FILE существует, запись стоит на паузе (текущий чанк
+                   дописывается и сбрасывается на носитель); удалите файл — продолжится
+  --dry-run        Проверить аргументы и устройство, показать план проходов, сколько
+                   байт будет записано и проверено и оценку времени по пробному
+                   чтению (до 64 MiB) — и выйти, ничего не записав. Код 0, если
+                   настоящий запуск с теми же аргументами прошёл бы проверки
+  --i-know-this-is-the-system-disk
+                   Стирать диск работающей системы: на нём (через dm-crypt/LVM/md
+                   или напрямую) лежат /, /boot, /usr, ESP или сам destroyer.
+                   Без флага такой диск и его разделы не трогаются (Linux)
+  --lang ru|en|zh-CN
+                   Язык сообщений. По умолчанию — из LC_ALL, LC_MESSAGES или LANG,
+                   иначе английский. События и ключи JSON, kind и коды выхода от языка не зависят
+
+Сигналы во время стирания:
+  SIGINT, SIGTERM  Дописать текущий чанк, сбросить данные на носитель, показать, какие
+                   участки каких проходов записаны, сохранить журнал (--journal или
+                   /var/tmp/destroyer-<устройство>.journal) и выйти. Повторный сигнал —
+                   немедленный выход
+  SIGUSR1          Вывести в stderr текущее состояние (как dd)
+  SIGTSTP (Ctrl+Z) Пауза после текущего чанка; SIGCONT (fg) — продолжить
+
+Коды выхода:
+  0  — устройство очищено (и проверено, если задан --verify)
+  1  — ошибка аргументов или параметров, подтверждение не совпало, не записан отчёт
+  2  — проверка чтением обнаружила расхождения
+  3  — устройство не открывается (нет такого пути)
+  4  — не удалось определить размер устройства
+  5  — устройство занято (EBUSY)
+  6  — устройство только для чтения
+  7  — нет прав (запустите через sudo)
+  8  — ошибка ввода-вывода при записи или проверке
+  9  — системный ГСЧ не дал сид
+  10 — отказ: системный диск или устройство используется системой
+  130, 143 — стирание прервано SIGINT или SIGTERM (128 + номер сигнала)
+  Для нескольких устройств: код первой ошибки устройства, иначе 1, если не
+  записан отчёт, иначе 2, если хоть одно не прошло проверку",
+            "Usage:
+  {0} <device>... [passes] [--mode fast|durable|direct] [--buf BYTES] [--quiet]
+      [--gen-threads N] [--queue-depth N]
+      [--scheme NAME | --passes-spec SPEC] [--final PASS]
+      [--verify] [--verify-each-pass] [--seed HEX]
+      [--journal FILE] [--resume FILE]
+      [--range START:LEN]... [--exclude START:LEN]...
+      [--partition N | --partition-label LABEL | --unallocated] [--list-partitions]
+      [--force] [--i-know-this-is-the-system-disk] [--yes] [--dry-run]
+      [--progress text|json] [--progress-fd N]
+      [--report FILE [--sign-key FILE] [--operator NAME]] [--pause-file FILE]
+      [--lang ru|en|zh-CN]
+  {0} verify-report FILE [--pub-key FILE]
+
+Examples:
+  sudo {0} /dev/sdX 8
+  sudo {0} /dev/sdX --scheme dod3 --verify --dry-run
+  sudo {0} /dev/sdX 8 --mode durable --buf 65536
+  sudo {0} /dev/sdX 8 --mode direct --verify
+  sudo {0} /dev/diskN 3 --mode fast
+  {0} ./disk.img 3 --seed 2a --verify-each-pass
+  {0} ./disk.img 1 --yes
+  sudo {0} /dev/sdX --scheme dod3 --verify
+  sudo {0} /dev/sdX --passes-spec \"random*2,0x55,0xAA,0x92:0x49:0x24,zero\"
+  sudo {0} /dev/sdX 3 --final random
+  sudo {0} /dev/nvme0n1 --passes-spec random,zeroout --verify
+  sudo {0} /dev/sdX 1 --final secdiscard
+  sudo {0} /dev/sdX --scheme dod7 --journal /root/sdX.journal
+  sudo {0} --resume /root/sdX.journal
+  sudo {0} /dev/sdX 1 --range 0:64M --range -64M:64M
+  sudo {0} /dev/sdX 3 --exclude -20GiB:20GiB
+  sudo {0} /dev/sdX --list-partitions
+  sudo {0} /dev/sdX 3 --partition-label DATA
+  sudo {0} /dev/sdX 1 --unallocated --verify
+  sudo {0} /dev/sdb /dev/sdc /dev/sdd 3 --verify
+  sudo {0} /dev/sdX --scheme nist-clear --verify --report sdX.json --sign-key key.pem
+  sudo {0} /dev/sdX 3 --pause-file /run/destroyer.pause
+  {0} verify-report sdX.json --pub-key pub.pem
+
+Notes:
+  <device>         Path to a block device (Linux: /dev/sdX|nvme0n1; macOS: /dev/diskN).
+                   Several can be given (as paths containing \"/\") — they are wiped in parallel,
+                   with a single warning, a shared progress table and a summary for each;
+                   an error on one disk does not stop the others
+  [passes]         Number of passes (the last one writes zeros). Default is 8
+  --mode           fast | durable (maximum reliability) | direct (Linux, O_DIRECT — bypasses the page cache)
+  --buf BYTES      Buffer size. If not given, it is chosen automatically
+                   from the device block size (a multiple of the sector, aiming at ~64 KiB)
+  --quiet          Do not print the progress line (speeds things up).
+  --gen-threads N  How many threads generate random data while the main one writes
+                   (0 — generate and write in turn). By default the number
+                   of cores minus one, from 1 to 4
+  --queue-depth N  Linux, --mode direct, built with feature io-uring: keep N writes
+                   in flight via io_uring (default 32; 0 — blocking writes)
+  --scheme NAME    A named scheme instead of the number of passes:
+                   dod3       DoD 5220.22-M (E): 0x00, complement, random
+                   dod7       DoD 5220.22-M (ECE): 7 passes
+                   gutmann    Gutmann, 35 passes
+                   nist-clear NIST SP 800-88 Clear: a single pass of zeros
+                   vsitr      BSI VSITR: 0x00/0xFF ×3, then 0xAA
+                   schneier   Schneier: 0xFF, 0x00, 5 random
+                   rcmp       RCMP TSSIT OPS-II: 0x00/0xFF ×3, then random
+  --passes-spec SPEC
+                   An arbitrary comma-separated list of passes:
+                   random | zero | one | complement | 0xNN | 0xNN:0xNN:...
+                   with an optional repeat *N (e.g. random*3).
+                   Kernel-side passes (Linux): zeroout (BLKZEROOUT), discard
+                   (BLKDISCARD), secdiscard (BLKSECDISCARD); for an image file —
+                   fallocate ZERO_RANGE/PUNCH_HOLE. If unsupported, zeros are written
+  --final PASS     Final pass: with a number of passes it replaces the zeros
+                   (none — all passes random), with --passes-spec it is appended at the end (not none)
+  --verify         After the final pass re-read the whole device and compare it with what was written
+                   (in direct mode — reading through O_DIRECT, bypassing the page cache).
+  --verify-each-pass
+                   Verify every pass by reading: random passes are reproduced
+                   from the pass seed, pattern passes are compared with the pattern.
+  --seed HEX       Master seed (up to 64 hex characters) for reproducible runs;
+                   without it the seed comes from the system RNG.
+  --journal FILE   Every 10 seconds save a journal: device, plan, seed,
+                   current pass and the offset already flushed to the medium
+  --resume FILE    Continue an interrupted wipe from the journal (the device may
+                   be omitted; size and serial number are checked)
+  --range START:LEN
+                   Wipe only this region; may be given several times.
+                   Units: no suffix — bytes, K/M/G/T (KiB…TiB) — binary,
+                   KB/MB/GB/TB — decimal, s — 512-byte sectors (LBA).
+                   A negative START counts from the end of the device (-64M:64M)
+  --exclude START:LEN
+                   Leave a region untouched (e.g. a recovery partition); may be repeated
+  --partition N    Wipe partition N from the GPT/MBR table of the whole disk (numbered
+                   like the kernel: sdX3 is 3). Writes go through the whole-disk descriptor,
+                   --range/--exclude are counted from the start of the partition
+  --partition-label LABEL
+                   The same, but the partition is selected by its GPT name
+  --unallocated    Wipe only unallocated space: gaps between partitions,
+                   before the first and after the last one. Partitions, GPT headers
+                   and entry arrays (primary and backup) are left untouched
+  --list-partitions
+                   Show the partition table and what would be wiped, writing nothing
+  --force          Wipe even if the device or the partition being wiped is mounted,
+                   used as swap or held by dm/md/LVM/zram (Linux).
+                   Without the flag such a device is left alone
+  --yes, -y        Do not ask for confirmation. Without it you must retype the device
+                   name (sdX) or the last 4 characters of its serial number;
+                   if standard input is not a terminal, the wipe is cancelled without --yes
+  --progress text|json
+                   json — NDJSON events instead of the status line (plan, pass_start,
+                   progress, pass_end, verify_result, error, result) following
+                   schema destroyer.progress version 3; all text then goes to stderr
+  --progress-fd N  Write events to descriptor N (e.g. 3>events.jsonl),
+                   stdout stays for text
+  --report FILE    Write a wipe certificate with NIST SP 800-88 fields: medium
+                   (manufacturer, model, serial number), method and plan, every
+                   pass with start/end time and rate, verification result,
+                   host, operator and version. FILE is JSON, with a text copy (.txt) next to it.
+                   For several devices — FILE-<device>.json
+  --sign-key FILE  Sign the JSON report with an Ed25519 key: 64 hex characters of the seed or
+                   PEM (openssl genpkey -algorithm ed25519)
+  --operator NAME  Who performs the wipe (default SUDO_USER/USER)
+  verify-report FILE [--pub-key FILE]
+                   Check the report signature; --pub-key is the trusted public key
+                   (hex or PEM). Exit code 0 means the signature is valid
+  --pause-file FILE
+                   While FILE exists, writing is paused (the current chunk
+                   is finished and flushed to the medium); delete the file to continue
+  --dry-run        Check the arguments and the device, show the pass plan, how many
+                   bytes will be written and verified and a time estimate from a trial
+                   read (up to 64 MiB) — and exit without writing anything. Exit code 0 if
+                   a real run with the same arguments would pass the checks
+  --i-know-this-is-the-system-disk
+                   Wipe the disk of the running system: it holds (via dm-crypt/LVM/md
+                   or directly) /, /boot, /usr, the ESP or destroyer itself.
+                   Without the flag such a disk and its partitions are left alone (Linux)
+  --lang ru|en|zh-CN
+                   Message language. By default taken from LC_ALL, LC_MESSAGES or LANG,
+                   otherwise English. JSON events and keys, kind and exit codes do not depend on it
+
+Signals during a wipe:
+  SIGINT, SIGTERM  Finish the current chunk, flush data to the medium, show which
+                   regions of which passes were written, save the journal (--journal or
+                   /var/tmp/destroyer-<device>.journal) and exit. A second signal —
+                   immediate exit
+  SIGUSR1          Print the current state to stderr (like dd)
+  SIGTSTP (Ctrl+Z) Pause after the current chunk; SIGCONT (fg) — continue
+
+Exit codes:
+  0  — the device is wiped (and verified if --verify was given)
+  1  — bad arguments or parameters, confirmation mismatch, report not written
+  2  — read-back verification found mismatches
+  3  — the device cannot be opened (no such path)
+  4  — could not determine the device size
+  5  — the device is busy (EBUSY)
+  6  — the device is read-only
+  7  — permission denied (run via sudo)
+  8  — I/O error while writing or verifying
+  9  — the system RNG did not provide a seed
+  10 — refused: system disk or a device in use by the system
+  130, 143 — the wipe was interrupted by SIGINT or SIGTERM (128 + signal number)
+  For several devices: the code of the first device error, otherwise 1 if a
+  report was not written, otherwise 2 if at least one failed verification",
+            "用法：
+  {0} <设备>... [遍数] [--mode fast|durable|direct] [--buf BYTES] [--quiet]
+      [--gen-threads N] [--queue-depth N]
+      [--scheme NAME | --passes-spec SPEC] [--final PASS]
+      [--verify] [--verify-each-pass] [--seed HEX]
+      [--journal FILE] [--resume FILE]
+      [--range START:LEN]... [--exclude START:LEN]...
+      [--partition N | --partition-label LABEL | --unallocated] [--list-partitions]
+      [--force] [--i-know-this-is-the-system-disk] [--yes] [--dry-run]
+      [--progress text|json] [--progress-fd N]
+      [--report FILE [--sign-key FILE] [--operator NAME]] [--pause-file FILE]
+      [--lang ru|en|zh-CN]
+  {0} verify-report FILE [--pub-key FILE]
+
+示例：
+  sudo {0} /dev/sdX 8
+  sudo {0} /dev/sdX --scheme dod3 --verify --dry-run
+  sudo {0} /dev/sdX 8 --mode durable --buf 65536
+  sudo {0} /dev/sdX 8 --mode direct --verify
+  sudo {0} /dev/diskN 3 --mode fast
+  {0} ./disk.img 3 --seed 2a --verify-each-pass
+  {0} ./disk.img 1 --yes
+  sudo {0} /dev/sdX --scheme dod3 --verify
+  sudo {0} /dev/sdX --passes-spec \"random*2,0x55,0xAA,0x92:0x49:0x24,zero\"
+  sudo {0} /dev/sdX 3 --final random
+  sudo {0} /dev/nvme0n1 --passes-spec random,zeroout --verify
+  sudo {0} /dev/sdX 1 --final secdiscard
+  sudo {0} /dev/sdX --scheme dod7 --journal /root/sdX.journal
+  sudo {0} --resume /root/sdX.journal
+  sudo {0} /dev/sdX 1 --range 0:64M --range -64M:64M
+  sudo {0} /dev/sdX 3 --exclude -20GiB:20GiB
+  sudo {0} /dev/sdX --list-partitions
+  sudo {0} /dev/sdX 3 --partition-label DATA
+  sudo {0} /dev/sdX 1 --unallocated --verify
+  sudo {0} /dev/sdb /dev/sdc /dev/sdd 3 --verify
+  sudo {0} /dev/sdX --scheme nist-clear --verify --report sdX.json --sign-key key.pem
+  sudo {0} /dev/sdX 3 --pause-file /run/destroyer.pause
+  {0} verify-report sdX.json --pub-key pub.pem
+
+说明：
+  <设备>           块设备路径（Linux：/dev/sdX|nvme0n1；macOS：/dev/diskN）。
+                   可指定多个（含“/”的路径）——并行擦除，
+                   只提示一次警告，共用一个进度表，并分别给出结果；
+                   某个磁盘出错不会中止其他磁盘
+  [遍数]           擦除遍数（最后一遍写零）。默认 8
+  --mode           fast（快速）| durable（最高可靠性）| direct（Linux，O_DIRECT——绕过 page cache）
+  --buf BYTES      缓冲区大小。未指定时根据设备块大小
+                   自动选择（扇区的整数倍，目标约 64 KiB）
+  --quiet          不显示进度行（可加快速度）。
+  --gen-threads N  主线程写入时生成随机数据的线程数
+                   （0——生成与写入交替进行）。默认为
+                   CPU 核数减一，范围 1 到 4
+  --queue-depth N  Linux、--mode direct、启用 io-uring 特性构建时：通过 io_uring
+                   保持 N 个写请求在途（默认 32；0——阻塞写入）
+  --scheme NAME    使用命名方案代替遍数：
+                   dod3       DoD 5220.22-M (E)：0x00、补码、随机
+                   dod7       DoD 5220.22-M (ECE)：7 遍
+                   gutmann    Gutmann，35 遍
+                   nist-clear NIST SP 800-88 Clear：一遍零
+                   vsitr      BSI VSITR：0x00/0xFF ×3，然后 0xAA
+                   schneier   Schneier：0xFF、0x00、5 遍随机
+                   rcmp       RCMP TSSIT OPS-II：0x00/0xFF ×3，然后随机
+  --passes-spec SPEC
+                   以逗号分隔的任意遍次列表：
+                   random | zero | one | complement | 0xNN | 0xNN:0xNN:...
+                   可带重复 *N（例如 random*3）。
+                   由内核完成的遍次（Linux）：zeroout (BLKZEROOUT)、discard
+                   (BLKDISCARD)、secdiscard (BLKSECDISCARD)；对镜像文件——
+                   fallocate ZERO_RANGE/PUNCH_HOLE。不支持时改为写零
+  --final PASS     最终遍次：与遍数一起使用时替换写零遍
+                   （none——全部为随机遍），与 --passes-spec 一起使用时追加到末尾（不接受 none）
+  --verify         最终遍次后重新读取整个设备并与写入内容比对
+                   （direct 模式下通过 O_DIRECT 读取，绕过 page cache）。
+  --verify-each-pass
+                   每一遍都读取校验：随机遍按该遍的种子重现，
+                   模式遍与模式比对。
+  --seed HEX       主种子（最多 64 个十六进制字符），用于可重现的运行；
+                   不指定时种子取自系统随机数生成器。
+  --journal FILE   每 10 秒保存一次日志：设备、方案、种子、
+                   当前遍次以及已落盘的偏移
+  --resume FILE    根据日志继续被中断的擦除（可不指定设备；
+                   会核对大小和序列号）
+  --range START:LEN
+                   只擦除该区段；可多次指定。
+                   单位：无后缀——字节，K/M/G/T (KiB…TiB)——二进制，
+                   KB/MB/GB/TB——十进制，s——512 字节扇区 (LBA)。
+                   START 为负时从设备末尾起算 (-64M:64M)
+  --exclude START:LEN
+                   不触碰该区段（例如恢复分区）；可多次指定
+  --partition N    擦除整盘 GPT/MBR 分区表中的第 N 个分区（编号与
+                   内核一致：sdX3 即 3）。写入通过整盘描述符进行，
+                   --range/--exclude 从分区起点起算
+  --partition-label LABEL
+                   同上，但按 GPT 名称选择分区
+  --unallocated    只擦除未分配空间：分区之间的间隙、
+                   第一个分区之前和最后一个之后。分区、GPT 头
+                   和条目数组（主、备份）不会被触碰
+  --list-partitions
+                   显示分区表以及将被擦除的内容，不写入任何数据
+  --force          即使设备或待擦除分区已挂载、用作交换分区
+                   或被 dm/md/LVM/zram 占用（Linux）也进行擦除。
+                   不加此选项时不会触碰此类设备
+  --yes, -y        不请求确认。否则需要重新输入设备名
+                   (sdX) 或其序列号的最后 4 个字符；
+                   若标准输入不是终端，没有 --yes 则取消擦除
+  --progress text|json
+                   json——以 NDJSON 事件代替状态行（plan、pass_start、
+                   progress、pass_end、verify_result、error、result），遵循
+                   destroyer.progress 第 3 版模式；此时所有文本输出到 stderr
+  --progress-fd N  将事件写入描述符 N（例如 3>events.jsonl），
+                   stdout 仍用于文本
+  --report FILE    按 NIST SP 800-88 字段写出擦除证书：介质
+                   （厂商、型号、序列号）、方法和方案、每一遍的
+                   开始/结束时间和速度、校验结果、
+                   主机、操作员和版本。FILE 为 JSON，旁边附带文本版 (.txt)。
+                   多个设备时为 FILE-<设备>.json
+  --sign-key FILE  用 Ed25519 密钥签署 JSON 报告：64 个十六进制字符的种子或
+                   PEM（openssl genpkey -algorithm ed25519）
+  --operator NAME  执行擦除的人员（默认 SUDO_USER/USER）
+  verify-report FILE [--pub-key FILE]
+                   校验报告签名；--pub-key 为受信任的公钥
+                   （hex 或 PEM）。退出码 0 表示签名有效
+  --pause-file FILE
+                   FILE 存在期间暂停写入（当前数据块
+                   写完并落盘）；删除该文件即继续
+  --dry-run        检查参数和设备，显示遍次方案、将写入和校验的
+                   字节数以及基于试读（最多 64 MiB）的时间估计——
+                   然后退出，不写入任何数据。若使用相同参数的真实运行
+                   能通过检查，则退出码为 0
+  --i-know-this-is-the-system-disk
+                   擦除正在运行的系统所在磁盘：其上（经 dm-crypt/LVM/md
+                   或直接）存放 /、/boot、/usr、ESP 或 destroyer 本身。
+                   不加此选项时不会触碰此类磁盘及其分区（Linux）
+  --lang ru|en|zh-CN
+                   消息语言。默认取自 LC_ALL、LC_MESSAGES 或 LANG，
+                   否则为英语。JSON 事件与键、kind 及退出码与语言无关
+
+擦除过程中的信号：
+  SIGINT, SIGTERM  写完当前数据块，数据落盘，显示哪些遍次的
+                   哪些区段已写入，保存日志（--journal 或
+                   /var/tmp/destroyer-<设备>.journal）后退出。再次发送信号——
+                   立即退出
+  SIGUSR1          向 stderr 输出当前状态（类似 dd）
+  SIGTSTP (Ctrl+Z) 当前数据块后暂停；SIGCONT (fg)——继续
+
+退出码：
+  0  — 设备已擦除（指定 --verify 时已校验）
+  1  — 参数错误、确认不匹配、报告未写出
+  2  — 读取校验发现不一致
+  3  — 无法打开设备（路径不存在）
+  4  — 无法确定设备大小
+  5  — 设备忙 (EBUSY)
+  6  — 设备只读
+  7  — 权限不足（请通过 sudo 运行）
+  8  — 写入或校验时发生 I/O 错误
+  9  — 系统随机数生成器未提供种子
+  10 — 拒绝：系统盘或被系统占用的设备
+  130, 143 — 擦除被 SIGINT 或 SIGTERM 中断（128 + 信号编号）
+  多个设备时：取第一个设备错误的退出码，否则若有报告未写出则为 1，
+  否则若至少一个设备校验失败则为 2",
+        ],
+    ),
+    (
+        "progress.verify",
+        [
+            "Проверка",
+            "Verifying",
+            "校验",
+        ],
+    ),
+    (
+        "progress.pass",
+        [
+            "Пасс",
+            "Pass",
+            "遍次",
+        ],
+    ),
+    (
+        "progress.percent",
+        [
+            "Прогресс",
+            "Progress",
+            "进度",
+        ],
+    ),
+    (
+        "progress.eta_pass",
+        [
+            "Осталось проход",
+            "Pass left",
+            "本遍剩余",
+        ],
+    ),
+    (
+        "progress.eta_total",
+        [
+            "Осталось всего",
+            "Total left",
+            "总剩余",
+        ],
+    ),
+    (
+        "wipe.status_verify",
+        [
+            "Проверка после прохода {0}",
+            "Verification after pass {0}",
+            "第 {0} 遍后的校验",
+        ],
+    ),
+    (
+        "wipe.status_pass",
+        [
+            "Проход {0}/{1}",
+            "Pass {0}/{1}",
+            "第 {0}/{1} 遍",
+        ],
+    ),
+    (
+        "wipe.status_done",
+        [
+            ": {0} из {1} байт ({2}%), {3} MB/s, осталось прохода ",
+            ": {0} of {1} bytes ({2}%), {3} MB/s, pass left ",
+            "：{0} / {1} 字节（{2}%），{3} MB/s，本遍剩余 ",
+        ],
+    ),
+    (
+        "wipe.status_total",
+        [
+            "; всего {0} из {1} байт, осталось ",
+            "; total {0} of {1} bytes, left ",
+            "；总计 {0} / {1} 字节，剩余 ",
+        ],
+    ),
+    (
+        "wipe.uring_direct_only",
+        [
+            "io_uring используется только с O_DIRECT",
+            "io_uring is used only with O_DIRECT",
+            "io_uring 仅与 O_DIRECT 一起使用",
+        ],
+    ),
+    (
+        "wipe.zeros",
+        [
+            "нули",
+            "zeros",
+            "零",
+        ],
+    ),
+    (
+        "wipe.byte",
+        [
+            "байт {0}",
+            "byte {0}",
+            "字节 {0}",
+        ],
+    ),
+    (
+        "wipe.pattern",
+        [
+            "шаблон {0}",
+            "pattern {0}",
+            "模式 {0}",
+        ],
+    ),
+    (
+        "wipe.random",
+        [
+            "случайные данные",
+            "random data",
+            "随机数据",
+        ],
+    ),
+    (
+        "wipe.complement",
+        [
+            "дополнение случайного прохода",
+            "complement of the random pass",
+            "随机遍的补码",
+        ],
+    ),
+    (
+        "wipe.offload_fallback",
+        [
+            "{0} не поддерживается ({1}) — дописываем нулями",
+            "{0} is not supported ({1}) — writing zeros instead",
+            "不支持 {0}（{1}）——改为写零",
+        ],
+    ),
+    (
+        "wipe.generator_died",
+        [
+            "поток генерации завершился раньше времени",
+            "the generator thread exited prematurely",
+            "生成线程提前退出",
+        ],
+    ),
+    (
+        "wipe.write_zero",
+        [
+            "устройство не приняло данные на смещении {0}",
+            "the device did not accept data at offset {0}",
+            "设备未在偏移 {0} 处接受数据",
+        ],
+    ),
+    (
+        "offload.zeroout",
+        [
+            "обнуление ядром (BLKZEROOUT)",
+            "kernel zeroing (BLKZEROOUT)",
+            "内核清零 (BLKZEROOUT)",
+        ],
+    ),
+    (
+        "offload.linux_only",
+        [
+            "ядерные режимы стирания есть только в Linux",
+            "kernel wipe modes are available only on Linux",
+            "内核擦除模式仅在 Linux 上可用",
+        ],
+    ),
+    (
+        "verify.read_failed",
+        [
+            "чтение на байте {0}: {1}",
+            "read at byte {0}: {1}",
+            "在字节 {0} 处读取：{1}",
+        ],
+    ),
+    (
+        "multi.done",
+        [
+            "готово",
+            "done",
+            "完成",
+        ],
+    ),
+    (
+        "multi.failed",
+        [
+            "ОШИБКА",
+            "FAILED",
+            "失败",
+        ],
+    ),
+    (
+        "multi.verify",
+        [
+            "Проверка ",
+            "Verifying ",
+            "校验 ",
+        ],
+    ),
+    (
+        "multi.paused",
+        [
+            "пауза",
+            "paused",
+            "已暂停",
+        ],
+    ),
+    (
+        "multi.pass",
+        [
+            "Пасс {0}/{1}",
+            "Pass {0}/{1}",
+            "遍次 {0}/{1}",
+        ],
+    ),
+    (
+        "multi.eta",
+        [
+            "Осталось",
+            "Left",
+            "剩余",
+        ],
+    ),
+    (
+        "multi.total",
+        [
+            "Всего",
+            "Total",
+            "总计",
+        ],
+    ),
+    (
+        "signals.signal",
+        [
+            "сигнал",
+            "signal",
+            "信号",
+        ],
+    ),
+    (
+        "signals.cancel",
+        [
+            "отмена",
+            "cancel",
+            "取消",
+        ],
+    ),
+    (
+        "signals.stopped",
+        [
+            "остановлено ({0}) на байте {1}",
+            "stopped ({0}) at byte {1}",
+            "已停止（{0}），位于字节 {1}",
+        ],
+    ),
+    (
+        "signals.resume_cont",
+        [
+            "fg или kill -CONT {0}",
+            "fg or kill -CONT {0}",
+            "fg 或 kill -CONT {0}",
+        ],
+    ),
+    (
+        "signals.paused",
+        [
+            "Пауза на байте {0}, данные сброшены на носитель. Продолжить: {1}",
+            "Paused at byte {0}, data flushed to the medium. To continue: {1}",
+            "在字节 {0} 处暂停，数据已落盘。继续：{1}",
+        ],
+    ),
+    (
+        "signals.resumed",
+        [
+            "Продолжаем",
+            "Resuming",
+            "继续",
+        ],
+    ),
+    (
+        "confirm.prompt_serial",
+        [
+            "Введите имя устройства ({0}) или последние {1} символа серийного номера (…{2}): ",
+            "Type the device name ({0}) or the last {1} characters of its serial number (…{2}): ",
+            "请输入设备名（{0}）或序列号的最后 {1} 个字符（…{2}）：",
+        ],
+    ),
+    (
+        "confirm.prompt",
+        [
+            "Введите имя устройства ({0}): ",
+            "Type the device name ({0}): ",
+            "请输入设备名（{0}）：",
+        ],
+    ),
+    (
+        "wiper.size_failed",
+        [
+            "Ошибка определения размера устройства",
+            "Failed to determine the device size",
+            "无法确定设备大小",
+        ],
+    ),
+    (
+        "wiper.identify_failed",
+        [
+            "Не удалось определить устройство",
+            "Failed to identify the device",
+            "无法识别设备",
+        ],
+    ),
+    (
+        "wiper.journal_mismatch",
+        [
+            "Журнал относится к другому устройству: {0} ({1} байт, {2}), а сейчас {3} ({4} байт, {5})",
+            "The journal belongs to another device: {0} ({1} bytes, {2}), but now it is {3} ({4} bytes, {5})",
+            "日志属于另一个设备：{0}（{1} 字节，{2}），而当前为 {3}（{4} 字节，{5}）",
+        ],
+    ),
+    (
+        "wiper.partition_table_failed",
+        [
+            "Не удалось прочитать таблицу разделов: {0}",
+            "Failed to read the partition table: {0}",
+            "无法读取分区表：{0}",
+        ],
+    ),
+    (
+        "wiper.bad_extents",
+        [
+            "Некорректные участки: {0}",
+            "Invalid ranges: {0}",
+            "区段无效：{0}",
+        ],
+    ),
+    (
+        "wiper.no_unallocated",
+        [
+            "Неразмеченного места нет — стирать нечего",
+            "There is no unallocated space — nothing to wipe",
+            "没有未分配空间——无需擦除",
+        ],
+    ),
+    (
+        "wiper.system_check_failed",
+        [
+            "Не удалось проверить, не системный ли это диск: {0} (--i-know-this-is-the-system-disk — не проверять)",
+            "Failed to check whether this is the system disk: {0} (--i-know-this-is-the-system-disk skips the check)",
+            "无法检查是否为系统盘：{0}（--i-know-this-is-the-system-disk 跳过检查）",
+        ],
+    ),
+    (
+        "wiper.usage_check_failed",
+        [
+            "Не удалось проверить, используется ли устройство: {0} (--force — не проверять)",
+            "Failed to check whether the device is in use: {0} (--force skips the check)",
+            "无法检查设备是否被占用：{0}（--force 跳过检查）",
+        ],
+    ),
+    (
+        "wiper.rng_failed",
+        [
+            "Не удалось получить сид из системного ГСЧ: {0}",
+            "Failed to get a seed from the system RNG: {0}",
+            "无法从系统随机数生成器获取种子：{0}",
+        ],
+    ),
+    (
+        "wiper.read_buffer_failed",
+        [
+            "Не удалось подготовить буфер чтения: {0}",
+            "Cannot allocate the read buffer: {0}",
+            "无法准备读取缓冲区：{0}",
+        ],
+    ),
+    (
+        "wiper.probe_failed",
+        [
+            "Пробное чтение не удалось",
+            "Probe read failed",
+            "试读失败",
+        ],
+    ),
+    (
+        "wiper.buffer_failed",
+        [
+            "Не удалось подготовить буфер записи: {0}",
+            "Failed to prepare the write buffer: {0}",
+            "无法准备写入缓冲区：{0}",
+        ],
+    ),
+    (
+        "wiper.uring_unavailable",
+        [
+            "io_uring недоступен ({0}), запись блокирующая",
+            "io_uring is unavailable ({0}), using blocking writes",
+            "io_uring 不可用（{0}），使用阻塞写入",
+        ],
+    ),
+    (
+        "wiper.journal_failed",
+        [
+            "Не удалось записать журнал {0}: {1}",
+            "Failed to write the journal {0}: {1}",
+            "无法写入日志 {0}：{1}",
+        ],
+    ),
+    (
+        "wiper.journal_remove_failed",
+        [
+            "Не удалось удалить журнал: {0}",
+            "Cannot remove journal: {0}",
+            "无法删除日志：{0}",
+        ],
+    ),
+    (
+        "wiper.journal_write_failed",
+        [
+            "Не удалось записать журнал {0}: {1}",
+            "Cannot write journal {0}: {1}",
+            "无法写入日志 {0}：{1}",
+        ],
+    ),
+    (
+        "wiper.write_failed",
+        [
+            "Ошибка записи прохода {0} после байта {1}: {2}",
+            "Write error in pass {0} after byte {1}: {2}",
+            "第 {0} 遍在字节 {1} 之后写入出错：{2}",
+        ],
+    ),
+    (
+        "wiper.verify_read_failed",
+        [
+            "Ошибка чтения при проверке прохода {0}: {1}",
+            "Read error while verifying pass {0}: {1}",
+            "校验第 {0} 遍时读取出错：{1}",
+        ],
+    ),
+    (
+        "wiper.open_failed",
+        [
+            "Ошибка открытия устройства",
+            "Failed to open the device",
+            "无法打开设备",
+        ],
+    ),
+    (
+        "wiper.report_failed",
+        [
+            "Не удалось записать отчёт {0}: {1}",
+            "Cannot write report {0}: {1}",
+            "无法写入报告 {0}：{1}",
+        ],
+    ),
+    (
+        "wiper.thread_panicked",
+        [
+            "рабочий поток аварийно завершился",
+            "the worker thread panicked",
+            "工作线程异常终止",
+        ],
+    ),
+    (
+        "wiper.dry_run_start",
+        [
+            "Пробный запуск: запись не запускается",
+            "Dry run: writing is not started",
+            "试运行：不会开始写入",
+        ],
+    ),
+    (
+        "error.system_disk",
+        [
+            "системный диск: {0} (--i-know-this-is-the-system-disk — стирать всё равно)",
+            "system disk: {0} (--i-know-this-is-the-system-disk wipes it anyway)",
+            "系统盘：{0}（--i-know-this-is-the-system-disk 仍然擦除）",
+        ],
+    ),
+    (
+        "error.in_use",
+        [
+            "используется системой: {0} (--force — стирать всё равно)",
+            "in use by the system: {0} (--force wipes it anyway)",
+            "被系统占用：{0}（--force 仍然擦除）",
+        ],
+    ),
+    (
+        "error.verify",
+        [
+            "проверка не пройдена: {0} байт из {1} не совпадают",
+            "verification failed: {0} of {1} bytes do not match",
+            "校验失败：{1} 字节中有 {0} 字节不一致",
+        ],
+    ),
+    (
+        "error.cancelled",
+        [
+            "прервано {0}: проход {1}, записано до байта {2}",
+            "interrupted by {0}: pass {1}, written up to byte {2}",
+            "被 {0} 中断：第 {1} 遍，已写入至字节 {2}",
+        ],
+    ),
+    (
+        "inuse.mounted",
+        [
+            "примонтирован в {0} ({1})",
+            "mounted at {0} ({1})",
+            "挂载于 {0}（{1}）",
+        ],
+    ),
+    (
+        "inuse.swap",
+        [
+            "используется как подкачка ({0})",
+            "used as swap ({0})",
+            "用作交换分区（{0}）",
+        ],
+    ),
+    (
+        "inuse.held",
+        [
+            "занят {0}",
+            "held by {0}",
+            "被 {0} 占用",
+        ],
+    ),
+    (
+        "inuse.zram",
+        [
+            "backing_dev у {0}",
+            "backing_dev of {0}",
+            "{0} 的 backing_dev",
+        ],
+    ),
+    (
+        "inuse.swap_file",
+        [
+            "используется как файл подкачки",
+            "used as a swap file",
+            "用作交换文件",
+        ],
+    ),
+    (
+        "inuse.loop",
+        [
+            "подключён как /dev/{0}",
+            "attached as /dev/{0}",
+            "已挂接为 /dev/{0}",
+        ],
+    ),
+    (
+        "inuse.md",
+        [
+            "{0} (программный RAID md)",
+            "{0} (md software RAID)",
+            "{0}（md 软件 RAID）",
+        ],
+    ),
+    (
+        "inuse.bad_devno",
+        [
+            "некорректный номер устройства в {0}",
+            "invalid device number in {0}",
+            "{0} 中的设备号无效",
+        ],
+    ),
+    (
+        "inuse.not_in_sysfs",
+        [
+            "устройство не найдено в sysfs",
+            "device not found in sysfs",
+            "在 sysfs 中找不到设备",
+        ],
+    ),
+    (
+        "inuse.executable",
+        [
+            "исполняемый файл {0}",
+            "executable {0}",
+            "可执行文件 {0}",
+        ],
+    ),
+    (
+        "inuse.holds",
+        [
+            "на нём {0} ({1})",
+            "holds {0} ({1})",
+            "其上有 {0}（{1}）",
+        ],
+    ),
+    (
+        "identity.rotational",
+        [
+            "вращающийся",
+            "rotational",
+            "机械盘",
+        ],
+    ),
+    (
+        "identity.non_rotational",
+        [
+            "без вращения (SSD/флеш)",
+            "non-rotational (SSD/flash)",
+            "非机械（SSD/闪存）",
+        ],
+    ),
+    (
+        "identity.removable",
+        [
+            "съёмный",
+            "removable",
+            "可移动",
+        ],
+    ),
+    (
+        "identity.fixed",
+        [
+            "несъёмный",
+            "fixed",
+            "不可移动",
+        ],
+    ),
+    (
+        "identity.device",
+        [
+            "Устройство: {0} — {1} ({2})",
+            "Device: {0} — {1} ({2})",
+            "设备：{0} — {1}（{2}）",
+        ],
+    ),
+    (
+        "identity.unknown_model",
+        [
+            "модель неизвестна",
+            "unknown model",
+            "型号未知",
+        ],
+    ),
+    (
+        "identity.serial",
+        [
+            "серийный номер: {0}",
+            "serial number: {0}",
+            "序列号：{0}",
+        ],
+    ),
+    (
+        "identity.unknown",
+        [
+            "неизвестен",
+            "unknown",
+            "未知",
+        ],
+    ),
+    (
+        "identity.firmware",
+        [
+            "прошивка: {0}",
+            "firmware: {0}",
+            "固件：{0}",
+        ],
+    ),
+    (
+        "identity.no_partitions",
+        [
+            "Разделов нет",
+            "No partitions",
+            "无分区",
+        ],
+    ),
+    (
+        "identity.summary",
+        [
+            "{0}, серийный номер {1}",
+            "{0}, serial number {1}",
+            "{0}，序列号 {1}",
+        ],
+    ),
+    (
+        "extents.expected_range",
+        [
+            "ожидается START:LEN: {0}",
+            "expected START:LEN: {0}",
+            "应为 START:LEN：{0}",
+        ],
+    ),
+    (
+        "extents.zero_len",
+        [
+            "длина диапазона должна быть > 0: {0}",
+            "the range length must be > 0: {0}",
+            "区间长度必须 > 0：{0}",
+        ],
+    ),
+    (
+        "extents.zero_from_end",
+        [
+            "смещение от конца должно быть > 0: {0}",
+            "an offset from the end must be > 0: {0}",
+            "自末尾起的偏移必须 > 0：{0}",
+        ],
+    ),
+    (
+        "extents.too_large",
+        [
+            "диапазон слишком большой: {0}",
+            "range too large: {0}",
+            "区间过大：{0}",
+        ],
+    ),
+    (
+        "extents.from_end_too_far",
+        [
+            "смещение -{0} больше размера устройства ({1} байт)",
+            "offset -{0} exceeds the device size ({1} bytes)",
+            "偏移 -{0} 超过设备大小（{1} 字节）",
+        ],
+    ),
+    (
+        "extents.past_end",
+        [
+            "диапазон {0}..{1} выходит за конец устройства ({2} байт)",
+            "range {0}..{1} goes past the end of the device ({2} bytes)",
+            "区间 {0}..{1} 超出设备末尾（{2} 字节）",
+        ],
+    ),
+    (
+        "extents.expected_number",
+        [
+            "ожидается число: {0}",
+            "expected a number: {0}",
+            "应为数字：{0}",
+        ],
+    ),
+    (
+        "extents.bad_number",
+        [
+            "некорректное число: {0}",
+            "invalid number: {0}",
+            "数字无效：{0}",
+        ],
+    ),
+    (
+        "extents.unknown_unit",
+        [
+            "неизвестная единица {0}: {1}",
+            "unknown unit {0}: {1}",
+            "未知单位 {0}：{1}",
+        ],
+    ),
+    (
+        "extents.overflow",
+        [
+            "слишком большое значение: {0}",
+            "value too large: {0}",
+            "数值过大：{0}",
+        ],
+    ),
+    (
+        "extents.nothing_left",
+        [
+            "после исключений стирать нечего",
+            "nothing left to wipe after the exclusions",
+            "排除之后没有可擦除的内容",
+        ],
+    ),
+    (
+        "extents.expected_absolute",
+        [
+            "ожидается абсолютный диапазон: {0}",
+            "expected an absolute range: {0}",
+            "应为绝对区间：{0}",
+        ],
+    ),
+    (
+        "plan.unknown_pass",
+        [
+            "неизвестный проход: {0}",
+            "unknown pass: {0}",
+            "未知遍次：{0}",
+        ],
+    ),
+    (
+        "plan.expected_byte",
+        [
+            "ожидается байт 0x00..0xFF: {0}",
+            "expected a byte 0x00..0xFF: {0}",
+            "应为字节 0x00..0xFF：{0}",
+        ],
+    ),
+    (
+        "plan.empty",
+        [
+            "план должен содержать хотя бы один проход",
+            "the plan must contain at least one pass",
+            "方案必须至少包含一遍",
+        ],
+    ),
+    (
+        "plan.complement_first",
+        [
+            "дополнение не может быть первым проходом",
+            "a complement pass cannot be the first pass",
+            "补码遍次不能作为第一遍",
+        ],
+    ),
+    (
+        "plan.empty_item",
+        [
+            "пустой элемент в списке проходов",
+            "empty item in the pass list",
+            "遍次列表中有空项",
+        ],
+    ),
+    (
+        "plan.bad_repeat",
+        [
+            "некорректный повтор: {0}",
+            "invalid repeat: {0}",
+            "重复次数无效：{0}",
+        ],
+    ),
+    (
+        "plan.repeat_range",
+        [
+            "повтор должен быть 1..={0}: {1}",
+            "the repeat must be 1..={0}: {1}",
+            "重复次数必须为 1..={0}：{1}",
+        ],
+    ),
+    (
+        "journal.version",
+        [
+            "неподдерживаемая версия журнала: {0}",
+            "unsupported journal version: {0}",
+            "不支持的日志版本：{0}",
+        ],
+    ),
+    (
+        "journal.not_journal",
+        [
+            "это не журнал destroyer",
+            "this is not a destroyer journal",
+            "这不是 destroyer 日志",
+        ],
+    ),
+    (
+        "journal.bad_line",
+        [
+            "некорректная строка журнала: {0}",
+            "invalid journal line: {0}",
+            "日志行无效：{0}",
+        ],
+    ),
+    (
+        "journal.bad_value",
+        [
+            "некорректное значение {0}: {1}",
+            "invalid value of {0}: {1}",
+            "{0} 的值无效：{1}",
+        ],
+    ),
+    (
+        "journal.missing",
+        [
+            "в журнале нет поля {0}",
+            "the journal has no field {0}",
+            "日志缺少字段 {0}",
+        ],
+    ),
+    (
+        "journal.pass_out_of_plan",
+        [
+            "номер прохода вне плана: {0}",
+            "pass number outside the plan: {0}",
+            "遍次编号超出方案：{0}",
+        ],
+    ),
+    (
+        "journal.offset_past_end",
+        [
+            "смещение за концом устройства: {0}",
+            "offset past the end of the device: {0}",
+            "偏移超出设备末尾：{0}",
+        ],
+    ),
+    (
+        "journal.seed_mismatch",
+        [
+            "сид прохода не соответствует мастер-сиду",
+            "the pass seed does not match the master seed",
+            "本遍种子与主种子不匹配",
+        ],
+    ),
+    (
+        "partition.by_number",
+        [
+            "номер {0}",
+            "number {0}",
+            "编号 {0}",
+        ],
+    ),
+    (
+        "partition.by_label",
+        [
+            "метка \"{0}\"",
+            "label \"{0}\"",
+            "标签 \"{0}\"",
+        ],
+    ),
+    (
+        "partition.not_found",
+        [
+            "раздел не найден: {0}",
+            "partition not found: {0}",
+            "找不到分区：{0}",
+        ],
+    ),
+    (
+        "partition.ambiguous",
+        [
+            "несколько разделов подходят под {0}; укажите --partition N",
+            "several partitions match {0}; use --partition N",
+            "有多个分区匹配 {0}；请使用 --partition N",
+        ],
+    ),
+    (
+        "partition.gpt_corrupt",
+        [
+            "GPT повреждена: {0}",
+            "GPT is corrupted: {0}",
+            "GPT 已损坏：{0}",
+        ],
+    ),
+    (
+        "partition.gpt_missing",
+        [
+            "защитная MBR указывает на GPT, но заголовок GPT не найден",
+            "the protective MBR points to GPT, but no GPT header was found",
+            "保护性 MBR 指向 GPT，但找不到 GPT 头",
+        ],
+    ),
+    (
+        "partition.no_table",
+        [
+            "таблица разделов не найдена",
+            "no partition table found",
+            "找不到分区表",
+        ],
+    ),
+    (
+        "partition.backup_header",
+        [
+            "резервный заголовок: {0}",
+            "backup header: {0}",
+            "备份头：{0}",
+        ],
+    ),
+    (
+        "partition.read_error",
+        [
+            "ошибка чтения: {0}",
+            "read error: {0}",
+            "读取错误：{0}",
+        ],
+    ),
+    (
+        "partition.header_size",
+        [
+            "некорректный размер заголовка: {0}",
+            "invalid header size: {0}",
+            "头大小无效：{0}",
+        ],
+    ),
+    (
+        "partition.header_crc",
+        [
+            "CRC заголовка не совпадает (LBA {0})",
+            "header CRC mismatch (LBA {0})",
+            "头 CRC 不匹配（LBA {0}）",
+        ],
+    ),
+    (
+        "partition.header_lba",
+        [
+            "заголовок на LBA {0} указывает на LBA {1}",
+            "the header at LBA {0} points to LBA {1}",
+            "LBA {0} 处的头指向 LBA {1}",
+        ],
+    ),
+    (
+        "partition.entries_size",
+        [
+            "некорректный массив записей: {0} x {1} байт",
+            "invalid entry array: {0} x {1} bytes",
+            "条目数组无效：{0} x {1} 字节",
+        ],
+    ),
+    (
+        "partition.entries_past_end",
+        [
+            "массив записей за концом устройства",
+            "the entry array is past the end of the device",
+            "条目数组超出设备末尾",
+        ],
+    ),
+    (
+        "partition.entries_crc",
+        [
+            "CRC массива записей не совпадает (LBA {0})",
+            "entry array CRC mismatch (LBA {0})",
+            "条目数组 CRC 不匹配（LBA {0}）",
+        ],
+    ),
+    (
+        "partition.outside_usable",
+        [
+            "раздел {0}: LBA {1}..{2} вне допустимой области",
+            "partition {0}: LBA {1}..{2} is outside the usable area",
+            "分区 {0}：LBA {1}..{2} 超出可用区域",
+        ],
+    ),
+    (
+        "partition.bad_usable",
+        [
+            "некорректная допустимая область: LBA {0}..{1}",
+            "invalid usable area: LBA {0}..{1}",
+            "可用区域无效：LBA {0}..{1}",
+        ],
+    ),
+    (
+        "partition.no_ebr",
+        [
+            "нет сигнатуры EBR на LBA {0}",
+            "no EBR signature at LBA {0}",
+            "LBA {0} 处没有 EBR 签名",
+        ],
+    ),
+    (
+        "partition.ebr_outside",
+        [
+            "EBR указывает за расширенный раздел: {0}",
+            "EBR points outside the extended partition: {0}",
+            "EBR 指向扩展分区之外：{0}",
+        ],
+    ),
+    (
+        "partition.past_end",
+        [
+            "раздел {0} выходит за конец устройства",
+            "partition {0} goes past the end of the device",
+            "分区 {0} 超出设备末尾",
+        ],
+    ),
+    (
+        "dev.unsupported_os",
+        [
+            "Поддерживаются только Linux и macOS",
+            "Only Linux and macOS are supported",
+            "仅支持 Linux 和 macOS",
+        ],
+    ),
+    (
+        "main.unsupported_os",
+        [
+            "destroyer поддерживает только Linux и macOS.",
+            "destroyer supports only Linux and macOS.",
+            "destroyer 仅支持 Linux 和 macOS。",
+        ],
+    ),
+    (
+        "report.unknown",
+        [
+            "неизвестно",
+            "unknown",
+            "未知",
+        ],
+    ),
+    (
+        "report.title",
+        [
+            "СЕРТИФИКАТ САНИТИЗАЦИИ НОСИТЕЛЯ (NIST SP 800-88 Rev. 1)",
+            "MEDIA SANITIZATION CERTIFICATE (NIST SP 800-88 Rev. 1)",
+            "介质净化证书（NIST SP 800-88 Rev. 1）",
+        ],
+    ),
+    (
+        "report.performer",
+        [
+            "Исполнитель",
+            "Performed by",
+            "执行者",
+        ],
+    ),
+    (
+        "report.operator",
+        [
+            "  Оператор: {0}",
+            "  Operator: {0}",
+            "  操作员：{0}",
+        ],
+    ),
+    (
+        "report.host",
+        [
+            "  Хост: {0}",
+            "  Host: {0}",
+            "  主机：{0}",
+        ],
+    ),
+    (
+        "report.tool",
+        [
+            "  Инструмент: {0}",
+            "  Tool: {0}",
+            "  工具：{0}",
+        ],
+    ),
+    (
+        "report.medium",
+        [
+            "Носитель",
+            "Media",
+            "介质",
+        ],
+    ),
+    (
+        "report.source",
+        [
+            "  Источник: {0}",
+            "  Source: {0}",
+            "  来源：{0}",
+        ],
+    ),
+    (
+        "report.vendor",
+        [
+            "  Производитель: {0}",
+            "  Manufacturer: {0}",
+            "  厂商：{0}",
+        ],
+    ),
+    (
+        "report.model",
+        [
+            "  Модель: {0}",
+            "  Model: {0}",
+            "  型号：{0}",
+        ],
+    ),
+    (
+        "report.serial",
+        [
+            "  Серийный номер: {0}",
+            "  Serial number: {0}",
+            "  序列号：{0}",
+        ],
+    ),
+    (
+        "report.firmware",
+        [
+            "  Прошивка: {0}",
+            "  Firmware: {0}",
+            "  固件：{0}",
+        ],
+    ),
+    (
+        "report.transport",
+        [
+            "  Шина: {0}",
+            "  Bus: {0}",
+            "  总线：{0}",
+        ],
+    ),
+    (
+        "report.media_type",
+        [
+            "  Тип носителя: {0}",
+            "  Media type: {0}",
+            "  介质类型：{0}",
+        ],
+    ),
+    (
+        "report.size",
+        [
+            "  Размер: {0} байт",
+            "  Size: {0} bytes",
+            "  大小：{0} 字节",
+        ],
+    ),
+    (
+        "report.sanitization",
+        [
+            "Санитизация",
+            "Sanitization",
+            "净化",
+        ],
+    ),
+    (
+        "report.method_type",
+        [
+            "  Категория метода: {0}",
+            "  Method type: {0}",
+            "  方法类别：{0}",
+        ],
+    ),
+    (
+        "report.method",
+        [
+            "  Метод: {0}",
+            "  Method: {0}",
+            "  方法：{0}",
+        ],
+    ),
+    (
+        "report.plan",
+        [
+            "  План: {0}{1}",
+            "  Plan: {0}{1}",
+            "  方案：{0}{1}",
+        ],
+    ),
+    (
+        "report.scheme",
+        [
+            " (схема {0})",
+            " (scheme {0})",
+            "（方案 {0}）",
+        ],
+    ),
+    (
+        "report.mode",
+        [
+            "  Режим: {0}",
+            "  Mode: {0}",
+            "  模式：{0}",
+        ],
+    ),
+    (
+        "report.extents",
+        [
+            "  Участки: {0} шт., {1} байт за проход",
+            "  Ranges: {0}, {1} bytes per pass",
+            "  区段：{0} 个，每遍 {1} 字节",
+        ],
+    ),
+    (
+        "report.verification",
+        [
+            "  Проверка: {0}",
+            "  Verification: {0}",
+            "  校验：{0}",
+        ],
+    ),
+    (
+        "report.resumed",
+        [
+            "  Продолжение по журналу: с прохода {0}, байт {1}",
+            "  Resumed from journal: pass {0}, byte {1}",
+            "  根据日志继续：第 {0} 遍，字节 {1}",
+        ],
+    ),
+    (
+        "report.passes",
+        [
+            "Проходы",
+            "Passes",
+            "遍次",
+        ],
+    ),
+    (
+        "report.verify_skipped",
+        [
+            ", проверка пропущена",
+            ", verification skipped",
+            "，跳过校验",
+        ],
+    ),
+    (
+        "report.verify_passed",
+        [
+            ", проверка пройдена ({0} байт)",
+            ", verification passed ({0} bytes)",
+            "，校验通过（{0} 字节）",
+        ],
+    ),
+    (
+        "report.verify_failed",
+        [
+            ", проверка НЕ пройдена ({0} байт не совпали)",
+            ", verification FAILED ({0} bytes did not match)",
+            "，校验未通过（{0} 字节不一致）",
+        ],
+    ),
+    (
+        "report.pass",
+        [
+            "  {0} {1} ({2}): {3} — {4}, {5} байт, {6}{7}",
+            "  {0} {1} ({2}): {3} — {4}, {5} bytes, {6}{7}",
+            "  {0} {1}（{2}）：{3} — {4}，{5} 字节，{6}{7}",
+        ],
+    ),
+    (
+        "report.none",
+        [
+            "нет",
+            "none",
+            "无",
+        ],
+    ),
+    (
+        "report.result",
+        [
+            "Итог: {0}",
+            "Result: {0}",
+            "结果：{0}",
+        ],
+    ),
+    (
+        "report.status_ok",
+        [
+            "носитель очищен",
+            "media sanitized",
+            "介质已净化",
+        ],
+    ),
+    (
+        "report.status_verify_failed",
+        [
+            "ПРОВЕРКА НЕ ПРОЙДЕНА",
+            "VERIFICATION FAILED",
+            "校验未通过",
+        ],
+    ),
+    (
+        "report.status_interrupted",
+        [
+            "ПРЕРВАНО, стирание не завершено",
+            "INTERRUPTED, the wipe is incomplete",
+            "已中断，擦除未完成",
+        ],
+    ),
+    (
+        "report.status_error",
+        [
+            "ОШИБКА, стирание не завершено",
+            "ERROR, the wipe is incomplete",
+            "错误，擦除未完成",
+        ],
+    ),
+    (
+        "report.started",
+        [
+            "  Начато: {0}",
+            "  Started: {0}",
+            "  开始：{0}",
+        ],
+    ),
+    (
+        "report.finished",
+        [
+            "  Завершено: {0}",
+            "  Finished: {0}",
+            "  结束：{0}",
+        ],
+    ),
+    (
+        "report.signature",
+        [
+            "Подпись: Ed25519, открытый ключ {0} (подписан JSON-вариант отчёта)",
+            "Signature: Ed25519, public key {0} (the JSON version of the report is signed)",
+            "签名：Ed25519，公钥 {0}（签署的是 JSON 版报告）",
+        ],
+    ),
+    (
+        "report.signature_none",
+        [
+            "Подпись: {0}",
+            "Signature: {0}",
+            "签名：{0}",
+        ],
+    ),
+    (
+        "report.unsigned",
+        [
+            "отчёт не подписан",
+            "the report is not signed",
+            "报告未签名",
+        ],
+    ),
+    (
+        "report.malformed",
+        [
+            "подпись не разобрана: {0}",
+            "could not parse the signature: {0}",
+            "无法解析签名：{0}",
+        ],
+    ),
+    (
+        "report.bad_signature",
+        [
+            "подпись НЕ верна: отчёт изменён после подписи или подпись повреждена",
+            "the signature is NOT valid: the report was changed after signing or the signature is damaged",
+            "签名无效：报告在签名后被修改，或签名已损坏",
+        ],
+    ),
+    (
+        "report.wrong_key",
+        [
+            "подпись верна, но сделана другим ключом: {0}",
+            "the signature is valid, but made with another key: {0}",
+            "签名有效，但使用的是另一个密钥：{0}",
+        ],
+    ),
+    (
+        "report.no_field",
+        [
+            "нет поля {0}",
+            "no field {0}",
+            "缺少字段 {0}",
+        ],
+    ),
+    (
+        "report.unclosed_field",
+        [
+            "поле {0} не закрыто",
+            "field {0} is not closed",
+            "字段 {0} 未闭合",
+        ],
+    ),
+    (
+        "report.after_signature",
+        [
+            "после подписи есть неподписанные данные: документ должен заканчиваться полем signature",
+            "unsigned data follows the signature: the document must end with the signature field",
+            "签名之后存在未签名的数据：文档必须以 signature 字段结尾",
+        ],
+    ),
+    (
+        "report.ed25519_only",
+        [
+            "поддерживается только ed25519",
+            "only ed25519 is supported",
+            "仅支持 ed25519",
+        ],
+    ),
+    (
+        "report.bad_public_key",
+        [
+            "некорректный public_key",
+            "invalid public_key",
+            "public_key 无效",
+        ],
+    ),
+    (
+        "report.bad_signature_value",
+        [
+            "некорректная подпись",
+            "invalid signature",
+            "签名值无效",
+        ],
+    ),
+    (
+        "report.not_pkcs8",
+        [
+            "это не ключ Ed25519 в формате PKCS#8",
+            "this is not an Ed25519 key in PKCS#8 format",
+            "这不是 PKCS#8 格式的 Ed25519 密钥",
+        ],
+    ),
+    (
+        "report.expected_private",
+        [
+            "ожидается 64 hex-символа или PEM PRIVATE KEY",
+            "expected 64 hex characters or a PEM PRIVATE KEY",
+            "应为 64 个十六进制字符或 PEM PRIVATE KEY",
+        ],
+    ),
+    (
+        "report.key_len",
+        [
+            "ключ должен быть 32 байта (64 hex-символа)",
+            "the key must be 32 bytes (64 hex characters)",
+            "密钥必须为 32 字节（64 个十六进制字符）",
+        ],
+    ),
+    (
+        "report.not_spki",
+        [
+            "это не открытый ключ Ed25519",
+            "this is not an Ed25519 public key",
+            "这不是 Ed25519 公钥",
+        ],
+    ),
+    (
+        "report.expected_public",
+        [
+            "ожидается 64 hex-символа или PEM PUBLIC KEY",
+            "expected 64 hex characters or a PEM PUBLIC KEY",
+            "应为 64 个十六进制字符或 PEM PUBLIC KEY",
+        ],
+    ),
+    (
+        "args.final_none_with_spec",
+        [
+            "--final none не сочетается с --passes-spec: финальный проход задаётся самим списком",
+            "--final none cannot be combined with --passes-spec: the list itself defines the final pass",
+            "--final none 不能与 --passes-spec 同时使用：最终遍由列表本身决定",
+        ],
+    ),
+    (
+        "extents.from_end_past_end",
+        [
+            "диапазон от конца выходит за конец устройства: {0}",
+            "range counted from the end runs past the end of the device: {0}",
+            "从末尾计算的区间超出了设备末尾：{0}",
+        ],
+    ),
+    (
+        "report.verification_outcome",
+        [
+            "  Итог проверки: {0}",
+            "  Verification outcome: {0}",
+            "  校验结果：{0}",
+        ],
+    ),
+    (
+        "wipe.uring_missing",
+        [
+            "кольцо io_uring не создано для этого прохода",
+            "io_uring ring is not set up for this pass",
+            "本遍未创建 io_uring 环",
+        ],
+    ),
+    (
+        "error.context",
+        [
+            "{0}: {1}",
+            "{0}: {1}",
+            "{0}：{1}",
+        ],
+    ),
+];
@@ -0,0 +1,262 @@
+use crate::i18n::{tr, trf};
+use crate::inuse::{BlockDev, SystemView};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Кто это физически: данные для оператора перед стиранием, для журналов и отчётов.
+/// Неизвестные поля — `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeviceIdentity {
+    /// Имя в sysfs (`sdb`, `nvme0n1`); для раздела — имя диска.
+    pub name: String,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+    pub serial: Option<String>,
+    /// WWN (SATA/SAS) или EUI/NGUID (NVMe).
+    pub wwn: Option<String>,
+    pub firmware: Option<String>,
+    /// `sata`, `sas`, `nvme`, `usb`, `mmc`, `virtio`, `loop`…
+    pub transport: Option<String>,
+    pub rotational: Option<bool>,
+    pub removable: Option<bool>,
+    pub partitions: Vec<PartitionInfo>,
+}
+
+/// Раздел из sysfs с файловой системой и меткой из udev.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartitionInfo {
+    pub name: String,
+    pub start: u64,
+    pub len: u64,
+    pub fs_type: Option<String>,
+    pub label: Option<String>,
+}
+
+impl DeviceIdentity {
+    /// Прочитать всё про устройство `name` (диск или раздел). Аппаратные поля
+    /// берутся у диска; разделы — у самого `name` (у раздела это он сам).
+    pub fn read(view: &SystemView, name: &str) -> Self {
+        let disk: String = view.disk_of(name);
+        let block: PathBuf = view.sys.join("class/block").join(&disk);
+        let device: PathBuf = block.join("device");
+        let udev: Vec<(String, String)> = udev_properties(view, &block);
+        let prop = |key: &str| -> Option<String> {
+            udev.iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .filter(|v| !v.is_empty())
+        };
+        let partitions: Vec<PartitionInfo> = view
+            .block_family(name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|d| d.name != disk)
+            .map(|d| partition_info(view, d))
+            .collect();
+        Self {
+            vendor: read_attr(&device.join("vendor")).or_else(|| prop("ID_VENDOR")),
+            model: read_attr(&device.join("model")).or_else(|| prop("ID_MODEL")),
+            serial: prop("ID_SERIAL_SHORT")
+                .or_else(|| read_attr(&device.join("serial")))
+                .or_else(|| read_attr(&block.join("serial"))),
+            wwn: prop("ID_WWN")
+                .or_else(|| read_attr(&block.join("wwid")))
+                .or_else(|| read_attr(&device.join("wwid"))),
+            firmware: prop("ID_REVISION")
+                .or_else(|| read_attr(&device.join("firmware_rev")))
+                .or_else(|| read_attr(&device.join("rev"))),
+            transport: transport(&disk, &block, prop("ID_BUS").as_deref()),
+            rotational: read_attr(&block.join("queue/rotational")).map(|v| v == "1"),
+            removable: read_attr(&block.join("removable")).map(|v| v == "1"),
+            partitions,
+            name: disk,
+        }
+    }
+
+    /// Строки для вывода перед стиранием.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        let title: String = [self.vendor.as_deref(), self.model.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let mut traits: Vec<&str> = Vec::new();
+        if let Some(t) = &self.transport {
+            traits.push(t);
+        }
+        match self.rotational {
+            Some(true) => traits.push(tr("identity.rotational")),
+            Some(false) => traits.push(tr("identity.non_rotational")),
+            None => {}
+        }
+        match self.removable {
+            Some(true) => traits.push(tr("identity.removable")),
+            Some(false) => traits.push(tr("identity.fixed")),
+            None => {}
+        }
+        lines.push(trf(
+            "identity.device",
+            &[
+                &self.name,
+                &if title.is_empty() {
+                    tr("identity.unknown_model")
+                } else {
+                    &title
+                },
+                &traits.join(", "),
+            ],
+        ));
+        let mut ids: Vec<String> = Vec::new();
+        ids.push(trf(
+            "identity.serial",
+            &[&self.serial.as_deref().unwrap_or(tr("identity.unknown"))],
+        ));
+        if let Some(wwn) = &self.wwn {
+            ids.push(format!("WWN/EUI: {wwn}"));
+        }
+        if let Some(fw) = &self.firmware {
+            ids.push(trf("identity.firmware", &[fw]));
+        }
+        lines.push(format!("  {}", ids.join(", ")));
+        if self.partitions.is_empty() {
+            lines.push(format!("  {}", tr("identity.no_partitions")));
+        }
+        let width: usize = self
+            .partitions
+            .iter()
+            .map(|p| p.name.len())
+            .max()
+            .unwrap_or(0);
+        for p in &self.partitions {
+            let mut line: String = format!(
+                "  {:<width$}  {:>10.2} GB  {}",
+                p.name,
+                p.len as f64 / (1024.0 * 1024.0 * 1024.0),
+                p.fs_type.as_deref().unwrap_or("—")
+            );
+            if let Some(label) = &p.label {
+                line.push_str(&format!(" \"{label}\""));
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Одна строка для таблицы нескольких устройств.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<&str> = [self.vendor.as_deref(), self.model.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        if let Some(t) = &self.transport {
+            parts.push(t);
+        }
+        trf(
+            "identity.summary",
+            &[
+                &if parts.is_empty() {
+                    tr("identity.unknown_model").to_string()
+                } else {
+                    parts.join(" ")
+                },
+                &self.serial.as_deref().unwrap_or(tr("identity.unknown")),
+            ],
+        )
+    }
+}
+
+fn partition_info(view: &SystemView, dev: BlockDev) -> PartitionInfo {
+    let udev: Vec<(String, String)> =
+        udev_properties(view, &view.sys.join("class/block").join(&dev.name));
+    let prop = |key: &str| -> Option<String> {
+        udev.iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .filter(|v| !v.is_empty())
+    };
+    PartitionInfo {
+        fs_type: prop("ID_FS_TYPE"),
+        label: prop("ID_FS_LABEL").or_else(|| prop("ID_PART_ENTRY_NAME")),
+        name: dev.name,
+        start: dev.start,
+        len: dev.len,
+    }
+}
+
+/// Свойства `E:KEY=VALUE` из базы udev (`/run/udev/data/bM:m`).
+fn udev_properties(view: &SystemView, block: &Path) -> Vec<(String, String)> {
+    let Some(devno) = read_attr(&block.join("dev")) else {
+        return Vec::new();
+    };
+    let text: String =
+        fs::read_to_string(view.run.join(format!("udev/data/b{devno}"))).unwrap_or_default();
+    text.lines()
+        .filter_map(|l| l.strip_prefix("E:"))
+        .filter_map(|l| l.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/// Шина по пути устройства в sysfs (USB-мосты видны только так), затем по
+/// ID_BUS из udev и по имени.
+fn transport(disk: &str, block: &Path, udev_bus: Option<&str>) -> Option<String> {
+    let path: String = fs::canonicalize(block)
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+    let by_path: Option<&str> = [
+        ("/usb", "usb"),
+        ("/nvme", "nvme"),
+        ("/ata", "sata"),
+        ("/virtio", "virtio"),
+        ("/mmc", "mmc"),
+    ]
+    .into_iter()
+    .find(|(marker, _)| path.contains(marker))
+    .map(|(_, t)| t);
+    let by_bus: Option<&str> = udev_bus.map(|bus| match bus {
+        "ata" => "sata",
+        "scsi" => "sas",
+        other => other,
+    });
+    let by_name: Option<&str> = [
+        ("nvme", "nvme"),
+        ("mmcblk", "mmc"),
+        ("vd", "virtio"),
+        ("loop", "loop"),
+        ("dm-", "device-mapper"),
+        ("md", "md"),
+    ]
+    .into_iter()
+    .find(|(prefix, _)| disk.starts_with(prefix))
+    .map(|(_, t)| t);
+    match by_path {
+        Some("usb") => Some("usb"),
+        _ => by_bus.or(by_path).or(by_name),
+    }
+    .map(str::to_string)
+}
+
+fn read_attr(path: &Path) -> Option<String> {
+    let v: String = fs::read_to_string(path).ok()?.trim().to_string();
+    (!v.is_empty()).then_some(v)
+}
+
+/// Паспорт блочного устройства по пути; для файла-образа — `None`.
+#[cfg(target_os = "linux")]
+pub fn device_identity(dev_path: &str) -> Option<DeviceIdentity> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let meta: fs::Metadata = fs::metadata(dev_path).ok()?;
+    if !meta.file_type().is_block_device() {
+        return None;
+    }
+    let view: SystemView = SystemView::live();
+    let name: String = crate::inuse::sysfs_name(&view, &meta).ok()?;
+    Some(DeviceIdentity::read(&view, &name))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn device_identity(_dev_path: &str) -> Option<DeviceIdentity> {
+    None
+}
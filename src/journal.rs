@@ -0,0 +1,288 @@
+use crate::dev::{dev_major_minor, safe_sync};
+use crate::extents::ExtentSet;
+use crate::i18n::Message;
+use crate::plan::{PassKind, PassPlan};
+use crate::rng::Seed;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Версия формата журнала. Журналы другой версии не принимаются.
+pub const JOURNAL_VERSION: u32 = 1;
+
+/// Как часто сохранять журнал во время прохода.
+pub const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+const MAGIC: &str = "destroyer-journal";
+
+/// Журнал контрольной точки: что стираем, по какому плану и докуда дошли.
+///
+/// Текстовый формат `ключ=значение`, по строке на поле:
+/// ```text
+/// destroyer-journal 1
+/// device=/dev/sdb
+/// size=2000398934016
+/// fingerprint=serial:WDC_WD20EZRZ-00Z5HB0_WD-WCC4M1234567
+/// plan=random*7,zero
+/// seed=<64 hex>
+/// pass=3
+/// offset=1073741824
+/// pass_seed=<64 hex, только для случайного прохода>
+/// extents=0:67108864,1999331672064:67108864   (только если стирается не всё устройство)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Journal {
+    pub device_path: String,
+    pub device_size: u64,
+    /// Отпечаток устройства (см. `device_fingerprint`).
+    pub fingerprint: String,
+    pub plan: PassPlan,
+    /// Стираемые участки (`--range`/`--exclude`).
+    pub extents: ExtentSet,
+    /// Мастер-сид: из него выводятся сиды всех случайных проходов.
+    pub seed: Seed,
+    /// Текущий проход, с единицы.
+    pub pass: usize,
+    /// Смещение на устройстве, до которого текущий проход гарантированно
+    /// записан на носитель (по участкам `extents`).
+    pub offset: u64,
+}
+
+impl Journal {
+    /// Сид текущего прохода, если он случайный.
+    pub fn pass_seed(&self) -> Option<Seed> {
+        match self.plan.passes().get(self.pass.wrapping_sub(1)) {
+            Some(PassKind::Random) => Some(self.seed.derive_pass(self.pass)),
+            _ => None,
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out: String = format!("{MAGIC} {JOURNAL_VERSION}\n");
+        out.push_str(&format!("device={}\n", self.device_path));
+        out.push_str(&format!("size={}\n", self.device_size));
+        out.push_str(&format!("fingerprint={}\n", self.fingerprint));
+        out.push_str(&format!("plan={}\n", self.plan.spec()));
+        out.push_str(&format!("seed={}\n", self.seed.to_hex()));
+        out.push_str(&format!("pass={}\n", self.pass));
+        out.push_str(&format!("offset={}\n", self.offset));
+        if let Some(seed) = self.pass_seed() {
+            out.push_str(&format!("pass_seed={}\n", seed.to_hex()));
+        }
+        if !self.extents.is_full(self.device_size) {
+            out.push_str(&format!("extents={}\n", self.extents.to_spec()));
+        }
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Message> {
+        let mut lines = text.lines();
+        let header: &str = lines.next().unwrap_or("");
+        match header.split_once(' ') {
+            Some((MAGIC, ver)) if ver.trim() == JOURNAL_VERSION.to_string() => {}
+            Some((MAGIC, ver)) => return Err(Message::new("journal.version").arg(ver.to_string())),
+            _ => return Err(Message::new("journal.not_journal")),
+        }
+
+        let mut device_path: Option<String> = None;
+        let mut device_size: Option<u64> = None;
+        let mut fingerprint: Option<String> = None;
+        let mut plan: Option<PassPlan> = None;
+        let mut seed: Option<Seed> = None;
+        let mut pass: Option<usize> = None;
+        let mut offset: Option<u64> = None;
+        let mut pass_seed: Option<Seed> = None;
+        let mut extents: Option<&str> = None;
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Message::new("journal.bad_line").arg(line.to_string()))?;
+            let bad = || {
+                Message::new("journal.bad_value")
+                    .arg(key.to_string())
+                    .arg(value.to_string())
+            };
+            match key {
+                "device" => device_path = Some(value.to_string()),
+                "size" => device_size = Some(value.parse().map_err(|_| bad())?),
+                "fingerprint" => fingerprint = Some(value.to_string()),
+                "plan" => plan = Some(PassPlan::parse_spec(value)?),
+                "seed" => seed = Some(Seed::from_hex(value).ok_or_else(bad)?),
+                "pass" => pass = Some(value.parse().map_err(|_| bad())?),
+                "offset" => offset = Some(value.parse().map_err(|_| bad())?),
+                "pass_seed" => pass_seed = Some(Seed::from_hex(value).ok_or_else(bad)?),
+                "extents" => extents = Some(value),
+                // Неизвестные ключи пропускаем — задел для совместимых расширений.
+                _ => {}
+            }
+        }
+
+        let missing = |key: &str| Message::new("journal.missing").arg(key.to_string());
+        let device_size: u64 = device_size.ok_or_else(|| missing("size"))?;
+        let extents: ExtentSet = match extents {
+            Some(spec) => ExtentSet::parse_spec(spec, device_size)?,
+            // Журналы без поля extents описывают всё устройство.
+            None => ExtentSet::full(device_size),
+        };
+        let journal = Journal {
+            device_path: device_path.ok_or_else(|| missing("device"))?,
+            device_size,
+            fingerprint: fingerprint.ok_or_else(|| missing("fingerprint"))?,
+            plan: plan.ok_or_else(|| missing("plan"))?,
+            extents,
+            seed: seed.ok_or_else(|| missing("seed"))?,
+            pass: pass.ok_or_else(|| missing("pass"))?,
+            offset: offset.ok_or_else(|| missing("offset"))?,
+        };
+        if journal.pass == 0 || journal.pass > journal.plan.len() {
+            return Err(Message::new("journal.pass_out_of_plan").arg(journal.pass));
+        }
+        if journal.offset > journal.device_size {
+            return Err(Message::new("journal.offset_past_end").arg(journal.offset));
+        }
+        if pass_seed.is_some() && pass_seed != journal.pass_seed() {
+            return Err(Message::new("journal.seed_mismatch"));
+        }
+        Ok(journal)
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text: String = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Атомарно сохранить: пишем во временный файл, fsync, rename, fsync каталога.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut tmp: PathBuf = path.to_path_buf();
+        tmp.as_mut_os_string().push(".tmp");
+        {
+            let mut f: File = File::create(&tmp)?;
+            f.write_all(self.to_text().as_bytes())?;
+            f.sync_all()?;
+        }
+        fs::rename(&tmp, path)?;
+        let dir: &Path = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        if let Ok(d) = File::open(dir) {
+            let _ = d.sync_all();
+        }
+        Ok(())
+    }
+}
+
+/// Отпечаток устройства для сверки при возобновлении.
+/// Блочное устройство: серийный номер/WWN из udev или sysfs, иначе `rdev:major:minor`.
+/// Обычный файл (образ): `file:<st_dev>:<inode>`.
+pub fn device_fingerprint(dev_path: &str) -> io::Result<String> {
+    let meta = fs::metadata(dev_path)?;
+    if meta.file_type().is_block_device() || meta.file_type().is_char_device() {
+        let (major, minor) = dev_major_minor(meta.rdev());
+        if let Some(serial) = device_serial(major, minor) {
+            return Ok(format!("serial:{serial}"));
+        }
+        return Ok(format!("rdev:{major}:{minor}"));
+    }
+    Ok(format!("file:{}:{}", meta.dev(), meta.ino()))
+}
+
+/// Серийный номер или WWN устройства `major:minor` (Linux).
+#[cfg(target_os = "linux")]
+fn device_serial(major: u32, minor: u32) -> Option<String> {
+    if let Ok(udev) = fs::read_to_string(format!("/run/udev/data/b{major}:{minor}")) {
+        for key in ["E:ID_SERIAL=", "E:ID_WWN="] {
+            if let Some(v) = udev.lines().find_map(|l| l.strip_prefix(key))
+                && !v.trim().is_empty()
+            {
+                return Some(v.trim().to_string());
+            }
+        }
+    }
+    for attr in ["device/serial", "device/wwid", "wwid"] {
+        if let Ok(v) = fs::read_to_string(format!("/sys/dev/block/{major}:{minor}/{attr}"))
+            && !v.trim().is_empty()
+        {
+            return Some(v.trim().to_string());
+        }
+    }
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn device_serial(_major: u32, _minor: u32) -> Option<String> {
+    None
+}
+
+/// Куда сохранить журнал при остановке сигналом, если `--journal` не задан:
+/// `/var/tmp` переживает перезагрузку, в отличие от `/tmp`.
+pub fn default_path(device_path: &str) -> PathBuf {
+    let name: &str = Path::new(device_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("device");
+    let dir: &Path = Path::new("/var/tmp");
+    let dir: PathBuf = if dir.is_dir() {
+        dir.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    };
+    dir.join(format!("destroyer-{name}.journal"))
+}
+
+/// Периодическое сохранение журнала во время стирания.
+pub struct Checkpointer {
+    path: PathBuf,
+    journal: Journal,
+    every: Duration,
+    last: Instant,
+}
+
+impl Checkpointer {
+    pub fn new(path: PathBuf, journal: Journal) -> Self {
+        Self {
+            path,
+            journal,
+            every: CHECKPOINT_INTERVAL,
+            last: Instant::now(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Начало (или продолжение) прохода: фиксируем сразу.
+    pub fn start_pass(&mut self, pass: usize, offset: u64) -> io::Result<()> {
+        self.journal.pass = pass;
+        self.journal.offset = offset;
+        self.last = Instant::now();
+        self.journal.save(&self.path)
+    }
+
+    /// Вызывается после каждого чанка. Раз в `CHECKPOINT_INTERVAL` делает sync
+    /// устройства и только потом записывает смещение — в журнале всегда
+    /// смещение, которое уже лежит на носителе.
+    pub fn after_chunk(&mut self, file: &File, done: u64) -> io::Result<()> {
+        if self.last.elapsed() < self.every {
+            return Ok(());
+        }
+        safe_sync(file)?;
+        self.journal.offset = done;
+        self.last = Instant::now();
+        self.journal.save(&self.path)
+    }
+
+    /// Всё стёрто — журнал больше не нужен.
+    pub fn finish(self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,5 +1,28 @@
 pub mod app;
 pub mod args;
+pub mod batch;
+pub mod confirm;
 pub mod dev;
+pub mod ed25519;
+pub mod error;
+pub mod estimate;
+pub mod events;
+pub mod extents;
+pub mod i18n;
+pub mod identity;
+pub mod inuse;
+pub mod journal;
+pub mod multi;
+pub mod offload;
+pub mod partition;
+pub mod plan;
 pub mod platform;
+pub mod progress;
+pub mod report;
+pub mod rng;
+pub mod signals;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+pub mod uring;
+pub mod verify;
 pub mod wipe;
+pub mod wiper;
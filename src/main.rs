@@ -10,6 +10,8 @@ fn main() {
 
 #[cfg(not(any(target_os = "linux", target_os = "macos")))]
 fn main() {
-    eprintln!("destroyer поддерживает только Linux и macOS.");
+    use destroyer::i18n::{self, Lang};
+    i18n::set_lang(Lang::detect(std::env::args_os().skip(1)));
+    eprintln!("{}", i18n::tr("main.unsupported_os"));
     std::process::exit(1);
 }
@@ -0,0 +1,176 @@
+use crate::i18n::{tr, trf};
+use crate::wipe::{ProgressSnapshot, SharedProgress, append_eta, push_percent};
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Состояние строки устройства в общей таблице.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowState {
+    Running,
+    Done,
+    Failed,
+}
+
+/// Строка общей таблицы: одно устройство.
+pub struct BoardRow {
+    pub name: String,
+    pub total_passes: usize,
+    /// Байт за один проход (`ExtentSet::total`).
+    pub pass_size: u64,
+    pub progress: Arc<SharedProgress>,
+    pub state: RowState,
+}
+
+impl BoardRow {
+    fn total_bytes(&self) -> u64 {
+        self.pass_size.saturating_mul(self.total_passes as u64)
+    }
+
+    /// Оставшееся время записи по средней скорости с момента старта.
+    fn eta(&self, snap: &ProgressSnapshot, elapsed: Duration) -> Option<Duration> {
+        match self.state {
+            RowState::Done | RowState::Failed => return Some(Duration::ZERO),
+            RowState::Running => {}
+        }
+        let total: u64 = self.total_bytes();
+        if snap.total_done >= total {
+            return Some(Duration::ZERO);
+        }
+        let secs: f64 = elapsed.as_secs_f64();
+        if snap.total_done == 0 || secs <= 0.0 {
+            return None;
+        }
+        let speed: f64 = snap.total_done as f64 / secs;
+        Some(Duration::from_secs_f64(
+            (total - snap.total_done) as f64 / speed,
+        ))
+    }
+}
+
+/// Общая таблица прогресса при стирании нескольких устройств: строка на
+/// устройство и итоговая строка с общим ETA (по самому медленному диску —
+/// устройства пишутся параллельно).
+pub struct Board {
+    rows: Vec<BoardRow>,
+    started: Instant,
+    /// Перерисовывать на месте (терминал) или печатать таблицу целиком.
+    redraw: bool,
+    drawn_lines: usize,
+    name_width: usize,
+}
+
+impl Board {
+    pub fn new(rows: Vec<BoardRow>, redraw: bool) -> Self {
+        let name_width: usize = rows
+            .iter()
+            .map(|r| r.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        Self {
+            rows,
+            started: Instant::now(),
+            redraw,
+            drawn_lines: 0,
+            name_width,
+        }
+    }
+
+    pub fn set_state(&mut self, index: usize, state: RowState) {
+        self.rows[index].state = state;
+    }
+
+    /// Текст таблицы: строки устройств и строка «Всего».
+    pub fn render(&self) -> Vec<String> {
+        let elapsed: Duration = self.started.elapsed();
+        let mut lines: Vec<String> = Vec::with_capacity(self.rows.len() + 1);
+        let mut all_total: u64 = 0;
+        let mut all_done: u64 = 0;
+        let mut slowest: Option<Duration> = Some(Duration::ZERO);
+        for row in &self.rows {
+            let snap: ProgressSnapshot = row.progress.snapshot();
+            let eta: Option<Duration> = row.eta(&snap, elapsed);
+            all_total = all_total.saturating_add(row.total_bytes());
+            let done: u64 = match row.state {
+                RowState::Done => row.total_bytes(),
+                _ => snap.total_done.min(row.total_bytes()),
+            };
+            all_done = all_done.saturating_add(done);
+            slowest = match (slowest, eta) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                _ => None,
+            };
+
+            let mut line: Vec<u8> = Vec::with_capacity(96);
+            line.extend_from_slice(format!("{:<w$}  ", row.name, w = self.name_width).as_bytes());
+            match row.state {
+                RowState::Done => line.extend_from_slice(tr("multi.done").as_bytes()),
+                RowState::Failed => line.extend_from_slice(tr("multi.failed").as_bytes()),
+                RowState::Running => {
+                    if snap.paused {
+                        line.extend_from_slice(tr("multi.paused").as_bytes());
+                        line.extend_from_slice(" | ".as_bytes());
+                    }
+                    if snap.verifying {
+                        line.extend_from_slice(tr("multi.verify").as_bytes());
+                    } else {
+                        line.extend_from_slice(
+                            trf("multi.pass", &[&snap.pass.max(1), &row.total_passes]).as_bytes(),
+                        );
+                    }
+                    line.extend_from_slice(" | ".as_bytes());
+                    push_percent(&mut line, percent(snap.pass_done, row.pass_size));
+                    line.extend_from_slice(b"% | ");
+                    line.extend_from_slice(tr("multi.eta").as_bytes());
+                    line.extend_from_slice(b": ");
+                    append_eta(&mut line, eta);
+                }
+            }
+            lines.push(String::from_utf8_lossy(&line).into_owned());
+        }
+        let mut line: Vec<u8> = Vec::with_capacity(64);
+        line.extend_from_slice(
+            format!("{:<w$}  ", tr("multi.total"), w = self.name_width).as_bytes(),
+        );
+        push_percent(&mut line, percent(all_done, all_total));
+        line.extend_from_slice(b"% | ");
+        line.extend_from_slice(tr("progress.eta_total").as_bytes());
+        line.extend_from_slice(b": ");
+        append_eta(&mut line, slowest);
+        lines.push(String::from_utf8_lossy(&line).into_owned());
+        lines
+    }
+
+    /// Вывести таблицу; в терминале — поверх предыдущей.
+    pub fn draw(&mut self) {
+        let lines: Vec<String> = self.render();
+        let mut out: Vec<u8> = Vec::new();
+        if self.redraw && self.drawn_lines > 0 {
+            // Курсор вверх на высоту прошлой таблицы, строки затираются до конца.
+            out.extend_from_slice(format!("\x1b[{}A", self.drawn_lines).as_bytes());
+        }
+        for line in &lines {
+            if self.redraw {
+                out.extend_from_slice(b"\r\x1b[2K");
+            }
+            out.extend_from_slice(line.as_bytes());
+            out.push(b'\n');
+        }
+        if !self.redraw {
+            out.push(b'\n');
+        }
+        self.drawn_lines = lines.len();
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(&out);
+        let _ = stdout.flush();
+    }
+}
+
+fn percent(done: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 100;
+    }
+    (done as f64 / total as f64 * 100.0)
+        .round()
+        .clamp(0.0, 100.0) as u64
+}
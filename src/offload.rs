@@ -0,0 +1,104 @@
+use crate::i18n::tr;
+use std::fs::File;
+use std::io;
+
+/// Проход, который выполняет ядро, а не запись буферов из userspace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Offload {
+    /// BLKZEROOUT: устройство само пишет нули (WRITE ZEROES / WRITE SAME).
+    ZeroOut,
+    /// BLKDISCARD: TRIM/UNMAP — контроллер забывает блоки.
+    Discard,
+    /// BLKSECDISCARD: discard с гарантией физического удаления (eMMC и др.).
+    SecDiscard,
+}
+
+/// Сколько байт отдаём ядру за один вызов: ioctl не прерывается, а прогресс
+/// и журнал двигаются между вызовами.
+pub const OFFLOAD_CHUNK: u64 = 64 * 1024 * 1024;
+
+impl Offload {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "zeroout" => Some(Offload::ZeroOut),
+            "discard" => Some(Offload::Discard),
+            "secdiscard" => Some(Offload::SecDiscard),
+            _ => None,
+        }
+    }
+
+    /// Имя для `--passes-spec`/`--final`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Offload::ZeroOut => "zeroout",
+            Offload::Discard => "discard",
+            Offload::SecDiscard => "secdiscard",
+        }
+    }
+
+    /// Описание для вывода.
+    pub fn title(self) -> &'static str {
+        match self {
+            Offload::ZeroOut => tr("offload.zeroout"),
+            Offload::Discard => "discard (BLKDISCARD)",
+            Offload::SecDiscard => "secure discard (BLKSECDISCARD)",
+        }
+    }
+
+    /// После discard блочное устройство может вернуть что угодно, проверять нечего.
+    /// Для обычного файла discard — дыра, она читается нулями.
+    pub fn leaves_zeros_on_device(self) -> bool {
+        matches!(self, Offload::ZeroOut)
+    }
+}
+
+/// Операция не поддерживается устройством или ФС — пора писать нули самим.
+pub fn is_unsupported(e: &io::Error) -> bool {
+    matches!(
+        e.raw_os_error(),
+        Some(libc::EOPNOTSUPP) | Some(libc::ENOTTY) | Some(libc::ENOSYS)
+    ) || e.kind() == io::ErrorKind::Unsupported
+}
+
+/// Выполнить `kind` над `[offset, offset + len)`. Блочное устройство — ioctl,
+/// обычный файл — `fallocate` (ZERO_RANGE для zeroout, PUNCH_HOLE для discard).
+#[cfg(target_os = "linux")]
+pub fn offload_range(file: &File, kind: Offload, offset: u64, len: u64) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    const BLKDISCARD: libc::c_ulong = 0x1277;
+    const BLKSECDISCARD: libc::c_ulong = 0x127D;
+    const BLKZEROOUT: libc::c_ulong = 0x127F;
+
+    let fd = file.as_raw_fd();
+    let rc: libc::c_int = if file.metadata()?.is_file() {
+        let mode: libc::c_int = libc::FALLOC_FL_KEEP_SIZE
+            | match kind {
+                Offload::ZeroOut => libc::FALLOC_FL_ZERO_RANGE,
+                Offload::Discard | Offload::SecDiscard => libc::FALLOC_FL_PUNCH_HOLE,
+            };
+        // SAFETY: fd открыт, диапазон проверен вызывающим.
+        unsafe { libc::fallocate(fd, mode, offset as libc::off_t, len as libc::off_t) }
+    } else {
+        let request: libc::c_ulong = match kind {
+            Offload::ZeroOut => BLKZEROOUT,
+            Offload::Discard => BLKDISCARD,
+            Offload::SecDiscard => BLKSECDISCARD,
+        };
+        let range: [u64; 2] = [offset, len];
+        // SAFETY: ioctl читает два u64 из range.
+        unsafe { libc::ioctl(fd, request as _, range.as_ptr()) }
+    };
+    if rc < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn offload_range(_file: &File, _kind: Offload, _offset: u64, _len: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        crate::i18n::Message::new("offload.linux_only"),
+    ))
+}
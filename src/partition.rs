@@ -0,0 +1,526 @@
+use crate::extents::{Extent, ExtentSet};
+use crate::i18n::Message;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// Сигнатура заголовка GPT.
+const GPT_SIGNATURE: &[u8; 8] = b"EFI PART";
+/// Тип записи MBR «защитный GPT».
+const MBR_PROTECTIVE: u8 = 0xEE;
+/// Предел записей GPT: спецификация требует минимум 128, больше на практике не бывает.
+const GPT_MAX_ENTRIES: u32 = 1024;
+/// Предел цепочки EBR — защита от зацикленных расширенных разделов.
+const MBR_MAX_LOGICAL: usize = 128;
+
+/// Тип таблицы разделов.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableKind {
+    Gpt,
+    Mbr,
+}
+
+impl TableKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            TableKind::Gpt => "GPT",
+            TableKind::Mbr => "MBR",
+        }
+    }
+}
+
+/// Раздел из таблицы; смещения — в байтах от начала всего диска.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Partition {
+    /// Номер как у ядра Linux: для GPT — позиция в массиве записей с единицы,
+    /// для MBR — 1..4 основные, с 5 — логические.
+    pub number: u32,
+    pub start: u64,
+    pub len: u64,
+    /// Тип: GUID (GPT) или байт `0xNN` (MBR).
+    pub type_id: String,
+    /// Имя раздела GPT; у MBR пустое.
+    pub label: String,
+    /// Уникальный GUID раздела (только GPT).
+    pub guid: Option<String>,
+}
+
+impl Partition {
+    pub fn extent(&self) -> Extent {
+        Extent {
+            start: self.start,
+            len: self.len,
+        }
+    }
+
+    /// Понятное имя типа, если он известен.
+    pub fn type_name(&self) -> &'static str {
+        match self.type_id.as_str() {
+            "C12A7328-F81F-11D2-BA4B-00A0C93EC93B" | "0xEF" => "EFI System",
+            "21686148-6449-6E6F-744E-656564454649" => "BIOS boot",
+            "E3C9E316-0B5C-4DB8-817D-F92DF00215AE" => "Microsoft reserved",
+            "EBD0A0A2-B9E5-4433-87C0-68B6B72699C7" | "0x07" => "Microsoft basic data",
+            "DE94BBA4-06D1-4D40-A16A-BFD50179D6AC" | "0x27" => "Windows recovery",
+            "0FC63DAF-8483-4772-8E79-3D69D8477DE4" | "0x83" => "Linux filesystem",
+            "4F68BCE3-E8CD-4DB1-96E7-FBCAF984B709" => "Linux root (x86-64)",
+            "BC13C2FF-59E6-4262-A352-B275FD6F7172" => "Linux extended boot",
+            "0657FD6D-A4AB-43C4-84E5-0933C84B4F4F" | "0x82" => "Linux swap",
+            "E6D6D379-F507-44C2-A23C-238F2A3DF928" | "0x8E" => "Linux LVM",
+            "A19D880F-05FC-4D3B-A006-743F0F84911E" | "0xFD" => "Linux RAID",
+            "CA7D7CCB-63ED-4C53-861C-1742536059CC" => "Linux LUKS",
+            "7C3457EF-0000-11AA-AA11-00306543ECAC" => "Apple APFS",
+            "48465300-0000-11AA-AA11-00306543ECAC" => "Apple HFS+",
+            "0x0B" | "0x0C" => "FAT32",
+            "0x06" | "0x0E" => "FAT16",
+            _ => "",
+        }
+    }
+}
+
+/// Прочитанная и проверенная таблица разделов.
+#[derive(Clone, Debug)]
+pub struct PartitionTable {
+    pub kind: TableKind,
+    /// Размер логического сектора, в котором записана таблица.
+    pub sector: u64,
+    /// GUID диска (только GPT).
+    pub disk_guid: Option<String>,
+    /// Основной заголовок GPT повреждён, таблица взята из резервной копии.
+    pub from_backup: bool,
+    pub partitions: Vec<Partition>,
+    /// Область, где могут лежать разделы (GPT: first..last usable LBA).
+    pub usable: Extent,
+    /// Служебные структуры таблицы, которые нельзя трогать: MBR, заголовки
+    /// и массивы записей GPT (основные и резервные), цепочка EBR.
+    pub reserved: Vec<Extent>,
+}
+
+/// Какой раздел стирать: `--partition N` или `--partition-label LABEL`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartitionSelector {
+    Number(u32),
+    Label(String),
+}
+
+impl PartitionSelector {
+    pub fn matches(&self, p: &Partition) -> bool {
+        match self {
+            PartitionSelector::Number(n) => p.number == *n,
+            PartitionSelector::Label(label) => p.label == *label,
+        }
+    }
+
+    pub fn describe(&self) -> Message {
+        match self {
+            PartitionSelector::Number(n) => Message::new("partition.by_number").arg(*n),
+            PartitionSelector::Label(label) => {
+                Message::new("partition.by_label").arg(label.to_string())
+            }
+        }
+    }
+}
+
+impl PartitionTable {
+    /// Неразмеченное место: допустимая область минус разделы и служебные структуры.
+    /// Сюда входят и промежутки до первого и после последнего раздела.
+    pub fn unallocated(&self) -> ExtentSet {
+        let mut set: ExtentSet = ExtentSet::from_extents(vec![self.usable]);
+        for p in &self.partitions {
+            set = set.subtract(p.extent());
+        }
+        for r in &self.reserved {
+            set = set.subtract(*r);
+        }
+        set
+    }
+
+    /// Найти раздел. Для метки требуется ровно одно совпадение.
+    pub fn find(&self, selector: &PartitionSelector) -> Result<&Partition, Message> {
+        let found: Vec<&Partition> = self
+            .partitions
+            .iter()
+            .filter(|p| selector.matches(p))
+            .collect();
+        match found.as_slice() {
+            [one] => Ok(one),
+            [] => Err(Message::new("partition.not_found").arg(selector.describe())),
+            _ => Err(Message::new("partition.ambiguous").arg(selector.describe())),
+        }
+    }
+}
+
+/// Прочитать таблицу разделов всего диска `dev_path`.
+pub fn read_partition_table(
+    dev_path: &str,
+    device_size: u64,
+    logical_sector: u64,
+) -> io::Result<PartitionTable> {
+    let mut f: File = File::open(dev_path)?;
+    parse_partition_table(&mut f, device_size, logical_sector)
+}
+
+/// Разобрать таблицу разделов из образа диска.
+///
+/// Сначала ищем GPT (с проверкой CRC заголовка и массива записей; при
+/// повреждённом основном заголовке — резервный в конце диска), затем MBR.
+/// GPT ищется и с логическим сектором устройства, и с 512/4096 байт — у образов
+/// 4Kn-дисков размер сектора иначе не узнать.
+pub fn parse_partition_table<R: Read + Seek>(
+    r: &mut R,
+    device_size: u64,
+    logical_sector: u64,
+) -> io::Result<PartitionTable> {
+    let mbr: Vec<u8> = read_at(r, 0, 512)?;
+    let has_mbr: bool = mbr[510] == 0x55 && mbr[511] == 0xAA;
+    let protective: bool = has_mbr && (0..4).any(|i| mbr[446 + i * 16 + 4] == MBR_PROTECTIVE);
+
+    let mut sectors: Vec<u64> = vec![logical_sector.max(512)];
+    for guess in [512, 4096] {
+        if !sectors.contains(&guess) {
+            sectors.push(guess);
+        }
+    }
+    let mut gpt_error: Option<Message> = None;
+    for &sector in &sectors {
+        match read_gpt(r, device_size, sector) {
+            Ok(Some(table)) => return Ok(table),
+            Ok(None) => {}
+            Err(e) => {
+                gpt_error.get_or_insert(e);
+            }
+        }
+    }
+    if let Some(e) = gpt_error {
+        return Err(invalid(Message::new("partition.gpt_corrupt").arg(e)));
+    }
+    if protective {
+        return Err(invalid(Message::new("partition.gpt_missing")));
+    }
+    if !has_mbr {
+        return Err(invalid(Message::new("partition.no_table")));
+    }
+    read_mbr(r, &mbr, device_size, logical_sector.max(512))
+}
+
+/// Найти и проверить GPT при размере сектора `sector`.
+/// `Ok(None)` — сигнатуры нет ни в основном, ни в резервном заголовке.
+fn read_gpt<R: Read + Seek>(
+    r: &mut R,
+    device_size: u64,
+    sector: u64,
+) -> Result<Option<PartitionTable>, Message> {
+    if device_size < sector * 3 {
+        return Ok(None);
+    }
+    let last_lba: u64 = device_size / sector - 1;
+    let primary = read_gpt_at(r, device_size, sector, 1);
+    let primary_err: Option<Message> = match primary {
+        Ok(Some(table)) => return Ok(Some(table)),
+        Ok(None) => None,
+        Err(e) => Some(e),
+    };
+    match read_gpt_at(r, device_size, sector, last_lba) {
+        Ok(Some(mut table)) => {
+            table.from_backup = true;
+            Ok(Some(table))
+        }
+        Ok(None) => primary_err.map_or(Ok(None), Err),
+        Err(e) => {
+            Err(primary_err.unwrap_or_else(|| Message::new("partition.backup_header").arg(e)))
+        }
+    }
+}
+
+fn read_gpt_at<R: Read + Seek>(
+    r: &mut R,
+    device_size: u64,
+    sector: u64,
+    lba: u64,
+) -> Result<Option<PartitionTable>, Message> {
+    let io_err = |e: io::Error| Message::new("partition.read_error").arg(e);
+    let hdr: Vec<u8> = read_at(r, lba * sector, sector as usize).map_err(io_err)?;
+    if &hdr[0..8] != GPT_SIGNATURE {
+        return Ok(None);
+    }
+    let header_size: usize = le32(&hdr, 12) as usize;
+    if header_size < 92 || header_size > sector as usize {
+        return Err(Message::new("partition.header_size").arg(header_size));
+    }
+    let stored_crc: u32 = le32(&hdr, 16);
+    let mut check: Vec<u8> = hdr[..header_size].to_vec();
+    check[16..20].fill(0);
+    if crc32(&check) != stored_crc {
+        return Err(Message::new("partition.header_crc").arg(lba));
+    }
+    if le64(&hdr, 24) != lba {
+        return Err(Message::new("partition.header_lba")
+            .arg(lba)
+            .arg(le64(&hdr, 24)));
+    }
+    let first_usable: u64 = le64(&hdr, 40);
+    let last_usable: u64 = le64(&hdr, 48);
+    let disk_guid: String = format_guid(&hdr[56..72]);
+    let entries_lba: u64 = le64(&hdr, 72);
+    let num_entries: u32 = le32(&hdr, 80);
+    let entry_size: u32 = le32(&hdr, 84);
+    let entries_crc: u32 = le32(&hdr, 88);
+    let usable_end: Option<u64> = last_usable
+        .checked_add(1)
+        .and_then(|n| n.checked_mul(sector));
+    if last_usable < first_usable || usable_end.is_none_or(|end| end > device_size) {
+        return Err(Message::new("partition.bad_usable")
+            .arg(first_usable)
+            .arg(last_usable));
+    }
+    if num_entries > GPT_MAX_ENTRIES || entry_size < 128 || !entry_size.is_multiple_of(128) {
+        return Err(Message::new("partition.entries_size")
+            .arg(num_entries)
+            .arg(entry_size));
+    }
+    let array_len: u64 = num_entries as u64 * entry_size as u64;
+    if entries_lba.saturating_mul(sector).saturating_add(array_len) > device_size {
+        return Err(Message::new("partition.entries_past_end"));
+    }
+    let array: Vec<u8> = read_at(r, entries_lba * sector, array_len as usize).map_err(io_err)?;
+    if crc32(&array) != entries_crc {
+        return Err(Message::new("partition.entries_crc").arg(entries_lba));
+    }
+
+    let mut partitions: Vec<Partition> = Vec::new();
+    for (idx, entry) in array.chunks_exact(entry_size as usize).enumerate() {
+        if entry[0..16].iter().all(|&b| b == 0) {
+            continue;
+        }
+        let first: u64 = le64(entry, 32);
+        let last: u64 = le64(entry, 40);
+        let number: u32 = idx as u32 + 1;
+        let outside = || {
+            Message::new("partition.outside_usable")
+                .arg(number)
+                .arg(first)
+                .arg(last)
+        };
+        if last < first || first < first_usable || last > last_usable {
+            return Err(outside());
+        }
+        let start: u64 = first.checked_mul(sector).ok_or_else(outside)?;
+        let len: u64 = (last - first + 1).checked_mul(sector).ok_or_else(outside)?;
+        partitions.push(Partition {
+            number,
+            start,
+            len,
+            type_id: format_guid(&entry[0..16]),
+            label: utf16_name(&entry[56..128]),
+            guid: Some(format_guid(&entry[16..32])),
+        });
+    }
+
+    // Служебные области: всё вне допустимой области (MBR, заголовки и массивы
+    // при стандартной раскладке) плюс фактические массивы обеих копий.
+    let usable = Extent {
+        start: first_usable * sector,
+        len: (last_usable - first_usable + 1) * sector,
+    };
+    let mut reserved: Vec<Extent> = vec![
+        Extent {
+            start: 0,
+            len: usable.start,
+        },
+        Extent {
+            start: usable.end(),
+            len: device_size - usable.end(),
+        },
+        Extent {
+            start: lba * sector,
+            len: sector,
+        },
+        Extent {
+            start: entries_lba * sector,
+            len: array_len,
+        },
+    ];
+    let alt_lba: u64 = le64(&hdr, 32);
+    if alt_lba.saturating_add(1).saturating_mul(sector) <= device_size {
+        reserved.push(Extent {
+            start: alt_lba * sector,
+            len: sector,
+        });
+        // Вторая копия может быть повреждена — резервируем её массив по заголовку,
+        // если он хоть как-то читается, без проверки CRC.
+        if let Ok(alt) = read_at(r, alt_lba * sector, sector as usize)
+            && &alt[0..8] == GPT_SIGNATURE
+        {
+            let alt_entries: u64 = le64(&alt, 72);
+            let alt_len: u64 = le32(&alt, 80) as u64 * le32(&alt, 84) as u64;
+            if alt_entries.saturating_mul(sector).saturating_add(alt_len) <= device_size {
+                reserved.push(Extent {
+                    start: alt_entries * sector,
+                    len: alt_len,
+                });
+            }
+        }
+    }
+
+    Ok(Some(PartitionTable {
+        kind: TableKind::Gpt,
+        sector,
+        disk_guid: Some(disk_guid),
+        from_backup: false,
+        partitions,
+        usable,
+        reserved,
+    }))
+}
+
+fn read_mbr<R: Read + Seek>(
+    r: &mut R,
+    mbr: &[u8],
+    device_size: u64,
+    sector: u64,
+) -> io::Result<PartitionTable> {
+    let mut partitions: Vec<Partition> = Vec::new();
+    let mut reserved: Vec<Extent> = vec![Extent {
+        start: 0,
+        len: sector,
+    }];
+    let mut extended: Option<(u64, u64)> = None;
+    for i in 0..4 {
+        let e: &[u8] = &mbr[446 + i * 16..446 + (i + 1) * 16];
+        let kind: u8 = e[4];
+        let start: u64 = le32(e, 8) as u64;
+        let count: u64 = le32(e, 12) as u64;
+        if kind == 0 || count == 0 {
+            continue;
+        }
+        if matches!(kind, 0x05 | 0x0F | 0x85) {
+            extended = Some((start, count));
+            continue;
+        }
+        partitions.push(mbr_partition(i as u32 + 1, kind, start, count, sector));
+    }
+
+    // Логические разделы: цепочка EBR внутри расширенного раздела.
+    if let Some((ext_start, ext_count)) = extended {
+        let mut ebr_lba: u64 = ext_start;
+        let mut number: u32 = 5;
+        for _ in 0..MBR_MAX_LOGICAL {
+            let ebr: Vec<u8> = read_at(r, ebr_lba * sector, 512)?;
+            if ebr[510] != 0x55 || ebr[511] != 0xAA {
+                return Err(invalid(Message::new("partition.no_ebr").arg(ebr_lba)));
+            }
+            reserved.push(Extent {
+                start: ebr_lba * sector,
+                len: sector,
+            });
+            let (kind, rel, count) = (ebr[446 + 4], le32(&ebr, 446 + 8), le32(&ebr, 446 + 12));
+            if kind != 0 && count != 0 {
+                partitions.push(mbr_partition(
+                    number,
+                    kind,
+                    ebr_lba + rel as u64,
+                    count as u64,
+                    sector,
+                ));
+                number += 1;
+            }
+            let next: u64 = le32(&ebr, 462 + 8) as u64;
+            if ebr[462 + 4] == 0 || next == 0 {
+                break;
+            }
+            if next >= ext_count {
+                return Err(invalid(Message::new("partition.ebr_outside").arg(next)));
+            }
+            ebr_lba = ext_start + next;
+        }
+    }
+
+    for p in &partitions {
+        if p.start + p.len > device_size {
+            return Err(invalid(Message::new("partition.past_end").arg(p.number)));
+        }
+    }
+    Ok(PartitionTable {
+        kind: TableKind::Mbr,
+        sector,
+        disk_guid: None,
+        from_backup: false,
+        partitions,
+        usable: Extent {
+            start: 0,
+            len: device_size,
+        },
+        reserved,
+    })
+}
+
+fn mbr_partition(number: u32, kind: u8, start: u64, count: u64, sector: u64) -> Partition {
+    Partition {
+        number,
+        start: start * sector,
+        len: count * sector,
+        type_id: format!("0x{kind:02X}"),
+        label: String::new(),
+        guid: None,
+    }
+}
+
+fn read_at<R: Read + Seek>(r: &mut R, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+    let mut buf: Vec<u8> = vec![0u8; len];
+    r.seek(SeekFrom::Start(offset))?;
+    r.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn invalid(msg: Message) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn le32(b: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([b[at], b[at + 1], b[at + 2], b[at + 3]])
+}
+
+fn le64(b: &[u8], at: usize) -> u64 {
+    let mut v = [0u8; 8];
+    v.copy_from_slice(&b[at..at + 8]);
+    u64::from_le_bytes(v)
+}
+
+/// GUID в каноническом виде: первые три поля little-endian, остальное как есть.
+pub fn format_guid(b: &[u8]) -> String {
+    format!(
+        "{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}",
+        le32(b, 0),
+        u16::from_le_bytes([b[4], b[5]]),
+        u16::from_le_bytes([b[6], b[7]]),
+        b[8],
+        b[9],
+        b[10],
+        b[11],
+        b[12],
+        b[13],
+        b[14],
+        b[15]
+    )
+}
+
+/// Имя раздела GPT: UTF-16LE до первого нуля.
+fn utf16_name(b: &[u8]) -> String {
+    let units: Vec<u16> = b
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&u| u != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// CRC-32 (IEEE 802.3, отражённый полином 0xEDB88320) — как в заголовке GPT.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask: u32 = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
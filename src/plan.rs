@@ -0,0 +1,321 @@
+use crate::i18n::Message;
+use crate::offload::Offload;
+use crate::rng::Seed;
+use crate::wipe::PassData;
+
+/// Описание одного прохода в плане.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PassKind {
+    /// Случайные данные (поток ChaCha20 из сида прохода).
+    Random,
+    /// Один фиксированный байт.
+    Byte(u8),
+    /// Повторяющийся многобайтовый шаблон.
+    Pattern(Vec<u8>),
+    /// Побитовое дополнение предыдущего прохода.
+    Complement,
+    /// Проход силами ядра: `zeroout`, `discard`, `secdiscard`.
+    Offload(Offload),
+}
+
+/// Максимальный множитель повтора в `--passes-spec` (`random*N`).
+pub const MAX_REPEAT: usize = 1000;
+
+impl PassKind {
+    /// Разобрать один вид прохода: `random`, `zero`, `one`, `complement`,
+    /// `zeroout`/`discard`/`secdiscard`, байт `0x55` или шаблон `0x92:0x49:0x24`.
+    pub fn parse(s: &str) -> Result<Self, Message> {
+        if let Some(kind) = Offload::parse(s) {
+            return Ok(PassKind::Offload(kind));
+        }
+        match s {
+            "random" | "rand" => return Ok(PassKind::Random),
+            "zero" | "zeros" => return Ok(PassKind::Byte(0x00)),
+            "one" | "ones" => return Ok(PassKind::Byte(0xFF)),
+            "complement" | "~" => return Ok(PassKind::Complement),
+            _ => {}
+        }
+        let mut bytes: Vec<u8> = Vec::new();
+        for part in s.split(':') {
+            let hex: &str = part
+                .strip_prefix("0x")
+                .or_else(|| part.strip_prefix("0X"))
+                .ok_or_else(|| Message::new("plan.unknown_pass").arg(s.to_string()))?;
+            if hex.is_empty() || hex.len() > 2 {
+                return Err(Message::new("plan.expected_byte").arg(part.to_string()));
+            }
+            let b: u8 = u8::from_str_radix(hex, 16)
+                .map_err(|_| Message::new("plan.expected_byte").arg(part.to_string()))?;
+            bytes.push(b);
+        }
+        if bytes.len() == 1 {
+            Ok(PassKind::Byte(bytes[0]))
+        } else {
+            Ok(PassKind::Pattern(bytes))
+        }
+    }
+
+    /// Каноническая запись для `--passes-spec`.
+    pub fn spec(&self) -> String {
+        match self {
+            PassKind::Random => "random".to_string(),
+            PassKind::Byte(0x00) => "zero".to_string(),
+            PassKind::Byte(b) => format!("0x{b:02X}"),
+            PassKind::Pattern(p) => {
+                let parts: Vec<String> = p.iter().map(|b| format!("0x{b:02X}")).collect();
+                parts.join(":")
+            }
+            PassKind::Complement => "complement".to_string(),
+            PassKind::Offload(kind) => kind.name().to_string(),
+        }
+    }
+}
+
+/// Упорядоченный список проходов.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PassPlan {
+    passes: Vec<PassKind>,
+}
+
+impl PassPlan {
+    /// План должен быть непустым, а `Complement` не может быть первым проходом.
+    pub fn new(passes: Vec<PassKind>) -> Result<Self, Message> {
+        if passes.is_empty() {
+            return Err(Message::new("plan.empty"));
+        }
+        if passes[0] == PassKind::Complement {
+            return Err(Message::new("plan.complement_first"));
+        }
+        Ok(Self { passes })
+    }
+
+    /// Классический план destroyer: `count - 1` случайных проходов, затем нули.
+    pub fn standard(count: usize) -> Self {
+        Self::standard_with_final(count, Some(PassKind::Byte(0x00)))
+    }
+
+    /// `count` проходов: случайные, последний — `last`.
+    /// `None` — отдельного финального прохода нет, все `count` проходов случайные.
+    pub fn standard_with_final(count: usize, last: Option<PassKind>) -> Self {
+        let count: usize = count.max(1);
+        let mut passes: Vec<PassKind> = vec![PassKind::Random; count];
+        if let Some(kind) = last {
+            passes[count - 1] = kind;
+        }
+        Self { passes }
+    }
+
+    /// Разобрать `--passes-spec`: элементы через запятую, у каждого
+    /// необязательный множитель `*N`, например `random*3,0x55,0xAA,0x92:0x49:0x24,zero`.
+    pub fn parse_spec(spec: &str) -> Result<Self, Message> {
+        let mut passes: Vec<PassKind> = Vec::new();
+        for item in spec.split(',') {
+            let item: &str = item.trim();
+            if item.is_empty() {
+                return Err(Message::new("plan.empty_item"));
+            }
+            let (kind, count): (&str, usize) = match item.split_once('*') {
+                Some((kind, count)) => {
+                    let n: usize = count
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| Message::new("plan.bad_repeat").arg(item.to_string()))?;
+                    if n == 0 || n > MAX_REPEAT {
+                        return Err(Message::new("plan.repeat_range")
+                            .arg(MAX_REPEAT)
+                            .arg(item.to_string()));
+                    }
+                    (kind.trim(), n)
+                }
+                None => (item, 1),
+            };
+            let kind: PassKind = PassKind::parse(kind)?;
+            passes.extend(std::iter::repeat_n(kind, count));
+        }
+        Self::new(passes)
+    }
+
+    /// Каноническая запись плана; соседние одинаковые проходы сворачиваются в `*N`.
+    /// `PassPlan::parse_spec(&plan.spec())` даёт тот же план.
+    pub fn spec(&self) -> String {
+        let mut items: Vec<String> = Vec::new();
+        let mut i: usize = 0;
+        while i < self.passes.len() {
+            let mut run: usize = 1;
+            while i + run < self.passes.len() && self.passes[i + run] == self.passes[i] {
+                run += 1;
+            }
+            let kind: String = self.passes[i].spec();
+            if run > 1 {
+                items.push(format!("{kind}*{run}"));
+            } else {
+                items.push(kind);
+            }
+            i += run;
+        }
+        items.join(",")
+    }
+
+    /// Дописать финальный проход после списка (`--passes-spec … --final KIND`).
+    pub fn with_final(mut self, last: PassKind) -> Self {
+        self.passes.push(last);
+        self
+    }
+
+    pub fn passes(&self) -> &[PassKind] {
+        &self.passes
+    }
+
+    pub fn len(&self) -> usize {
+        self.passes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Превратить план в конкретное содержимое проходов.
+    /// Сид прохода `i` (с единицы) выводится из мастер-сида, дополнение
+    /// берёт содержимое предыдущего прохода и инвертирует его.
+    pub fn resolve(&self, master: &Seed) -> Vec<PassData> {
+        let mut out: Vec<PassData> = Vec::with_capacity(self.passes.len());
+        for (idx, kind) in self.passes.iter().enumerate() {
+            let data: PassData = match kind {
+                PassKind::Random => PassData::Random {
+                    seed: master.derive_pass(idx + 1),
+                    invert: false,
+                },
+                PassKind::Byte(b) => PassData::Pattern(vec![*b]),
+                PassKind::Pattern(p) => PassData::Pattern(p.clone()),
+                PassKind::Offload(kind) => PassData::Offload(*kind),
+                PassKind::Complement => match out.last() {
+                    Some(prev) => prev.complement(),
+                    None => PassData::Pattern(vec![0xFF]),
+                },
+            };
+            out.push(data);
+        }
+        out
+    }
+}
+
+/// Именованные схемы санитизации.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// DoD 5220.22-M (E): символ, его дополнение, случайные данные.
+    Dod3,
+    /// DoD 5220.22-M (ECE): (E) + (C) + (E), 7 проходов.
+    Dod7,
+    /// Питер Гутман, 35 проходов (шаблоны 5–31 в фиксированном порядке).
+    Gutmann,
+    /// NIST SP 800-88 Clear: один проход нулями.
+    NistClear,
+    /// BSI VSITR: 0x00/0xFF трижды, затем 0xAA.
+    Vsitr,
+    /// Брюс Шнайер: единицы, нули, пять случайных проходов.
+    Schneier,
+    /// RCMP TSSIT OPS-II: 0x00/0xFF трижды, затем случайные данные.
+    Rcmp,
+}
+
+impl Scheme {
+    pub const ALL: [Scheme; 7] = [
+        Scheme::Dod3,
+        Scheme::Dod7,
+        Scheme::Gutmann,
+        Scheme::NistClear,
+        Scheme::Vsitr,
+        Scheme::Schneier,
+        Scheme::Rcmp,
+    ];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scheme| scheme.name() == s)
+    }
+
+    /// Имя для `--scheme`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Scheme::Dod3 => "dod3",
+            Scheme::Dod7 => "dod7",
+            Scheme::Gutmann => "gutmann",
+            Scheme::NistClear => "nist-clear",
+            Scheme::Vsitr => "vsitr",
+            Scheme::Schneier => "schneier",
+            Scheme::Rcmp => "rcmp",
+        }
+    }
+
+    /// Полное название стандарта.
+    pub fn title(self) -> &'static str {
+        match self {
+            Scheme::Dod3 => "DoD 5220.22-M (E)",
+            Scheme::Dod7 => "DoD 5220.22-M (ECE)",
+            Scheme::Gutmann => "Gutmann",
+            Scheme::NistClear => "NIST SP 800-88 Clear",
+            Scheme::Vsitr => "BSI VSITR",
+            Scheme::Schneier => "Schneier",
+            Scheme::Rcmp => "RCMP TSSIT OPS-II",
+        }
+    }
+
+    pub fn plan(self) -> PassPlan {
+        use PassKind::{Byte, Complement, Pattern, Random};
+        let passes: Vec<PassKind> = match self {
+            Scheme::Dod3 => vec![Byte(0x00), Complement, Random],
+            Scheme::Dod7 => vec![
+                Byte(0x00),
+                Complement,
+                Random,
+                Byte(0x96),
+                Byte(0x00),
+                Complement,
+                Random,
+            ],
+            Scheme::Gutmann => {
+                let mut p: Vec<PassKind> = vec![Random; 4];
+                p.push(Byte(0x55));
+                p.push(Byte(0xAA));
+                p.push(Pattern(vec![0x92, 0x49, 0x24]));
+                p.push(Pattern(vec![0x49, 0x24, 0x92]));
+                p.push(Pattern(vec![0x24, 0x92, 0x49]));
+                for nibble in 0x0..=0xFu8 {
+                    p.push(Byte(nibble << 4 | nibble));
+                }
+                p.push(Pattern(vec![0x92, 0x49, 0x24]));
+                p.push(Pattern(vec![0x49, 0x24, 0x92]));
+                p.push(Pattern(vec![0x24, 0x92, 0x49]));
+                p.push(Pattern(vec![0x6D, 0xB6, 0xDB]));
+                p.push(Pattern(vec![0xB6, 0xDB, 0x6D]));
+                p.push(Pattern(vec![0xDB, 0x6D, 0xB6]));
+                p.extend(std::iter::repeat_n(Random, 4));
+                p
+            }
+            Scheme::NistClear => vec![Byte(0x00)],
+            Scheme::Vsitr => vec![
+                Byte(0x00),
+                Byte(0xFF),
+                Byte(0x00),
+                Byte(0xFF),
+                Byte(0x00),
+                Byte(0xFF),
+                Byte(0xAA),
+            ],
+            Scheme::Schneier => {
+                let mut p: Vec<PassKind> = vec![Byte(0xFF), Byte(0x00)];
+                p.extend(std::iter::repeat_n(Random, 5));
+                p
+            }
+            Scheme::Rcmp => vec![
+                Byte(0x00),
+                Byte(0xFF),
+                Byte(0x00),
+                Byte(0xFF),
+                Byte(0x00),
+                Byte(0xFF),
+                Random,
+            ],
+        };
+        PassPlan { passes }
+    }
+}
@@ -0,0 +1,337 @@
+//! Куда уходит прогресс прохода. `ProgressTracker` считает байты, скорость и
+//! ETA и отдаёт события `ProgressEvent` приёмникам `ProgressSink`: строке
+//! статуса в терминале, потоку `--progress=json`, каналу для библиотеки.
+
+use crate::events::{DeviceEvents, PROGRESS_INTERVAL};
+use crate::i18n::{tr, trf};
+use crate::report::VerifyOutcome;
+use crate::signals::PauseCause;
+use crate::wipe::{append_eta, push_num, push_percent};
+use std::io::{self, Write};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+/// Что делает проход: пишет или проверяет записанное чтением.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Write,
+    Verify,
+}
+
+impl Phase {
+    /// Поле `phase` в JSON.
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Write => "write",
+            Phase::Verify => "verify",
+        }
+    }
+}
+
+/// Состояние прохода после очередного чанка.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressUpdate {
+    pub phase: Phase,
+    pub pass: usize,
+    pub total_passes: usize,
+    pub pass_done: u64,
+    pub pass_bytes: u64,
+    /// При проверке — то же, что `pass_done`/`pass_bytes`: чтение не входит
+    /// в общий объём записи.
+    pub total_done: u64,
+    pub total_bytes: u64,
+    /// Байт в секунду с начала прохода; записанное до возобновления не считается.
+    pub rate: f64,
+    pub eta_pass: Option<Duration>,
+    /// При проверке `None`.
+    pub eta_total: Option<Duration>,
+}
+
+/// Событие прогресса.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgressEvent {
+    /// Проход начат; после возобновления `resumed` байт уже записано.
+    PassStart {
+        phase: Phase,
+        pass: usize,
+        total_passes: usize,
+        pass_bytes: u64,
+        resumed: u64,
+    },
+    /// Записан (или прочитан при проверке) очередной чанк.
+    Progress(ProgressUpdate),
+    /// Сообщение посреди прохода, например откат offload на нули.
+    Note(String),
+    /// Запись встала на паузу; всё до `offset` уже на носителе.
+    Paused {
+        pass: usize,
+        offset: u64,
+        cause: PauseCause,
+    },
+    /// Пауза снята.
+    Resumed { pass: usize },
+    /// Итог проверки чтением после прохода `pass`; проверка пропускается,
+    /// если содержимое после discard не определено.
+    Verified { pass: usize, outcome: VerifyOutcome },
+    /// Проход дописан и сброшен на носитель (проверка — дочитана).
+    PassEnd {
+        phase: Phase,
+        pass: usize,
+        bytes: u64,
+        elapsed: Duration,
+    },
+}
+
+/// Приёмник событий прогресса. Вызывается из потока записи после каждого
+/// чанка, поэтому должен быть быстрым: медленную работу — в другой поток.
+pub trait ProgressSink: Send {
+    fn event(&mut self, event: &ProgressEvent);
+}
+
+/// Ничего не выводит (`--quiet`, параллельное стирание).
+pub struct SilentSink;
+
+impl ProgressSink for SilentSink {
+    fn event(&mut self, _event: &ProgressEvent) {}
+}
+
+/// Строка статуса в stdout, перерисовывается через `\r`.
+pub struct TerminalSink {
+    line_buf: Vec<u8>,
+    /// Подписи на текущем языке: строка печатается на каждый чанк, искать их
+    /// в каталоге каждый раз незачем.
+    labels: [&'static str; 5],
+}
+
+impl TerminalSink {
+    pub fn new() -> Self {
+        Self {
+            line_buf: Vec::with_capacity(96),
+            labels: [
+                tr("progress.verify"),
+                tr("progress.pass"),
+                tr("progress.percent"),
+                tr("progress.eta_pass"),
+                tr("progress.eta_total"),
+            ],
+        }
+    }
+
+    fn print_status(&mut self, u: &ProgressUpdate) {
+        if u.pass_bytes == 0 {
+            return;
+        }
+        let percent: f64 = (u.pass_done as f64 / u.pass_bytes as f64 * 100.0).clamp(0.0, 100.0);
+        let [verify, pass, progress, eta_pass, eta_total] = self.labels;
+        self.line_buf.clear();
+        self.line_buf.push(b'\r');
+        if u.phase == Phase::Verify {
+            self.line_buf.extend_from_slice(verify.as_bytes());
+        } else {
+            self.line_buf.extend_from_slice(pass.as_bytes());
+            self.line_buf.push(b' ');
+            push_num(&mut self.line_buf, u.pass as u64);
+            self.line_buf.push(b'/');
+            push_num(&mut self.line_buf, u.total_passes as u64);
+        }
+        self.line_buf.extend_from_slice(b" | ");
+        self.line_buf.extend_from_slice(progress.as_bytes());
+        self.line_buf.extend_from_slice(b": ");
+        push_percent(&mut self.line_buf, percent.round() as u64);
+        self.line_buf.extend_from_slice(b"% | ");
+        self.line_buf.extend_from_slice(eta_pass.as_bytes());
+        self.line_buf.extend_from_slice(b": ");
+        append_eta(&mut self.line_buf, u.eta_pass);
+        self.line_buf.extend_from_slice(b" | ");
+        self.line_buf.extend_from_slice(eta_total.as_bytes());
+        self.line_buf.extend_from_slice(b": ");
+        // Проверка — одно чтение, её «всего» совпадает с проходом.
+        append_eta(&mut self.line_buf, u.eta_total.or(u.eta_pass));
+
+        let _ = io::stdout().write_all(&self.line_buf);
+        let _ = io::stdout().flush();
+    }
+}
+
+impl Default for TerminalSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressSink for TerminalSink {
+    fn event(&mut self, event: &ProgressEvent) {
+        match event {
+            ProgressEvent::PassStart { .. } | ProgressEvent::Verified { .. } => {}
+            ProgressEvent::Progress(u) => self.print_status(u),
+            // Отдельной строкой, не портя строку статуса.
+            ProgressEvent::Note(msg) => println!("\n{msg}"),
+            ProgressEvent::Paused { .. } | ProgressEvent::Resumed { .. } => print_pause(event),
+            ProgressEvent::PassEnd { .. } => {
+                let _ = io::stdout().write_all(b"\n");
+            }
+        }
+    }
+}
+
+/// Пауза и её снятие — в stderr отдельной строкой.
+fn print_pause(event: &ProgressEvent) {
+    match event {
+        ProgressEvent::Paused { offset, cause, .. } => eprintln!(
+            "\n{}",
+            trf("signals.paused", &[offset, &cause.resume_hint()])
+        ),
+        ProgressEvent::Resumed { .. } => eprintln!("{}", tr("signals.resumed")),
+        _ => {}
+    }
+}
+
+/// Состояние по SIGUSR1 — отдельной строкой для stderr, даже с `--quiet`.
+pub fn status_line(u: &ProgressUpdate) -> String {
+    let percent: f64 = if u.pass_bytes == 0 {
+        100.0
+    } else {
+        (u.pass_done as f64 / u.pass_bytes as f64 * 100.0).clamp(0.0, 100.0)
+    };
+    let mut line: Vec<u8> = Vec::with_capacity(160);
+    if u.phase == Phase::Verify {
+        line.extend_from_slice(trf("wipe.status_verify", &[&u.pass]).as_bytes());
+    } else {
+        line.extend_from_slice(trf("wipe.status_pass", &[&u.pass, &u.total_passes]).as_bytes());
+    }
+    line.extend_from_slice(
+        trf(
+            "wipe.status_done",
+            &[
+                &u.pass_done,
+                &u.pass_bytes,
+                &format_args!("{percent:.1}"),
+                &format_args!("{:.1}", u.rate / 1_000_000.0),
+            ],
+        )
+        .as_bytes(),
+    );
+    append_eta(&mut line, u.eta_pass);
+    if u.phase == Phase::Write {
+        line.extend_from_slice(
+            trf("wipe.status_total", &[&u.total_done, &u.total_bytes]).as_bytes(),
+        );
+        append_eta(&mut line, u.eta_total);
+    }
+    String::from_utf8_lossy(&line).into_owned()
+}
+
+/// Только сообщения о паузе, без строки статуса: с `--quiet` о паузе всё
+/// равно нужно знать, иначе стирание выглядит зависшим.
+pub struct PauseNoticeSink;
+
+impl ProgressSink for PauseNoticeSink {
+    fn event(&mut self, event: &ProgressEvent) {
+        print_pause(event);
+    }
+}
+
+/// Пропускает `Progress` чаще `PROGRESS_INTERVAL`; последний чанк прохода
+/// проходит всегда, чтобы потребитель увидел 100%.
+struct Throttle {
+    last: Option<Instant>,
+}
+
+impl Throttle {
+    fn pass(&mut self, u: &ProgressUpdate) -> bool {
+        if u.pass_done < u.pass_bytes && self.last.is_some_and(|t| t.elapsed() < PROGRESS_INTERVAL)
+        {
+            return false;
+        }
+        self.last = Some(Instant::now());
+        true
+    }
+}
+
+/// События `progress` в поток `--progress=json`. Начало и конец прохода
+/// (`pass_start`, `pass_end`) пишет сам `Wiper` — с сидом и временем.
+pub struct JsonSink {
+    events: DeviceEvents,
+    throttle: Throttle,
+}
+
+impl JsonSink {
+    pub fn new(events: DeviceEvents) -> Self {
+        Self {
+            events,
+            throttle: Throttle { last: None },
+        }
+    }
+}
+
+impl ProgressSink for JsonSink {
+    fn event(&mut self, event: &ProgressEvent) {
+        let u: &ProgressUpdate = match event {
+            ProgressEvent::Progress(u) => u,
+            ProgressEvent::Paused {
+                pass,
+                offset,
+                cause,
+            } => {
+                let event = self
+                    .events
+                    .event("paused")
+                    .num("pass", *pass as u64)
+                    .num("offset", *offset)
+                    .str("cause", cause.name());
+                self.events.emit(event);
+                return;
+            }
+            ProgressEvent::Resumed { pass } => {
+                let event = self.events.event("resumed").num("pass", *pass as u64);
+                self.events.emit(event);
+                return;
+            }
+            _ => return,
+        };
+        if !self.throttle.pass(u) {
+            return;
+        }
+        let event = self
+            .events
+            .event("progress")
+            .str("phase", u.phase.name())
+            .num("pass", u.pass as u64)
+            .num("total_passes", u.total_passes as u64)
+            .num("pass_bytes_done", u.pass_done)
+            .num("pass_bytes", u.pass_bytes)
+            .num("total_bytes_done", u.total_done)
+            .num("total_bytes", u.total_bytes)
+            .float("rate_bps", u.rate)
+            .secs("eta_pass_s", u.eta_pass)
+            .secs("eta_total_s", u.eta_total);
+        self.events.emit(event);
+    }
+}
+
+/// События в канал — для программ, встраивающих `Wiper`. `Progress` не чаще
+/// `PROGRESS_INTERVAL`, остальные — все. Закрытый приёмник не мешает записи.
+pub struct ChannelSink {
+    tx: Sender<ProgressEvent>,
+    throttle: Throttle,
+}
+
+impl ChannelSink {
+    pub fn new(tx: Sender<ProgressEvent>) -> Self {
+        Self {
+            tx,
+            throttle: Throttle { last: None },
+        }
+    }
+}
+
+impl ProgressSink for ChannelSink {
+    fn event(&mut self, event: &ProgressEvent) {
+        if let ProgressEvent::Progress(u) = event
+            && !self.throttle.pass(u)
+        {
+            return;
+        }
+        let _ = self.tx.send(event.clone());
+    }
+}
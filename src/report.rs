@@ -0,0 +1,733 @@
+//! Отчёт о стирании (`--report`): сертификат санитизации по полям
+//! NIST SP 800-88 Rev. 1, приложение G. JSON — основной вид, текстовый —
+//! для печати. С `--sign-key` JSON подписывается Ed25519.
+
+use crate::ed25519::{self, KEY_LEN, SIGNATURE_LEN, SigningKey};
+use crate::events::quote;
+use crate::i18n::{Message, neutral, tr, trf};
+use crate::identity::DeviceIdentity;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Имя схемы отчёта в поле `schema`.
+pub const REPORT_SCHEMA: &str = "destroyer.report";
+/// Версия схемы отчёта в поле `v`.
+pub const REPORT_VERSION: u32 = 1;
+
+/// Подпись — последнее поле JSON; подписаны байты документа без него.
+const SIGNATURE_FIELD: &str = ",\n  \"signature\": ";
+
+/// PKCS#8-обёртка закрытого ключа Ed25519 (`openssl genpkey -algorithm ed25519`).
+const PKCS8_PREFIX: [u8; 16] = [
+    0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20,
+];
+/// SubjectPublicKeyInfo открытого ключа Ed25519 (`openssl pkey -pubout`).
+const SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+/// Итог проверки чтением после прохода.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyOutcome {
+    /// После discard содержимое не определено — проверять нечего.
+    Skipped,
+    Passed {
+        bytes_checked: u64,
+    },
+    Failed {
+        bytes_checked: u64,
+        mismatched_bytes: u64,
+    },
+}
+
+/// Один выполненный проход.
+#[derive(Clone, Debug)]
+pub struct PassRecord {
+    pub pass_no: usize,
+    /// Проход в записи `--passes-spec` (`random`, `0x55`, `zeroout`).
+    pub kind: String,
+    pub describe: Message,
+    pub started: SystemTime,
+    pub finished: SystemTime,
+    /// Байт, записанных (или отданных ядру) за этот запуск.
+    pub bytes: u64,
+    /// `None` — проверка после прохода не заказана.
+    pub verify: Option<VerifyOutcome>,
+}
+
+impl PassRecord {
+    pub fn elapsed(&self) -> Duration {
+        self.finished
+            .duration_since(self.started)
+            .unwrap_or_default()
+    }
+
+    /// Скорость прохода, байт/с.
+    pub fn throughput(&self) -> Option<f64> {
+        let secs: f64 = self.elapsed().as_secs_f64();
+        (secs > 0.0).then(|| self.bytes as f64 / secs)
+    }
+}
+
+/// Всё, что попадает в отчёт об одном устройстве.
+#[derive(Clone, Debug)]
+pub struct Report {
+    /// Кто выполнял (`--operator`, иначе SUDO_USER/USER).
+    pub operator: Option<String>,
+    pub host: Option<String>,
+    pub device_path: String,
+    pub device_size: u64,
+    /// Отпечаток из `journal::device_fingerprint`.
+    pub fingerprint: String,
+    pub identity: Option<DeviceIdentity>,
+    /// Цель — файл-образ, а не блочное устройство.
+    pub is_file: bool,
+    pub plan: String,
+    pub scheme: Option<String>,
+    pub mode: String,
+    /// Стираемые участки `(начало, длина)`.
+    pub extents: Vec<(u64, u64)>,
+    /// Был ли проход, после которого содержимое не определено (discard).
+    pub has_discard: bool,
+    pub verify: bool,
+    pub verify_each_pass: bool,
+    /// Продолжение по журналу: с какого прохода и смещения.
+    pub resumed_from: Option<(usize, u64)>,
+    pub passes: Vec<PassRecord>,
+    pub started: SystemTime,
+    pub finished: SystemTime,
+    /// `ok`, `failed`, `verify_failed` или `interrupted`.
+    pub status: String,
+    pub message: Option<Message>,
+}
+
+/// Значение JSON для отчёта: порядок полей фиксирован, вывод с отступами.
+enum Value {
+    Null,
+    Num(String),
+    Str(String),
+    Arr(Vec<Value>),
+    Obj(Vec<(&'static str, Value)>),
+}
+
+impl Value {
+    fn str(s: &str) -> Value {
+        Value::Str(s.to_string())
+    }
+
+    fn opt(s: Option<&str>) -> Value {
+        s.map_or(Value::Null, Value::str)
+    }
+
+    fn num(n: u64) -> Value {
+        Value::Num(n.to_string())
+    }
+
+    fn float(v: f64) -> Value {
+        if v.is_finite() {
+            Value::Num(format!("{v:.3}"))
+        } else {
+            Value::Null
+        }
+    }
+
+    fn render(&self, indent: usize, out: &mut String) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Num(n) => out.push_str(n),
+            Value::Str(s) => out.push_str(&quote(s)),
+            Value::Arr(items) if items.is_empty() => out.push_str("[]"),
+            // Короткие массивы чисел (участки) — в одну строку.
+            Value::Arr(items) if items.iter().all(|v| matches!(v, Value::Num(_))) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.render(indent, out);
+                }
+                out.push(']');
+            }
+            Value::Arr(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&" ".repeat(indent + 2));
+                    item.render(indent + 2, out);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                out.push(']');
+            }
+            Value::Obj(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&" ".repeat(indent + 2));
+                    out.push_str(&quote(key));
+                    out.push_str(": ");
+                    value.render(indent + 2, out);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                out.push('}');
+            }
+        }
+    }
+}
+
+impl Report {
+    /// Категория метода по NIST: перезапись всех адресуемых блоков — Clear.
+    pub fn method_type(&self) -> &'static str {
+        "Clear"
+    }
+
+    pub fn method_used(&self) -> &'static str {
+        if self.has_discard {
+            "Overwrite, Other (discard)"
+        } else {
+            "Overwrite"
+        }
+    }
+
+    /// Способ проверки по NIST: полное перечитывание или никакого.
+    pub fn verification_method(&self) -> &'static str {
+        if self.verify || self.verify_each_pass {
+            "Full"
+        } else {
+            "None"
+        }
+    }
+
+    /// Итог проверки чтением: сбой любого прохода — `failed`, иначе итог
+    /// последнего проверявшегося прохода (`passed` или `skipped`).
+    pub fn verification_outcome(&self) -> &'static str {
+        let mut outcome: &'static str = "none";
+        for verify in self.passes.iter().filter_map(|p| p.verify.as_ref()) {
+            outcome = match verify {
+                VerifyOutcome::Failed { .. } => return "failed",
+                VerifyOutcome::Passed { .. } => "passed",
+                VerifyOutcome::Skipped => "skipped",
+            };
+        }
+        outcome
+    }
+
+    /// Тип носителя: по признаку вращения, для образа — файл.
+    pub fn media_type(&self) -> Option<&'static str> {
+        if self.is_file {
+            return Some("File");
+        }
+        match self.identity.as_ref()?.rotational? {
+            true => Some("Magnetic"),
+            false => Some("Flash Memory"),
+        }
+    }
+
+    /// JSON без подписи; подпись добавляет `sign_document`.
+    pub fn to_json(&self) -> String {
+        let id: Option<&DeviceIdentity> = self.identity.as_ref();
+        let field = |f: fn(&DeviceIdentity) -> Option<&String>| {
+            Value::opt(id.and_then(f).map(String::as_str))
+        };
+        let passes: Vec<Value> = self
+            .passes
+            .iter()
+            .map(|p| {
+                let verification: Value = match &p.verify {
+                    None => Value::Null,
+                    Some(VerifyOutcome::Skipped) => {
+                        Value::Obj(vec![("status", Value::str("skipped"))])
+                    }
+                    Some(VerifyOutcome::Passed { bytes_checked }) => Value::Obj(vec![
+                        ("status", Value::str("passed")),
+                        ("bytes_checked", Value::num(*bytes_checked)),
+                        ("mismatched_bytes", Value::num(0)),
+                    ]),
+                    Some(VerifyOutcome::Failed {
+                        bytes_checked,
+                        mismatched_bytes,
+                    }) => Value::Obj(vec![
+                        ("status", Value::str("failed")),
+                        ("bytes_checked", Value::num(*bytes_checked)),
+                        ("mismatched_bytes", Value::num(*mismatched_bytes)),
+                    ]),
+                };
+                Value::Obj(vec![
+                    ("pass", Value::num(p.pass_no as u64)),
+                    ("kind", Value::str(&p.kind)),
+                    ("describe", Value::Str(neutral(&p.describe))),
+                    ("started", Value::Str(format_utc(p.started))),
+                    ("finished", Value::Str(format_utc(p.finished))),
+                    ("bytes", Value::num(p.bytes)),
+                    ("elapsed_s", Value::float(p.elapsed().as_secs_f64())),
+                    (
+                        "throughput_bps",
+                        p.throughput().map_or(Value::Null, Value::float),
+                    ),
+                    ("verification", verification),
+                ])
+            })
+            .collect();
+        let doc = Value::Obj(vec![
+            ("schema", Value::str(REPORT_SCHEMA)),
+            ("v", Value::num(REPORT_VERSION as u64)),
+            ("standard", Value::str("NIST SP 800-88 Rev. 1")),
+            (
+                "tool",
+                Value::Obj(vec![
+                    ("name", Value::str("destroyer")),
+                    ("version", Value::str(env!("CARGO_PKG_VERSION"))),
+                ]),
+            ),
+            (
+                "sanitizer",
+                Value::Obj(vec![
+                    ("operator", Value::opt(self.operator.as_deref())),
+                    ("host", Value::opt(self.host.as_deref())),
+                ]),
+            ),
+            (
+                "media",
+                Value::Obj(vec![
+                    ("source", Value::str(&self.device_path)),
+                    ("manufacturer", field(|i| i.vendor.as_ref())),
+                    ("model", field(|i| i.model.as_ref())),
+                    ("serial", field(|i| i.serial.as_ref())),
+                    ("wwn", field(|i| i.wwn.as_ref())),
+                    ("firmware", field(|i| i.firmware.as_ref())),
+                    ("transport", field(|i| i.transport.as_ref())),
+                    ("media_type", Value::opt(self.media_type())),
+                    ("size_bytes", Value::num(self.device_size)),
+                    ("fingerprint", Value::str(&self.fingerprint)),
+                ]),
+            ),
+            (
+                "sanitization",
+                Value::Obj(vec![
+                    ("method_type", Value::str(self.method_type())),
+                    ("method_used", Value::str(self.method_used())),
+                    ("plan", Value::str(&self.plan)),
+                    ("scheme", Value::opt(self.scheme.as_deref())),
+                    ("mode", Value::str(&self.mode)),
+                    (
+                        "extents",
+                        Value::Arr(
+                            self.extents
+                                .iter()
+                                .map(|(s, l)| Value::Arr(vec![Value::num(*s), Value::num(*l)]))
+                                .collect(),
+                        ),
+                    ),
+                    (
+                        "verification_method",
+                        Value::str(self.verification_method()),
+                    ),
+                    (
+                        "verification_outcome",
+                        Value::str(self.verification_outcome()),
+                    ),
+                    (
+                        "resumed_from",
+                        match self.resumed_from {
+                            Some((pass, offset)) => Value::Obj(vec![
+                                ("pass", Value::num(pass as u64)),
+                                ("offset", Value::num(offset)),
+                            ]),
+                            None => Value::Null,
+                        },
+                    ),
+                ]),
+            ),
+            ("passes", Value::Arr(passes)),
+            (
+                "result",
+                Value::Obj(vec![
+                    ("status", Value::str(&self.status)),
+                    ("started", Value::Str(format_utc(self.started))),
+                    ("finished", Value::Str(format_utc(self.finished))),
+                    (
+                        "message",
+                        Value::opt(self.message.as_ref().map(|m| neutral(m)).as_deref()),
+                    ),
+                ]),
+            ),
+        ]);
+        let mut out: String = String::new();
+        doc.render(0, &mut out);
+        out.push('\n');
+        out
+    }
+
+    /// Текстовый вид для печати и подшивки.
+    pub fn to_text(&self, public_key: Option<&[u8; KEY_LEN]>) -> String {
+        let id: Option<&DeviceIdentity> = self.identity.as_ref();
+        let unknown: &str = tr("report.unknown");
+        let known = |v: Option<&String>| v.map_or(unknown.to_string(), String::clone);
+        let mut lines: Vec<String> = vec![
+            tr("report.title").to_string(),
+            String::new(),
+            tr("report.performer").to_string(),
+            trf("report.operator", &[&known(self.operator.as_ref())]),
+            trf("report.host", &[&known(self.host.as_ref())]),
+            trf(
+                "report.tool",
+                &[&format_args!("destroyer {}", env!("CARGO_PKG_VERSION"))],
+            ),
+            String::new(),
+            tr("report.medium").to_string(),
+            trf("report.source", &[&self.device_path]),
+            trf(
+                "report.vendor",
+                &[&known(id.and_then(|i| i.vendor.as_ref()))],
+            ),
+            trf("report.model", &[&known(id.and_then(|i| i.model.as_ref()))]),
+            trf(
+                "report.serial",
+                &[&known(id.and_then(|i| i.serial.as_ref()))],
+            ),
+        ];
+        if let Some(wwn) = id.and_then(|i| i.wwn.as_ref()) {
+            lines.push(format!("  WWN/EUI: {wwn}"));
+        }
+        if let Some(fw) = id.and_then(|i| i.firmware.as_ref()) {
+            lines.push(trf("report.firmware", &[fw]));
+        }
+        if let Some(t) = id.and_then(|i| i.transport.as_ref()) {
+            lines.push(trf("report.transport", &[t]));
+        }
+        lines.push(trf(
+            "report.media_type",
+            &[&self.media_type().unwrap_or(unknown)],
+        ));
+        lines.push(trf("report.size", &[&self.device_size]));
+        lines.push(String::new());
+        lines.push(tr("report.sanitization").to_string());
+        lines.push(trf("report.method_type", &[&self.method_type()]));
+        lines.push(trf("report.method", &[&self.method_used()]));
+        lines.push(trf(
+            "report.plan",
+            &[
+                &self.plan,
+                &self
+                    .scheme
+                    .as_deref()
+                    .map(|s| trf("report.scheme", &[&s]))
+                    .unwrap_or_default(),
+            ],
+        ));
+        lines.push(trf("report.mode", &[&self.mode]));
+        let bytes: u64 = self.extents.iter().map(|(_, l)| l).sum();
+        lines.push(trf("report.extents", &[&self.extents.len(), &bytes]));
+        lines.push(trf("report.verification", &[&self.verification_method()]));
+        lines.push(trf(
+            "report.verification_outcome",
+            &[&self.verification_outcome()],
+        ));
+        if let Some((pass, offset)) = self.resumed_from {
+            lines.push(trf("report.resumed", &[&pass, &offset]));
+        }
+        lines.push(String::new());
+        lines.push(tr("report.passes").to_string());
+        for p in &self.passes {
+            let verify: String = match &p.verify {
+                None => String::new(),
+                Some(VerifyOutcome::Skipped) => tr("report.verify_skipped").to_string(),
+                Some(VerifyOutcome::Passed { bytes_checked }) => {
+                    trf("report.verify_passed", &[bytes_checked])
+                }
+                Some(VerifyOutcome::Failed {
+                    mismatched_bytes, ..
+                }) => trf("report.verify_failed", &[mismatched_bytes]),
+            };
+            lines.push(trf(
+                "report.pass",
+                &[
+                    &p.pass_no,
+                    &p.kind,
+                    &p.describe,
+                    &format_utc(p.started),
+                    &format_utc(p.finished),
+                    &p.bytes,
+                    &p.throughput().map_or("—".to_string(), |s| {
+                        format!("{:.1} MB/s", s / 1_000_000.0)
+                    }),
+                    &verify,
+                ],
+            ));
+        }
+        if self.passes.is_empty() {
+            lines.push(format!("  {}", tr("report.none")));
+        }
+        lines.push(String::new());
+        lines.push(trf(
+            "report.result",
+            &[&tr(match self.status.as_str() {
+                "ok" => "report.status_ok",
+                "verify_failed" => "report.status_verify_failed",
+                "interrupted" => "report.status_interrupted",
+                _ => "report.status_error",
+            })],
+        ));
+        if let Some(msg) = &self.message {
+            lines.push(format!("  {msg}"));
+        }
+        lines.push(trf("report.started", &[&format_utc(self.started)]));
+        lines.push(trf("report.finished", &[&format_utc(self.finished)]));
+        lines.push(String::new());
+        match public_key {
+            Some(key) => lines.push(trf("report.signature", &[&to_hex(key)])),
+            None => lines.push(trf("report.signature_none", &[&tr("report.none")])),
+        }
+        let mut text: String = lines.join("\n");
+        text.push('\n');
+        text
+    }
+}
+
+/// Добавить подпись последним полем документа из `Report::to_json`.
+pub fn sign_document(doc: &str, key: &SigningKey) -> String {
+    let signature: [u8; SIGNATURE_LEN] = key.sign(doc.as_bytes());
+    let body: &str = doc.strip_suffix("\n}\n").unwrap_or(doc);
+    format!(
+        "{body}{SIGNATURE_FIELD}{{\"algorithm\": \"ed25519\", \"public_key\": \"{}\", \"value\": \"{}\"}}\n}}\n",
+        to_hex(&key.public_key()),
+        to_hex(&signature)
+    )
+}
+
+/// Почему отчёт не прошёл проверку.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReportError {
+    Unsigned,
+    Malformed(String),
+    BadSignature,
+    /// Подпись верна, но ключ не тот, что ожидался (`--pub-key`).
+    WrongKey([u8; KEY_LEN]),
+}
+
+impl std::fmt::Display for ReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportError::Unsigned => f.write_str(tr("report.unsigned")),
+            ReportError::Malformed(msg) => f.write_str(&trf("report.malformed", &[msg])),
+            ReportError::BadSignature => f.write_str(tr("report.bad_signature")),
+            ReportError::WrongKey(key) => f.write_str(&trf("report.wrong_key", &[&to_hex(key)])),
+        }
+    }
+}
+
+/// Проверить подпись документа; `Ok` — открытый ключ, которым он подписан.
+/// Подписаны байты файла без поля `signature`, поэтому переформатирование
+/// JSON подпись ломает. Поле `signature` должно быть последним: всё, что
+/// дописано после него, не подписано, и такой документ отвергается.
+pub fn verify_document(
+    text: &str,
+    expected: Option<&[u8; KEY_LEN]>,
+) -> Result<[u8; KEY_LEN], ReportError> {
+    let Some(at) = text.rfind(SIGNATURE_FIELD) else {
+        return Err(ReportError::Unsigned);
+    };
+    let message: String = format!("{}\n}}\n", &text[..at]);
+    let sig_line: &str = text[at + SIGNATURE_FIELD.len()..]
+        .strip_suffix("\n}\n")
+        .filter(|line| line.starts_with('{') && line.ends_with('}') && !line.contains('\n'))
+        .ok_or_else(|| ReportError::Malformed(tr("report.after_signature").to_string()))?;
+    let value_of = |key: &str| -> Result<String, ReportError> {
+        let marker: String = format!("\"{key}\": \"");
+        let start: usize = sig_line
+            .find(&marker)
+            .ok_or_else(|| ReportError::Malformed(trf("report.no_field", &[&key])))?
+            + marker.len();
+        let len: usize = sig_line[start..]
+            .find('"')
+            .ok_or_else(|| ReportError::Malformed(trf("report.unclosed_field", &[&key])))?;
+        Ok(sig_line[start..start + len].to_string())
+    };
+    if value_of("algorithm")? != "ed25519" {
+        return Err(ReportError::Malformed(
+            tr("report.ed25519_only").to_string(),
+        ));
+    }
+    let public: [u8; KEY_LEN] = from_hex(&value_of("public_key")?)
+        .and_then(|v| v.try_into().ok())
+        .ok_or_else(|| ReportError::Malformed(tr("report.bad_public_key").to_string()))?;
+    let signature: [u8; SIGNATURE_LEN] = from_hex(&value_of("value")?)
+        .and_then(|v| v.try_into().ok())
+        .ok_or_else(|| ReportError::Malformed(tr("report.bad_signature_value").to_string()))?;
+    if !ed25519::verify(&public, message.as_bytes(), &signature) {
+        return Err(ReportError::BadSignature);
+    }
+    match expected {
+        Some(key) if *key != public => Err(ReportError::WrongKey(public)),
+        _ => Ok(public),
+    }
+}
+
+/// Закрытый ключ из файла: 64 hex-символа сида или PEM PKCS#8
+/// (`openssl genpkey -algorithm ed25519 -out key.pem`).
+pub fn load_signing_key(path: &Path) -> Result<SigningKey, String> {
+    let text: String = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let seed: Vec<u8> = match pem_body(&text, "PRIVATE KEY") {
+        Some(der) => der
+            .strip_prefix(&PKCS8_PREFIX[..])
+            .filter(|seed| seed.len() == KEY_LEN)
+            .ok_or(tr("report.not_pkcs8"))?
+            .to_vec(),
+        None => from_hex(text.trim()).ok_or(tr("report.expected_private"))?,
+    };
+    let seed: [u8; KEY_LEN] = seed.try_into().map_err(|_| tr("report.key_len"))?;
+    Ok(SigningKey::from_seed(&seed))
+}
+
+/// Открытый ключ из файла: 64 hex-символа или PEM PUBLIC KEY.
+pub fn load_public_key(path: &Path) -> Result<[u8; KEY_LEN], String> {
+    let text: String = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let key: Vec<u8> = match pem_body(&text, "PUBLIC KEY") {
+        Some(der) => der
+            .strip_prefix(&SPKI_PREFIX[..])
+            .ok_or(tr("report.not_spki"))?
+            .to_vec(),
+        None => from_hex(text.trim()).ok_or(tr("report.expected_public"))?,
+    };
+    key.try_into().map_err(|_| tr("report.key_len").to_string())
+}
+
+/// Содержимое PEM-блока `label`, декодированное из base64.
+fn pem_body(text: &str, label: &str) -> Option<Vec<u8>> {
+    let begin: String = format!("-----BEGIN {label}-----");
+    let end: String = format!("-----END {label}-----");
+    let start: usize = text.find(&begin)? + begin.len();
+    let stop: usize = start + text[start..].find(&end)?;
+    base64_decode(&text[start..stop])
+}
+
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    for c in s.bytes().filter(|c| !c.is_ascii_whitespace() && *c != b'=') {
+        let v: u32 = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        } as u32;
+        acc = (acc << 6) | v;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Время UTC в RFC 3339: `2025-01-31T12:00:00Z`.
+pub fn format_utc(t: SystemTime) -> String {
+    let secs: u64 = t
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days: i64 = (secs / 86_400) as i64;
+    let rem: u64 = secs % 86_400;
+    // Дни от эпохи → гражданская дата (алгоритм Говарда Хиннанта).
+    let z: i64 = days + 719_468;
+    let era: i64 = z.div_euclid(146_097);
+    let doe: i64 = z - era * 146_097;
+    let yoe: i64 = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: i64 = (5 * doy + 2) / 153;
+    let day: i64 = doy - (153 * mp + 2) / 5 + 1;
+    let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year: i64 = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Имя хоста из `gethostname`.
+pub fn hostname() -> Option<String> {
+    let mut buf = [0u8; 256];
+    // SAFETY: буфер наш, длина передаётся вместе с ним.
+    if unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) } != 0 {
+        return None;
+    }
+    let len: usize = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    let name: String = String::from_utf8_lossy(&buf[..len]).into_owned();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Оператор по умолчанию: кто запустил sudo, иначе текущий пользователь.
+pub fn default_operator() -> Option<String> {
+    ["SUDO_USER", "USER", "LOGNAME"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+}
+
+/// Файл отчёта для устройства: при нескольких устройствах к имени
+/// добавляется имя устройства (`out.json` → `out-sdb.json`).
+pub fn report_path(base: &Path, device_path: &str, many: bool) -> PathBuf {
+    if !many {
+        return base.to_path_buf();
+    }
+    let device: &str = Path::new(device_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(device_path);
+    let stem: &str = base
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("report");
+    let name: String = match base.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{stem}-{device}.{ext}"),
+        None => format!("{stem}-{device}"),
+    };
+    base.with_file_name(name)
+}
+
+/// Текстовый вид рядом с JSON: `out.json` → `out.txt`.
+pub fn text_path(json: &Path) -> PathBuf {
+    let text: PathBuf = json.with_extension("txt");
+    if text == json {
+        let mut name = json.as_os_str().to_os_string();
+        name.push(".txt");
+        PathBuf::from(name)
+    } else {
+        text
+    }
+}
+
+/// Записать JSON (с подписью, если есть ключ) и текстовый вид.
+/// Возвращает путь текстового файла.
+pub fn write(report: &Report, path: &Path, key: Option<&SigningKey>) -> std::io::Result<PathBuf> {
+    let json: String = report.to_json();
+    let (json, public): (String, Option<[u8; KEY_LEN]>) = match key {
+        Some(key) => (sign_document(&json, key), Some(key.public_key())),
+        None => (json, None),
+    };
+    fs::write(path, json)?;
+    let text: PathBuf = text_path(path);
+    fs::write(&text, report.to_text(public.as_ref()))?;
+    Ok(text)
+}
@@ -0,0 +1,163 @@
+use std::fs::File;
+use std::io::{self, Read};
+
+/// Заполнить буфер криптографически стойкими случайными байтами из `/dev/urandom`.
+pub fn fill_secure_random(buf: &mut [u8]) -> io::Result<()> {
+    let mut urnd: File = std::fs::File::open("/dev/urandom")?;
+    let mut filled: usize = 0;
+    while filled < buf.len() {
+        let n: usize = urnd.read(&mut buf[filled..])?;
+        if n == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "urandom EOF"));
+        }
+        filled += n;
+    }
+    Ok(())
+}
+
+/// 256-битный сид генератора. Из мастер-сида детерминированно выводятся сиды
+/// проходов, поэтому содержимое любого случайного прохода можно воспроизвести.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Seed([u8; 32]);
+
+impl Seed {
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Новый сид из системного ГСЧ.
+    pub fn from_os_rng() -> io::Result<Self> {
+        let mut bytes = [0u8; 32];
+        fill_secure_random(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// Разобрать hex-строку длиной 1..=64 символа; короткие значения дополняются
+    /// нулями слева (`--seed 2a` == `--seed 00…002a`).
+    pub fn from_hex(s: &str) -> Option<Self> {
+        let s: &str = s.strip_prefix("0x").unwrap_or(s);
+        if s.is_empty() || s.len() > 64 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let padded: String = format!("{s:0>64}");
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&padded[i * 2..i * 2 + 2], 16).ok()?;
+        }
+        Some(Self(bytes))
+    }
+
+    pub fn to_hex(self) -> String {
+        self.0.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    /// Сид прохода `pass_index`: первые 32 байта потока ChaCha20(мастер, nonce = номер).
+    pub fn derive_pass(&self, pass_index: usize) -> Seed {
+        let mut kdf = ChaCha20::new(&self.0, &(pass_index as u64).to_le_bytes());
+        let mut bytes = [0u8; 32];
+        kdf.fill(&mut bytes);
+        Seed(bytes)
+    }
+
+    /// Поток случайных данных, однозначно определяемый сидом.
+    pub fn stream(&self) -> ChaCha20 {
+        ChaCha20::new(&self.0, &[0u8; 8])
+    }
+}
+
+/// Поток ChaCha20 (вариант Бернштейна: 64-битный счётчик блоков + 64-битный nonce).
+///
+/// Ключ задаётся один раз на проход, дальше поток просто продолжается, поэтому
+/// каждый записанный на устройство блок уникален. 64-битный счётчик позволяет
+/// покрыть любое устройство без повтора (32-битный из RFC 8439 кончается на 256 GiB).
+pub struct ChaCha20 {
+    state: [u32; 16],
+    block: [u8; 64],
+    /// Сколько байт текущего блока уже выдано (64 — блок исчерпан).
+    used: usize,
+}
+
+impl ChaCha20 {
+    pub fn new(key: &[u8; 32], nonce: &[u8; 8]) -> Self {
+        let mut state = [0u32; 16];
+        state[0] = 0x6170_7865;
+        state[1] = 0x3320_646e;
+        state[2] = 0x7962_2d32;
+        state[3] = 0x6b20_6574;
+        for (i, word) in key.chunks_exact(4).enumerate() {
+            state[4 + i] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        state[14] = u32::from_le_bytes([nonce[0], nonce[1], nonce[2], nonce[3]]);
+        state[15] = u32::from_le_bytes([nonce[4], nonce[5], nonce[6], nonce[7]]);
+        Self {
+            state,
+            block: [0u8; 64],
+            used: 64,
+        }
+    }
+
+    /// Установить номер следующего генерируемого 64-байтного блока.
+    pub fn set_block_counter(&mut self, counter: u64) {
+        self.state[12] = counter as u32;
+        self.state[13] = (counter >> 32) as u32;
+        self.used = 64;
+    }
+
+    /// Перейти к байту `offset` потока (от начала, счётчик блоков с нуля).
+    pub fn seek(&mut self, offset: u64) {
+        self.set_block_counter(offset / 64);
+        let rem: usize = (offset % 64) as usize;
+        if rem > 0 {
+            self.refill_block();
+            self.used = rem;
+        }
+    }
+
+    /// Заполнить буфер следующими байтами потока.
+    pub fn fill(&mut self, buf: &mut [u8]) {
+        let mut pos: usize = 0;
+        while pos < buf.len() {
+            if self.used == 64 {
+                self.refill_block();
+            }
+            let n: usize = (64 - self.used).min(buf.len() - pos);
+            buf[pos..pos + n].copy_from_slice(&self.block[self.used..self.used + n]);
+            self.used += n;
+            pos += n;
+        }
+    }
+
+    fn refill_block(&mut self) {
+        let mut x: [u32; 16] = self.state;
+        for _ in 0..10 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (i, word) in x.iter().enumerate() {
+            let v: u32 = word.wrapping_add(self.state[i]);
+            self.block[i * 4..i * 4 + 4].copy_from_slice(&v.to_le_bytes());
+        }
+        let counter: u64 = ((self.state[13] as u64) << 32 | self.state[12] as u64).wrapping_add(1);
+        self.state[12] = counter as u32;
+        self.state[13] = (counter >> 32) as u32;
+        self.used = 0;
+    }
+}
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
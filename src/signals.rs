@@ -0,0 +1,337 @@
+//! Сигналы во время стирания: остановка по SIGINT/SIGTERM на границе чанка,
+//! состояние по SIGUSR1 (как у `dd`), пауза по SIGTSTP/SIGCONT или по файлу
+//! `--pause-file`.
+//!
+//! Запросы остановки, паузы и состояния хранит `Signals` — свой у каждого
+//! стирания. Потоки записи читают его между чанками (`Control::between_chunks`).
+//! Обработчики сигналов ставит только CLI (`install`): они пересылают сигналы
+//! в его `Signals`. Библиотека сигналы процесса не трогает, а
+//! `WipeJob::cancel` останавливает через тот же `Signals` — без сигнала.
+
+use crate::dev::safe_sync;
+use crate::i18n::{tr, trf};
+use crate::wipe::ProgressTracker;
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// Куда обработчики пересылают сигналы; задаётся один раз в `install`.
+static FORWARD: OnceLock<Arc<Signals>> = OnceLock::new();
+
+/// Как часто проверять `--pause-file` во время записи и на паузе.
+const PAUSE_FILE_POLL: Duration = Duration::from_secs(1);
+/// Как часто поток, ждущий конца паузы, проверяет флаги.
+const PAUSE_WAIT: Duration = Duration::from_millis(100);
+
+/// Запросы к одному стиранию (или к группе устройств, стираемых вместе):
+/// остановка, пауза, вывод состояния. Всё на атомиках — методы можно звать
+/// из обработчика сигнала.
+#[derive(Debug, Default)]
+pub struct Signals {
+    /// Номер сигнала остановки; 0 — остановку сигналом не просили.
+    stop: AtomicI32,
+    /// Отмена через API (`WipeJob::cancel`).
+    cancelled: AtomicBool,
+    /// Запрошен вывод состояния (SIGUSR1).
+    status: AtomicBool,
+    /// Сколько раз просили паузу и сколько из них снято: пауза нужна,
+    /// пока первый счётчик впереди.
+    pauses: AtomicUsize,
+    resumes: AtomicUsize,
+    /// Пришёл SIGTSTP: один поток записи должен остановить процесс
+    /// (SIGSTOP), остальные ждут продолжения.
+    stop_process: AtomicBool,
+}
+
+impl Signals {
+    /// Остановиться по сигналу `sig`. `false`, если сигнал остановки уже
+    /// был — тогда остаётся первый.
+    pub fn request_stop(&self, sig: i32) -> bool {
+        self.stop
+            .compare_exchange(0, sig, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    /// Остановиться без сигнала.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Сигнал остановки, если он пришёл.
+    pub fn stop_signal(&self) -> Option<i32> {
+        match self.stop.load(Ordering::SeqCst) {
+            0 => None,
+            sig => Some(sig),
+        }
+    }
+
+    /// Пора ли остановиться: `Some(сигнал)` или `Some(None)` для отмены.
+    fn stop_cause(&self) -> Option<Option<i32>> {
+        if let Some(signal) = self.stop_signal() {
+            return Some(Some(signal));
+        }
+        self.cancelled.load(Ordering::SeqCst).then_some(None)
+    }
+
+    /// Попросить вывести состояние (SIGUSR1).
+    pub fn request_status(&self) {
+        self.status.store(true, Ordering::SeqCst);
+    }
+
+    /// Забрать запрос состояния: `true` один раз на каждый запрос.
+    pub fn take_status_request(&self) -> bool {
+        self.status.swap(false, Ordering::SeqCst)
+    }
+
+    /// Приостановить запись на границе чанка до `resume`.
+    pub fn pause(&self) {
+        self.pauses.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Снять все паузы, запрошенные до этого.
+    pub fn resume(&self) {
+        self.resumes
+            .store(self.pauses.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+
+    pub fn pause_requested(&self) -> bool {
+        self.pauses.load(Ordering::SeqCst) > self.resumes.load(Ordering::SeqCst)
+    }
+}
+
+extern "C" fn on_signal(sig: libc::c_int) {
+    let Some(signals) = FORWARD.get() else {
+        return;
+    };
+    match sig {
+        // Повторный сигнал — не ждать конца чанка.
+        libc::SIGINT | libc::SIGTERM if !signals.request_stop(sig) => {
+            // SAFETY: _exit допустим в обработчике сигнала.
+            unsafe { libc::_exit(128 + sig) };
+        }
+        libc::SIGUSR1 => signals.request_status(),
+        libc::SIGTSTP => {
+            signals.pause();
+            signals.stop_process.store(true, Ordering::SeqCst);
+        }
+        libc::SIGCONT => signals.resume(),
+        _ => {}
+    }
+}
+
+/// Поставить обработчики, пересылающие сигналы процесса в `signals`. Для
+/// CLI: вызывается после подтверждения, перед первой записью — до этого
+/// Ctrl+C и Ctrl+Z работают как обычно. Получатель задаётся один раз на процесс.
+pub fn install(signals: &Arc<Signals>) -> io::Result<()> {
+    if !Arc::ptr_eq(FORWARD.get_or_init(|| Arc::clone(signals)), signals) {
+        return Err(io::Error::from(io::ErrorKind::AlreadyExists));
+    }
+    for sig in [
+        libc::SIGINT,
+        libc::SIGTERM,
+        libc::SIGUSR1,
+        libc::SIGTSTP,
+        libc::SIGCONT,
+    ] {
+        // SAFETY: структура обнулена и заполнена полностью; обработчик
+        // трогает только атомики и _exit.
+        let rc: libc::c_int = unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = on_signal as extern "C" fn(libc::c_int) as libc::sighandler_t;
+            action.sa_flags = libc::SA_RESTART;
+            libc::sigemptyset(&mut action.sa_mask);
+            libc::sigaction(sig, &action, std::ptr::null_mut())
+        };
+        if rc != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Что остановило запись, для сообщений: имя сигнала или отмена через API.
+pub fn signal_name(signal: Option<i32>) -> &'static str {
+    match signal {
+        Some(libc::SIGINT) => "SIGINT",
+        Some(libc::SIGTERM) => "SIGTERM",
+        Some(_) => tr("signals.signal"),
+        None => tr("signals.cancel"),
+    }
+}
+
+/// Код выхода после остановки: 128 + номер сигнала, как у оболочки;
+/// отмена через API считается как SIGINT.
+pub fn exit_code(signal: Option<i32>) -> i32 {
+    128 + signal.unwrap_or(libc::SIGINT)
+}
+
+/// Проход остановлен: всё до `offset` записано и сброшено на носитель.
+/// `signal` — `None`, если остановил `WipeJob::cancel`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stopped {
+    pub signal: Option<i32>,
+    pub offset: u64,
+}
+
+impl std::fmt::Display for Stopped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&trf(
+            "signals.stopped",
+            &[&signal_name(self.signal), &self.offset],
+        ))
+    }
+}
+
+impl std::error::Error for Stopped {}
+
+/// Остановка, если ошибка прохода — это `Stopped`.
+pub fn stopped(e: &io::Error) -> Option<Stopped> {
+    e.get_ref()?.downcast_ref::<Stopped>().copied()
+}
+
+/// Отчего запись встала на паузу и как её продолжить.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PauseCause {
+    /// SIGTSTP или `Signals::pause`; продолжает SIGCONT или `Signals::resume`.
+    Signal,
+    /// Появился файл `--pause-file`; продолжит его удаление.
+    File(PathBuf),
+}
+
+impl PauseCause {
+    /// Поле `cause` в JSON.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PauseCause::Signal => "signal",
+            PauseCause::File(_) => "pause_file",
+        }
+    }
+
+    /// Как продолжить, для человека.
+    pub fn resume_hint(&self) -> String {
+        match self {
+            PauseCause::Signal => trf("signals.resume_cont", &[&std::process::id()]),
+            PauseCause::File(path) => format!("rm {}", path.display()),
+        }
+    }
+}
+
+/// Проверки между чанками одного устройства.
+pub struct Control {
+    signals: Arc<Signals>,
+    pause_file: Option<PathBuf>,
+    last_look: Option<Instant>,
+}
+
+impl Control {
+    pub fn new(signals: Arc<Signals>, pause_file: Option<&str>) -> Self {
+        Self {
+            signals,
+            pause_file: pause_file.map(PathBuf::from),
+            last_look: None,
+        }
+    }
+
+    /// Ошибка `Stopped`, если пора остановиться. Для проверки чтением:
+    /// там синхронизировать нечего.
+    pub fn check(&self, offset: u64) -> io::Result<()> {
+        match self.signals.stop_cause() {
+            Some(signal) => Err(io::Error::other(Stopped { signal, offset })),
+            None => Ok(()),
+        }
+    }
+
+    /// Вызывается после каждого чанка с основным дескриптором и смещением, до
+    /// которого дошёл проход. Остановка и пауза начинаются с sync, поэтому
+    /// всё до `done` уже на носителе. О паузе и продолжении узнают приёмники
+    /// `progress` (`ProgressEvent::Paused`, `ProgressEvent::Resumed`).
+    pub fn between_chunks(
+        &mut self,
+        file: &File,
+        done: u64,
+        progress: &mut ProgressTracker,
+    ) -> io::Result<()> {
+        self.stop_or_pause(Some(file), done, progress)
+    }
+
+    /// То же после каждого чанка проверки чтением: пауза и остановка такие же,
+    /// как при записи, только синхронизировать нечего.
+    pub fn between_reads(&mut self, done: u64, progress: &mut ProgressTracker) -> io::Result<()> {
+        self.stop_or_pause(None, done, progress)
+    }
+
+    fn stop_or_pause(
+        &mut self,
+        file: Option<&File>,
+        done: u64,
+        progress: &mut ProgressTracker,
+    ) -> io::Result<()> {
+        self.stop_if_requested(file, done)?;
+        if self.signals.pause_requested() {
+            sync_if_writing(file)?;
+            progress.paused(done, PauseCause::Signal);
+            self.wait_for_resume();
+            self.stop_if_requested(file, done)?;
+            progress.resumed();
+        }
+        if self.pause_file_present() {
+            sync_if_writing(file)?;
+            let path: PathBuf = self.pause_file.clone().expect("pause file");
+            progress.paused(done, PauseCause::File(path.clone()));
+            while path.exists() && self.signals.stop_cause().is_none() {
+                sleep(PAUSE_FILE_POLL);
+            }
+            self.stop_if_requested(file, done)?;
+            progress.resumed();
+        }
+        Ok(())
+    }
+
+    fn stop_if_requested(&self, file: Option<&File>, done: u64) -> io::Result<()> {
+        let Some(signal) = self.signals.stop_cause() else {
+            return Ok(());
+        };
+        sync_if_writing(file)?;
+        Err(io::Error::other(Stopped {
+            signal,
+            offset: done,
+        }))
+    }
+
+    /// Файл паузы проверяется не чаще `PAUSE_FILE_POLL`.
+    fn pause_file_present(&mut self) -> bool {
+        let Some(path) = &self.pause_file else {
+            return false;
+        };
+        if self
+            .last_look
+            .is_some_and(|t| t.elapsed() < PAUSE_FILE_POLL)
+        {
+            return false;
+        }
+        self.last_look = Some(Instant::now());
+        path.exists()
+    }
+
+    /// Ждать конца паузы или остановки. После SIGTSTP процесс сначала
+    /// останавливается: SIGSTOP посылает один поток, чтобы оболочка увидела
+    /// одну остановку (`fg` продолжает); остальные ждут флага.
+    fn wait_for_resume(&self) {
+        if self.signals.stop_process.swap(false, Ordering::SeqCst) {
+            // SAFETY: raise только посылает сигнал своему процессу.
+            unsafe { libc::raise(libc::SIGSTOP) };
+        }
+        while self.signals.pause_requested() && self.signals.stop_cause().is_none() {
+            sleep(PAUSE_WAIT);
+        }
+    }
+}
+
+/// Сбросить записанное на носитель; при проверке чтением дескриптора нет.
+fn sync_if_writing(file: Option<&File>) -> io::Result<()> {
+    file.map_or(Ok(()), safe_sync)
+}
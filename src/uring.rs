@@ -0,0 +1,343 @@
+//! Минимальная обёртка над io_uring (Linux) без внешних крейтов: кольца
+//! отправки и завершения через mmap, только операция записи.
+
+use std::io;
+use std::os::fd::RawFd;
+use std::ptr;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const IORING_OFF_SQ_RING: libc::off_t = 0;
+const IORING_OFF_CQ_RING: libc::off_t = 0x800_0000;
+const IORING_OFF_SQES: libc::off_t = 0x1000_0000;
+const IORING_ENTER_GETEVENTS: u32 = 1;
+const IORING_OP_WRITE: u8 = 23;
+
+#[repr(C)]
+#[derive(Default)]
+struct SqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    flags: u32,
+    dropped: u32,
+    array: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct CqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    overflow: u32,
+    cqes: u32,
+    flags: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct Params {
+    sq_entries: u32,
+    cq_entries: u32,
+    flags: u32,
+    sq_thread_cpu: u32,
+    sq_thread_idle: u32,
+    features: u32,
+    wq_fd: u32,
+    resv: [u32; 3],
+    sq_off: SqringOffsets,
+    cq_off: CqringOffsets,
+}
+
+/// Запись в очереди отправки (`struct io_uring_sqe`, 64 байта).
+#[repr(C)]
+struct Sqe {
+    opcode: u8,
+    flags: u8,
+    ioprio: u16,
+    fd: i32,
+    off: u64,
+    addr: u64,
+    len: u32,
+    rw_flags: u32,
+    user_data: u64,
+    buf_index: u16,
+    personality: u16,
+    splice_fd_in: i32,
+    addr3: u64,
+    pad2: u64,
+}
+
+/// Запись в очереди завершения (`struct io_uring_cqe`).
+#[repr(C)]
+struct Cqe {
+    user_data: u64,
+    res: i32,
+    flags: u32,
+}
+
+/// Отображённая в память область кольца.
+struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Mapping {
+    fn new(fd: RawFd, len: usize, offset: libc::off_t) -> io::Result<Self> {
+        // SAFETY: отображаем область, которую ядро выделило для кольца `fd`.
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED | libc::MAP_POPULATE,
+                fd,
+                offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            ptr: ptr as *mut u8,
+            len,
+        })
+    }
+
+    /// Указатель на поле кольца по смещению из `Params`.
+    fn at<T>(&self, offset: u32) -> *mut T {
+        // SAFETY: смещения берутся из ответа ядра и лежат внутри отображения.
+        unsafe { self.ptr.add(offset as usize) as *mut T }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY: ptr/len получены от успешного mmap.
+        unsafe {
+            libc::munmap(self.ptr as *mut libc::c_void, self.len);
+        }
+    }
+}
+
+/// Кольцо io_uring на `entries` одновременных операций.
+pub struct Ring {
+    fd: RawFd,
+    // Кольца держатся ради отображений; доступ к ним — через указатели ниже.
+    _sq: Mapping,
+    _cq: Mapping,
+    sqes: Mapping,
+    sq_tail: *const AtomicU32,
+    sq_mask: u32,
+    sq_array: *mut u32,
+    cq_head: *const AtomicU32,
+    cq_tail: *const AtomicU32,
+    cq_mask: u32,
+    cqes: *const Cqe,
+    entries: u32,
+    /// Поставлено в очередь, но ещё не отправлено в ядро.
+    unsubmitted: u32,
+}
+
+// Кольцо принадлежит одному потоку за раз; указатели — в его собственные mmap.
+unsafe impl Send for Ring {}
+
+impl Ring {
+    pub fn new(entries: u32) -> io::Result<Self> {
+        let mut params = Params::default();
+        // SAFETY: params — корректная структура io_uring_params.
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_io_uring_setup,
+                entries,
+                &mut params as *mut Params,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd: RawFd = fd as RawFd;
+        let close_on_err = |e: io::Error| {
+            // SAFETY: fd только что получен от io_uring_setup.
+            unsafe { libc::close(fd) };
+            e
+        };
+        let sq_len: usize =
+            params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+        let cq_len: usize =
+            params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<Cqe>();
+        let sq = Mapping::new(fd, sq_len, IORING_OFF_SQ_RING).map_err(close_on_err)?;
+        let cq = Mapping::new(fd, cq_len, IORING_OFF_CQ_RING).map_err(close_on_err)?;
+        let sqes = Mapping::new(
+            fd,
+            params.sq_entries as usize * size_of::<Sqe>(),
+            IORING_OFF_SQES,
+        )
+        .map_err(close_on_err)?;
+        // SAFETY: маски — обычные u32 внутри отображений.
+        let (sq_mask, cq_mask) = unsafe {
+            (
+                *sq.at::<u32>(params.sq_off.ring_mask),
+                *cq.at::<u32>(params.cq_off.ring_mask),
+            )
+        };
+        Ok(Self {
+            fd,
+            sq_tail: sq.at(params.sq_off.tail),
+            sq_mask,
+            sq_array: sq.at(params.sq_off.array),
+            cq_head: cq.at(params.cq_off.head),
+            cq_tail: cq.at(params.cq_off.tail),
+            cq_mask,
+            cqes: cq.at(params.cq_off.cqes),
+            entries: params.sq_entries,
+            unsubmitted: 0,
+            _sq: sq,
+            _cq: cq,
+            sqes,
+        })
+    }
+
+    /// Сколько операций помещается в очередь отправки.
+    pub fn entries(&self) -> u32 {
+        self.entries
+    }
+
+    /// Поставить запись `len` байт из `buf` по смещению `offset` в очередь.
+    /// Отправляется при следующем `submit_and_wait`.
+    ///
+    /// # Safety
+    /// Буфер должен жить и не меняться, пока не придёт завершение с `user_data`.
+    /// Одновременно в полёте — не больше `entries()` операций.
+    pub unsafe fn push_write(
+        &mut self,
+        fd: RawFd,
+        buf: *const u8,
+        len: u32,
+        offset: u64,
+        user_data: u64,
+    ) {
+        // SAFETY: хвост очереди пишем только мы; ядро читает его после Release.
+        unsafe {
+            let tail: u32 = (*self.sq_tail).load(Ordering::Relaxed);
+            let index: u32 = tail & self.sq_mask;
+            let sqe: *mut Sqe = (self.sqes.ptr as *mut Sqe).add(index as usize);
+            sqe.write(Sqe {
+                opcode: IORING_OP_WRITE,
+                flags: 0,
+                ioprio: 0,
+                fd,
+                off: offset,
+                addr: buf as u64,
+                len,
+                rw_flags: 0,
+                user_data,
+                buf_index: 0,
+                personality: 0,
+                splice_fd_in: 0,
+                addr3: 0,
+                pad2: 0,
+            });
+            *self.sq_array.add(index as usize) = index;
+            (*self.sq_tail).store(tail.wrapping_add(1), Ordering::Release);
+        }
+        self.unsubmitted += 1;
+    }
+
+    /// Отправить поставленное и дождаться хотя бы `min_complete` завершений.
+    /// EINTR повторяется.
+    pub fn submit_and_wait(&mut self, min_complete: u32) -> io::Result<()> {
+        loop {
+            let submitted: u32 = self.enter(self.unsubmitted, min_complete)?;
+            self.unsubmitted -= submitted.min(self.unsubmitted);
+            if self.unsubmitted == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Убрать из очереди отправки то, что ядро ещё не забрало; вернуть их число.
+    /// Без SQPOLL ядро читает очередь только внутри `io_uring_enter`, так что
+    /// между вызовами хвост можно откатить.
+    pub fn discard_unsubmitted(&mut self) -> usize {
+        let n: u32 = self.unsubmitted;
+        // SAFETY: хвост очереди пишем только мы.
+        unsafe {
+            let tail: u32 = (*self.sq_tail).load(Ordering::Relaxed);
+            (*self.sq_tail).store(tail.wrapping_sub(n), Ordering::Release);
+        }
+        self.unsubmitted = 0;
+        n as usize
+    }
+
+    /// Дождаться и выбросить `pending` завершений уже отправленных операций —
+    /// после этого ядро больше не трогает их буферы.
+    pub fn wait_all(&mut self, mut pending: usize) -> io::Result<()> {
+        while pending > 0 {
+            while pending > 0 && self.pop_completion().is_some() {
+                pending -= 1;
+            }
+            if pending > 0 {
+                self.enter(0, 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `io_uring_enter` с ожиданием завершений; EINTR повторяется.
+    fn enter(&self, to_submit: u32, min_complete: u32) -> io::Result<u32> {
+        loop {
+            // SAFETY: fd — наше кольцо, сигнальная маска не передаётся.
+            let rc = unsafe {
+                libc::syscall(
+                    libc::SYS_io_uring_enter,
+                    self.fd,
+                    to_submit,
+                    min_complete,
+                    IORING_ENTER_GETEVENTS,
+                    ptr::null::<libc::sigset_t>(),
+                    0usize,
+                )
+            };
+            if rc >= 0 {
+                return Ok(rc as u32);
+            }
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+
+    /// Забрать одно завершение: `(user_data, res)`, где `res` — записанные байты
+    /// или `-errno`.
+    pub fn pop_completion(&mut self) -> Option<(u64, i32)> {
+        // SAFETY: голову очереди завершений двигаем только мы, хвост — ядро.
+        unsafe {
+            let head: u32 = (*self.cq_head).load(Ordering::Relaxed);
+            let tail: u32 = (*self.cq_tail).load(Ordering::Acquire);
+            if head == tail {
+                return None;
+            }
+            let cqe: &Cqe = &*self.cqes.add((head & self.cq_mask) as usize);
+            let out = (cqe.user_data, cqe.res);
+            (*self.cq_head).store(head.wrapping_add(1), Ordering::Release);
+            Some(out)
+        }
+    }
+}
+
+impl Drop for Ring {
+    fn drop(&mut self) {
+        // SAFETY: fd открыт в `new`; отображения снимутся следом в Drop полей.
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
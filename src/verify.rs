@@ -0,0 +1,297 @@
+use crate::dev::{SyncMode, open_device_readable};
+use crate::extents::ExtentSet;
+use crate::i18n::trf;
+use crate::signals::Control;
+use crate::wipe::{Buffers, PassData, PassFiller, ProgressTracker};
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::time::{Duration, Instant};
+
+/// Сколько диапазонов расхождений храним в отчёте (остальные только считаются).
+pub const MAX_REPORTED_RANGES: usize = 1024;
+
+/// Диапазон байт, содержимое которого не совпало с ожидаемым.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+    pub offset: u64,
+    pub len: u64,
+}
+
+/// Итог проверки: сколько прочитано, сколько байт не совпало и где.
+#[derive(Clone, Debug, Default)]
+pub struct VerifyReport {
+    pub bytes_checked: u64,
+    pub mismatched_bytes: u64,
+    /// Диапазоны расхождений; соседние склеиваются. Не больше `MAX_REPORTED_RANGES`.
+    pub mismatches: Vec<Mismatch>,
+    /// true, если диапазонов было больше, чем поместилось в `mismatches`.
+    pub truncated: bool,
+}
+
+impl VerifyReport {
+    pub fn is_ok(&self) -> bool {
+        self.mismatched_bytes == 0
+    }
+
+    fn record(&mut self, offset: u64, len: u64) {
+        self.mismatched_bytes += len;
+        if let Some(last) = self.mismatches.last_mut()
+            && last.offset + last.len == offset
+        {
+            last.len += len;
+            return;
+        }
+        if self.mismatches.len() < MAX_REPORTED_RANGES {
+            self.mismatches.push(Mismatch { offset, len });
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    /// Сравнить прочитанный чанк с эталоном и занести расхождения.
+    fn compare(&mut self, base: u64, actual: &[u8], expected: &[u8]) {
+        self.bytes_checked += actual.len() as u64;
+        if actual == expected {
+            return;
+        }
+        let mut i: usize = 0;
+        while i < actual.len() {
+            if actual[i] == expected[i] {
+                i += 1;
+                continue;
+            }
+            let start: usize = i;
+            while i < actual.len() && actual[i] != expected[i] {
+                i += 1;
+            }
+            self.record(base + start as u64, (i - start) as u64);
+        }
+    }
+}
+
+/// Прочитать устройство целиком и сравнить с ожидаемым содержимым.
+/// `expected` заполняет эталонный чанк и вызывается последовательно от начала
+/// устройства — так же, как `refill` при записи прохода.
+/// В режиме direct читаем O_DIRECT-дескриптором выровненными блоками,
+/// «хвост» не кратный сектору — обычным дескриптором.
+pub fn verify_device<F>(
+    dev_path: &str,
+    device_size: u64,
+    mode: SyncMode,
+    sector: usize,
+    buffers: &mut Buffers,
+    progress: &mut ProgressTracker,
+    mut expected: F,
+) -> io::Result<VerifyReport>
+where
+    F: FnMut(&mut [u8]),
+{
+    let extents = ExtentSet::full(device_size);
+    verify_ranges(
+        dev_path,
+        &extents,
+        mode,
+        sector,
+        buffers,
+        progress,
+        None,
+        |_, chunk| expected(chunk),
+    )
+}
+
+/// Проверить только стираемые участки: эталон для каждого участка
+/// восстанавливается из `data` по его смещению на устройстве.
+/// С `control` проверка прерывается по сигналу или отмене (`Stopped`) и
+/// встаёт на паузу так же, как запись.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_extents(
+    dev_path: &str,
+    extents: &ExtentSet,
+    mode: SyncMode,
+    sector: usize,
+    buffers: &mut Buffers,
+    progress: &mut ProgressTracker,
+    data: &PassData,
+    control: Option<&mut Control>,
+) -> io::Result<VerifyReport> {
+    let mut filler: PassFiller = data.filler();
+    let mut next: u64 = 0;
+    verify_ranges(
+        dev_path,
+        extents,
+        mode,
+        sector,
+        buffers,
+        progress,
+        control,
+        |offset, chunk| {
+            if offset != next {
+                filler = data.filler_at(offset);
+            }
+            filler.fill(chunk);
+            next = offset + chunk.len() as u64;
+        },
+    )
+}
+
+/// Общий цикл проверки. `expected` получает смещение чанка на устройстве;
+/// чанки внутри участка идут подряд.
+#[allow(clippy::too_many_arguments)]
+fn verify_ranges<F>(
+    dev_path: &str,
+    extents: &ExtentSet,
+    mode: SyncMode,
+    sector: usize,
+    buffers: &mut Buffers,
+    progress: &mut ProgressTracker,
+    mut control: Option<&mut Control>,
+    mut expected: F,
+) -> io::Result<VerifyReport>
+where
+    F: FnMut(u64, &mut [u8]),
+{
+    let use_direct: bool = buffers.use_direct();
+    let sector: u64 = sector as u64;
+    let mut reader: File = open_device_readable(dev_path, mode)?;
+    let mut edge_reader: Option<File> = None;
+    let mut report = VerifyReport::default();
+    let mut want: Vec<u8> = vec![0u8; buffers.main_mut().len().max(2 * sector as usize)];
+
+    for extent in extents.extents() {
+        // Как и при записи: выровненная середина — O_DIRECT, края — обычным дескриптором.
+        let (mid_start, mid_end): (u64, u64) = if use_direct {
+            extent.aligned_middle(sector)
+        } else {
+            (extent.start, extent.end())
+        };
+        let segments: [(u64, u64, bool); 3] = [
+            (extent.start, mid_start, false),
+            (mid_start, mid_end, true),
+            (mid_end, extent.end(), false),
+        ];
+        for (from, to, aligned) in segments {
+            if to <= from {
+                continue;
+            }
+            if !aligned {
+                let len: usize = (to - from) as usize;
+                if edge_reader.is_none() {
+                    edge_reader = Some(open_device_readable(dev_path, SyncMode::Fast)?);
+                }
+                let edge: &mut File = edge_reader.as_mut().expect("edge reader");
+                edge.seek(SeekFrom::Start(from))?;
+                let tbuf = buffers.tail_buf(len);
+                edge.read_exact(tbuf).map_err(|e| read_failed(from, e))?;
+                expected(from, &mut want[..len]);
+                report.compare(from, tbuf, &want[..len]);
+                progress.record_chunk(len as u64);
+                continue;
+            }
+            reader.seek(SeekFrom::Start(from))?;
+            let buf: &mut [u8] = buffers.main_mut();
+            let mut offset: u64 = from;
+            while offset < to {
+                let to_read: usize = (to - offset).min(buf.len() as u64) as usize;
+                reader
+                    .read_exact(&mut buf[..to_read])
+                    .map_err(|e| read_failed(offset, e))?;
+                expected(offset, &mut want[..to_read]);
+                report.compare(offset, &buf[..to_read], &want[..to_read]);
+                offset += to_read as u64;
+                progress.record_chunk(to_read as u64);
+                if let Some(control) = control.as_deref_mut() {
+                    control.between_reads(offset, progress)?;
+                }
+            }
+        }
+    }
+
+    progress.finish_pass();
+    Ok(report)
+}
+
+/// Чтение при проверке не удалось на байте `offset`.
+#[derive(Debug)]
+pub struct ReadFailed {
+    pub offset: u64,
+    pub source: io::Error,
+}
+
+impl std::fmt::Display for ReadFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&trf("verify.read_failed", &[&self.offset, &self.source]))
+    }
+}
+
+impl std::error::Error for ReadFailed {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn read_failed(offset: u64, source: io::Error) -> io::Error {
+    io::Error::other(ReadFailed { offset, source })
+}
+
+/// Смещение, на котором не удалось чтение, если ошибка — `ReadFailed`.
+pub fn failed_read_offset(e: &io::Error) -> Option<u64> {
+    Some(e.get_ref()?.downcast_ref::<ReadFailed>()?.offset)
+}
+
+/// Итог пробного чтения для оценки времени `--dry-run`.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadProbe {
+    pub bytes: u64,
+    pub elapsed: Duration,
+}
+
+impl ReadProbe {
+    /// Байт в секунду; `None`, если прочитать ничего не удалось.
+    pub fn speed(&self) -> Option<f64> {
+        let secs: f64 = self.elapsed.as_secs_f64();
+        (self.bytes > 0 && secs > 0.0).then(|| self.bytes as f64 / secs)
+    }
+}
+
+/// Прочитать начало стираемых участков (не больше `max_bytes` и `max_time`)
+/// тем же способом, что и проверка, и замерить скорость. Ничего не пишет.
+pub fn probe_read_speed(
+    dev_path: &str,
+    extents: &ExtentSet,
+    mode: SyncMode,
+    sector: usize,
+    buffers: &mut Buffers,
+    max_bytes: u64,
+    max_time: Duration,
+) -> io::Result<ReadProbe> {
+    let use_direct: bool = buffers.use_direct();
+    let mut reader: File = open_device_readable(dev_path, mode)?;
+    let buf: &mut [u8] = buffers.main_mut();
+    let started: Instant = Instant::now();
+    let mut bytes: u64 = 0;
+    'extents: for extent in extents.extents() {
+        let (from, to): (u64, u64) = if use_direct {
+            extent.aligned_middle(sector as u64)
+        } else {
+            (extent.start, extent.end())
+        };
+        if to <= from {
+            continue;
+        }
+        reader.seek(SeekFrom::Start(from))?;
+        let mut offset: u64 = from;
+        while offset < to {
+            if bytes >= max_bytes || started.elapsed() >= max_time {
+                break 'extents;
+            }
+            let to_read: usize = (to - offset).min(buf.len() as u64) as usize;
+            reader.read_exact(&mut buf[..to_read])?;
+            offset += to_read as u64;
+            bytes += to_read as u64;
+        }
+    }
+    Ok(ReadProbe {
+        bytes,
+        elapsed: started.elapsed(),
+    })
+}
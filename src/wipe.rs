@@ -1,97 +1,289 @@
 use crate::dev::{SyncMode, alloc_aligned, full_sync, open_device_writable, safe_sync};
+use crate::extents::{Extent, ExtentSet};
+use crate::i18n::{Message, trf};
+use crate::offload::{OFFLOAD_CHUNK, Offload, is_unsupported, offload_range};
+use crate::progress::{
+    Phase, ProgressEvent, ProgressSink, ProgressUpdate, SilentSink, TerminalSink,
+};
+pub use crate::rng::fill_secure_random;
+use crate::rng::{ChaCha20, Seed};
+use crate::signals::PauseCause;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::uring::Ring;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use std::collections::VecDeque;
 use std::fs::File;
-use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::io::{self, Seek, SeekFrom, Write};
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use std::os::fd::AsRawFd;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel, sync_channel};
+use std::thread;
 use std::time::{Duration, Instant};
 
-/// Трекинг прогресса: хранит таймеры и подсчитывает ETA.
+/// Прогресс одного устройства при параллельном стирании: рабочий поток
+/// обновляет его через `ProgressTracker`, общая таблица читает.
+#[derive(Debug, Default)]
+pub struct SharedProgress {
+    pass: AtomicUsize,
+    verifying: AtomicBool,
+    paused: AtomicBool,
+    pass_done: AtomicU64,
+    /// Записано за все проходы (чтение при проверке не учитывается).
+    total_done: AtomicU64,
+}
+
+/// Снимок `SharedProgress` для отрисовки.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProgressSnapshot {
+    pub pass: usize,
+    pub verifying: bool,
+    /// Запись стоит на паузе (`ProgressEvent::Paused`).
+    pub paused: bool,
+    pub pass_done: u64,
+    pub total_done: u64,
+}
+
+impl SharedProgress {
+    pub fn snapshot(&self) -> ProgressSnapshot {
+        ProgressSnapshot {
+            pass: self.pass.load(Ordering::Relaxed),
+            verifying: self.verifying.load(Ordering::Relaxed),
+            paused: self.paused.load(Ordering::Relaxed),
+            pass_done: self.pass_done.load(Ordering::Relaxed),
+            total_done: self.total_done.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Трекинг прогресса: хранит таймеры, подсчитывает скорость и ETA и отдаёт
+/// события приёмникам `ProgressSink`.
 pub struct ProgressTracker {
     total_start: Instant,
     pass_start: Instant,
-    device_size: u64,
+    /// Байт за один проход: размер устройства или сумма стираемых участков.
+    pass_size: u64,
     total_passes: usize,
     total_target_bytes: u64,
     total_bytes_done: u64,
     pass_bytes_done: u64,
+    /// Байты, записанные до возобновления (не участвуют в расчёте скорости).
+    total_resumed: u64,
+    pass_resumed: u64,
     current_pass: usize,
-    quiet: bool,
-    line_buf: Vec<u8>,
+    verifying: bool,
+    sinks: Vec<Box<dyn ProgressSink>>,
+    shared: Option<Arc<SharedProgress>>,
 }
 
 impl ProgressTracker {
-    pub fn new(total_passes: usize, device_size: u64, quiet: bool) -> Self {
+    /// `pass_size` — сколько байт пишет один проход (`ExtentSet::total`).
+    /// Без `quiet` прогресс выводится строкой статуса (`TerminalSink`).
+    pub fn new(total_passes: usize, pass_size: u64, quiet: bool) -> Self {
+        let sink: Box<dyn ProgressSink> = if quiet {
+            Box::new(SilentSink)
+        } else {
+            Box::new(TerminalSink::new())
+        };
+        Self::with_sink(total_passes, pass_size, sink)
+    }
+
+    pub fn with_sink(total_passes: usize, pass_size: u64, sink: Box<dyn ProgressSink>) -> Self {
         Self {
             total_start: Instant::now(),
             pass_start: Instant::now(),
-            device_size,
+            pass_size,
             total_passes: total_passes.max(1),
-            total_target_bytes: device_size.saturating_mul(total_passes as u64),
+            total_target_bytes: pass_size.saturating_mul(total_passes as u64),
             total_bytes_done: 0,
             pass_bytes_done: 0,
+            total_resumed: 0,
+            pass_resumed: 0,
             current_pass: 0,
-            quiet,
-            line_buf: Vec::with_capacity(96),
+            verifying: false,
+            sinks: vec![sink],
+            shared: None,
         }
     }
 
+    /// Ещё один приёмник событий: поток `--progress=json`, канал библиотеки.
+    pub fn add_sink(&mut self, sink: Box<dyn ProgressSink>) {
+        self.sinks.push(sink);
+    }
+
+    /// Дублировать состояние в `shared` для общей таблицы прогресса.
+    pub fn share(&mut self, shared: Arc<SharedProgress>) {
+        self.shared = Some(shared);
+        self.publish();
+    }
+
     pub fn start_pass(&mut self, pass_index: usize) {
+        self.begin_pass(pass_index, 0);
+    }
+
+    /// Восстановить состояние после возобновления из журнала: в проходе `pass_index`
+    /// уже записано `pass_offset` байт (`ExtentSet::bytes_before`), предыдущие — целиком.
+    /// Уже записанное учитывается в процентах, но не в скорости, поэтому ETA не врёт.
+    pub fn resume(&mut self, pass_index: usize, pass_offset: u64) {
+        self.total_start = Instant::now();
+        self.total_bytes_done = self
+            .pass_size
+            .saturating_mul(pass_index.saturating_sub(1) as u64)
+            .saturating_add(pass_offset);
+        self.total_resumed = self.total_bytes_done;
+        self.begin_pass(pass_index, pass_offset);
+    }
+
+    /// Переключить трекер на проверку чтением после прохода `pass_index`.
+    pub fn start_verify(&mut self, pass_index: usize) {
+        self.verifying = true;
+        self.start_pass(pass_index);
+    }
+
+    fn begin_pass(&mut self, pass_index: usize, resumed: u64) {
         self.current_pass = pass_index;
         self.pass_start = Instant::now();
-        self.pass_bytes_done = 0;
+        self.pass_bytes_done = resumed;
+        self.pass_resumed = resumed;
+        self.publish();
+        self.emit(ProgressEvent::PassStart {
+            phase: self.phase(),
+            pass: pass_index,
+            total_passes: self.total_passes,
+            pass_bytes: self.pass_size,
+            resumed,
+        });
     }
 
     pub fn record_chunk(&mut self, chunk_bytes: u64) {
         self.pass_bytes_done = self.pass_bytes_done.saturating_add(chunk_bytes);
         self.total_bytes_done = self.total_bytes_done.saturating_add(chunk_bytes);
-        self.print_status();
+        self.publish();
+        let update: ProgressUpdate = self.update();
+        self.emit(ProgressEvent::Progress(update));
     }
 
-    pub fn finish_line(&mut self) {
-        if self.quiet {
-            return;
+    /// Запись встала на паузу: всё до `offset` сброшено на носитель.
+    pub fn paused(&mut self, offset: u64, cause: PauseCause) {
+        if let Some(shared) = &self.shared {
+            shared.paused.store(true, Ordering::Relaxed);
         }
-        let _ = io::stdout().write_all(b"\n");
+        self.emit(ProgressEvent::Paused {
+            pass: self.current_pass,
+            offset,
+            cause,
+        });
     }
 
-    fn print_status(&mut self) {
-        if self.quiet || self.device_size == 0 {
-            return;
+    /// Пауза снята, запись продолжается.
+    pub fn resumed(&mut self) {
+        if let Some(shared) = &self.shared {
+            shared.paused.store(false, Ordering::Relaxed);
         }
-        let percent: f64 =
-            (self.pass_bytes_done as f64 / self.device_size as f64 * 100.0).clamp(0.0, 100.0);
-        let pass_eta: Option<Duration> =
-            Self::eta(self.pass_bytes_done, self.device_size, self.pass_start);
-        let total_eta: Option<Duration> = Self::eta(
-            self.total_bytes_done,
-            self.total_target_bytes,
-            self.total_start,
+        self.emit(ProgressEvent::Resumed {
+            pass: self.current_pass,
+        });
+    }
+
+    /// Сообщение посреди прохода.
+    pub fn note(&mut self, msg: &str) {
+        self.emit(ProgressEvent::Note(msg.to_string()));
+    }
+
+    /// Проход дописан (или дочитан при проверке).
+    pub fn finish_pass(&mut self) {
+        self.emit(ProgressEvent::PassEnd {
+            phase: self.phase(),
+            pass: self.current_pass,
+            bytes: self.pass_bytes_done,
+            elapsed: self.pass_start.elapsed(),
+        });
+    }
+
+    fn phase(&self) -> Phase {
+        if self.verifying {
+            Phase::Verify
+        } else {
+            Phase::Write
+        }
+    }
+
+    fn emit(&mut self, event: ProgressEvent) {
+        for sink in &mut self.sinks {
+            sink.event(&event);
+        }
+    }
+
+    /// Байты, скорость прохода и оба ETA на текущий момент.
+    fn update(&self) -> ProgressUpdate {
+        let elapsed: f64 = self.pass_start.elapsed().as_secs_f64();
+        let fresh: u64 = self.pass_bytes_done.saturating_sub(self.pass_resumed);
+        let rate: f64 = if elapsed > 0.0 {
+            fresh as f64 / elapsed
+        } else {
+            0.0
+        };
+        let eta_pass: Option<Duration> = Self::eta(
+            self.pass_bytes_done,
+            self.pass_resumed,
+            self.pass_size,
+            self.pass_start,
         );
+        let (total_done, total_bytes, eta_total) = if self.verifying {
+            (self.pass_bytes_done, self.pass_size, None)
+        } else {
+            (
+                self.total_bytes_done,
+                self.total_target_bytes,
+                Self::eta(
+                    self.total_bytes_done,
+                    self.total_resumed,
+                    self.total_target_bytes,
+                    self.total_start,
+                ),
+            )
+        };
+        ProgressUpdate {
+            phase: self.phase(),
+            pass: self.current_pass,
+            total_passes: self.total_passes,
+            pass_done: self.pass_bytes_done,
+            pass_bytes: self.pass_size,
+            total_done,
+            total_bytes,
+            rate,
+            eta_pass,
+            eta_total,
+        }
+    }
 
-        self.line_buf.clear();
-        self.line_buf.extend_from_slice("\rПасс ".as_bytes());
-        push_num(&mut self.line_buf, self.current_pass as u64);
-        self.line_buf.push(b'/');
-        push_num(&mut self.line_buf, self.total_passes as u64);
-        self.line_buf.extend_from_slice(" | Прогресс: ".as_bytes());
-        push_percent(&mut self.line_buf, percent.round() as u64);
-        self.line_buf
-            .extend_from_slice("% | Осталось проход: ".as_bytes());
-        append_eta(&mut self.line_buf, pass_eta);
-        self.line_buf
-            .extend_from_slice(" | Осталось всего: ".as_bytes());
-        append_eta(&mut self.line_buf, total_eta);
-
-        let _ = io::stdout().write_all(&self.line_buf);
-        let _ = io::stdout().flush();
-    }
-
-    fn eta(done: u64, total: u64, start: Instant) -> Option<Duration> {
+    fn publish(&self) {
+        let Some(shared) = &self.shared else {
+            return;
+        };
+        shared.pass.store(self.current_pass, Ordering::Relaxed);
+        shared.verifying.store(self.verifying, Ordering::Relaxed);
+        shared
+            .pass_done
+            .store(self.pass_bytes_done, Ordering::Relaxed);
+        // У проверки свой трекер: её байты не двигают общий прогресс записи.
+        if !self.verifying {
+            shared
+                .total_done
+                .store(self.total_bytes_done, Ordering::Relaxed);
+        }
+    }
+
+    fn eta(done: u64, resumed: u64, total: u64, start: Instant) -> Option<Duration> {
         if total == 0 {
             return None;
         }
         if done >= total {
             return Some(Duration::from_secs(0));
         }
-        if done == 0 {
+        let fresh: u64 = done.saturating_sub(resumed);
+        if fresh == 0 {
             return None;
         }
         let elapsed = start.elapsed();
@@ -99,7 +291,7 @@ impl ProgressTracker {
         if elapsed_secs <= 0.0 {
             return None;
         }
-        let speed = done as f64 / elapsed_secs;
+        let speed = fresh as f64 / elapsed_secs;
         if speed <= 0.0 {
             return None;
         }
@@ -108,7 +300,7 @@ impl ProgressTracker {
     }
 }
 
-fn push_num(buf: &mut Vec<u8>, mut n: u64) {
+pub(crate) fn push_num(buf: &mut Vec<u8>, mut n: u64) {
     if n == 0 {
         buf.push(b'0');
         return;
@@ -123,7 +315,7 @@ fn push_num(buf: &mut Vec<u8>, mut n: u64) {
     buf.extend_from_slice(&tmp[idx..]);
 }
 
-fn push_percent(buf: &mut Vec<u8>, percent: u64) {
+pub(crate) fn push_percent(buf: &mut Vec<u8>, percent: u64) {
     let p = percent.min(100);
     if p < 100 {
         if p < 10 {
@@ -136,7 +328,7 @@ fn push_percent(buf: &mut Vec<u8>, percent: u64) {
     push_num(buf, p);
 }
 
-fn append_eta(buf: &mut Vec<u8>, eta: Option<Duration>) {
+pub(crate) fn append_eta(buf: &mut Vec<u8>, eta: Option<Duration>) {
     match eta {
         Some(dur) => {
             let mut secs: u64 = dur.as_secs();
@@ -171,25 +363,20 @@ fn push_two_digits(buf: &mut Vec<u8>, value: u8) {
     buf.push(b'0' + ones);
 }
 
-/// Заполнить буфер криптографически стойкими случайными байтами из `/dev/urandom`.
-pub fn fill_secure_random(buf: &mut [u8]) -> io::Result<()> {
-    let mut urnd: File = std::fs::File::open("/dev/urandom")?;
-    let mut filled: usize = 0;
-    while filled < buf.len() {
-        let n: usize = urnd.read(&mut buf[filled..])?;
-        if n == 0 {
-            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "urandom EOF"));
-        }
-        filled += n;
-    }
-    Ok(())
-}
-
 /// Набор буферов, переиспользуемых между проходами, включая хвост для O_DIRECT.
 pub struct Buffers {
     main: Box<[u8]>,
     tail: Vec<u8>,
     use_direct: bool,
+    sector: usize,
+    /// Потоков генерации для конвейера случайных проходов (0 — без конвейера).
+    generators: usize,
+    /// Буферы конвейера, возвращённые после прохода, — для следующих проходов.
+    spare: Vec<Box<[u8]>>,
+    /// Кольцо io_uring для записи в режиме direct (`--queue-depth`).
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    ring: Option<Ring>,
+    queue_depth: usize,
 }
 
 impl Buffers {
@@ -204,9 +391,47 @@ impl Buffers {
             main,
             tail: vec![0u8; tail_capacity],
             use_direct,
+            sector,
+            generators: 0,
+            spare: Vec::new(),
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            ring: None,
+            queue_depth: 0,
         })
     }
 
+    /// Писать выровненную середину через io_uring, держа `depth` буферов
+    /// в полёте. Только для O_DIRECT: с page cache очередь ничего не даёт.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    pub fn enable_uring(&mut self, depth: usize) -> io::Result<()> {
+        if !self.use_direct {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Message::new("wipe.uring_direct_only"),
+            ));
+        }
+        let ring: Ring = Ring::new(depth as u32)?;
+        self.queue_depth = depth.min(ring.entries() as usize);
+        self.ring = Some(ring);
+        Ok(())
+    }
+
+    /// Глубина очереди io_uring; 0 — запись блокирующими `write_all`.
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth
+    }
+
+    /// Генерировать случайные проходы в `n` отдельных потоках, пока текущий
+    /// поток пишет (0 — генерировать и писать по очереди в одном потоке).
+    pub fn with_generators(mut self, n: usize) -> Self {
+        self.generators = n;
+        self
+    }
+
+    pub fn generators(&self) -> usize {
+        self.generators
+    }
+
     pub fn main_mut(&mut self) -> &mut [u8] {
         &mut self.main
     }
@@ -221,11 +446,165 @@ impl Buffers {
     pub fn use_direct(&self) -> bool {
         self.use_direct
     }
+
+    /// Взять `n` буферов размера основного: сначала из запаса, недостающие выделить.
+    fn take_spare(&mut self, n: usize) -> io::Result<Vec<Box<[u8]>>> {
+        let mut out: Vec<Box<[u8]>> = Vec::with_capacity(n);
+        while out.len() < n {
+            let buf: Box<[u8]> = match self.spare.pop() {
+                Some(buf) => buf,
+                None if self.use_direct => alloc_aligned(self.main.len(), self.sector)?,
+                None => vec![0u8; self.main.len()].into_boxed_slice(),
+            };
+            out.push(buf);
+        }
+        Ok(out)
+    }
+
+    fn return_spare(&mut self, bufs: Vec<Box<[u8]>>) {
+        self.spare.extend(bufs);
+    }
 }
 
-/// Один проход перезаписи случайными данными (новая генерация буфера на каждый проход).
+/// Число потоков генерации по умолчанию: по ядрам, одно остаётся писателю,
+/// но не больше `MAX_DEFAULT_GENERATORS` — дальше упираемся в носитель.
+pub fn default_generators() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get().saturating_sub(1))
+        .unwrap_or(1)
+        .clamp(1, MAX_DEFAULT_GENERATORS)
+}
+
+const MAX_DEFAULT_GENERATORS: usize = 4;
+
+/// Глубина очереди io_uring по умолчанию (`--queue-depth`).
+pub const DEFAULT_QUEUE_DEPTH: usize = 32;
+
+/// Сколько буферов в обороте у каждого потока генерации: один пишется,
+/// один ждёт в очереди, один заполняется.
+const BUFFERS_PER_GENERATOR: usize = 3;
+
+/// Содержимое прохода для генерического писателя `pass_pattern`.
+#[derive(Clone)]
+pub enum PassData {
+    /// Повторяющийся шаблон (один байт или несколько), непрерывный по всему устройству.
+    Pattern(Vec<u8>),
+    /// Поток ChaCha20 из сида прохода; `invert` — побитовое дополнение потока.
+    Random { seed: Seed, invert: bool },
+    /// Проход делает ядро (ioctl/fallocate); ожидаемое содержимое — нули.
+    Offload(Offload),
+}
+
+impl PassData {
+    pub fn zeros() -> Self {
+        PassData::Pattern(vec![0])
+    }
+
+    /// Побитовое дополнение этого прохода.
+    pub fn complement(&self) -> Self {
+        match self {
+            PassData::Pattern(p) => PassData::Pattern(p.iter().map(|b| !b).collect()),
+            PassData::Random { seed, invert } => PassData::Random {
+                seed: *seed,
+                invert: !invert,
+            },
+            PassData::Offload(_) => PassData::Pattern(vec![0xFF]),
+        }
+    }
+
+    /// Генератор байтов прохода с начала устройства.
+    /// Один и тот же `PassData` всегда даёт одну и ту же последовательность,
+    /// поэтому им же пользуется проверка чтением.
+    pub fn filler(&self) -> PassFiller {
+        match self {
+            PassData::Pattern(p) => PassFiller::Pattern {
+                pattern: if p.is_empty() { vec![0] } else { p.clone() },
+                phase: 0,
+            },
+            PassData::Random { seed, invert } => PassFiller::Random {
+                stream: seed.stream(),
+                invert: *invert,
+            },
+            PassData::Offload(_) => PassFiller::Pattern {
+                pattern: vec![0],
+                phase: 0,
+            },
+        }
+    }
+
+    /// Генератор байтов прохода, начиная со смещения `offset` (для возобновления).
+    pub fn filler_at(&self, offset: u64) -> PassFiller {
+        let mut filler: PassFiller = self.filler();
+        match &mut filler {
+            PassFiller::Pattern { pattern, phase } => {
+                *phase = (offset % pattern.len() as u64) as usize;
+            }
+            PassFiller::Random { stream, .. } => stream.seek(offset),
+        }
+        filler
+    }
+
+    /// Короткое описание для вывода; в JSON — по-английски (`i18n::neutral`).
+    pub fn describe(&self) -> Message {
+        match self {
+            PassData::Pattern(p) if p.len() == 1 && p[0] == 0 => Message::new("wipe.zeros"),
+            PassData::Pattern(p) if p.len() == 1 => {
+                Message::new("wipe.byte").arg(format!("0x{:02X}", p[0]))
+            }
+            PassData::Pattern(p) => {
+                let hex: Vec<String> = p.iter().map(|b| format!("0x{b:02X}")).collect();
+                Message::new("wipe.pattern").arg(hex.join(":"))
+            }
+            PassData::Random { invert: false, .. } => Message::new("wipe.random"),
+            PassData::Random { invert: true, .. } => Message::new("wipe.complement"),
+            PassData::Offload(Offload::ZeroOut) => Message::new("offload.zeroout"),
+            PassData::Offload(kind) => Message::from(kind.title()),
+        }
+    }
+}
+
+/// Последовательный источник байтов прохода.
+pub enum PassFiller {
+    Pattern { pattern: Vec<u8>, phase: usize },
+    Random { stream: ChaCha20, invert: bool },
+}
+
+impl PassFiller {
+    /// Заполнить буфер следующими байтами прохода.
+    pub fn fill(&mut self, buf: &mut [u8]) {
+        match self {
+            PassFiller::Pattern { pattern, phase } => {
+                if pattern.len() == 1 {
+                    buf.fill(pattern[0]);
+                    return;
+                }
+                for b in buf.iter_mut() {
+                    *b = pattern[*phase];
+                    *phase += 1;
+                    if *phase == pattern.len() {
+                        *phase = 0;
+                    }
+                }
+            }
+            PassFiller::Random { stream, invert } => {
+                stream.fill(buf);
+                if *invert {
+                    for b in buf.iter_mut() {
+                        *b = !*b;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Один проход перезаписи случайными данными.
+/// Поток ChaCha20 получает новый ключ из системного ГСЧ на каждый проход и
+/// перезаполняет буфер перед каждой записью — одинаковых чанков на устройстве нет,
+/// дедупликация и сжатие контроллера ничего не «сэкономят».
 /// Если дескриптор открыт в режиме O_DIRECT (Linux), буфер должен быть выровнен,
 /// длина записи кратна `sector`, а смещение — кратно `sector`.
+#[allow(clippy::too_many_arguments)]
 pub fn pass_random(
     file: &mut File,
     device_size: u64,
@@ -236,66 +615,53 @@ pub fn pass_random(
     buffers: &mut Buffers,
     tail_handle: Option<&mut File>,
 ) -> io::Result<()> {
-    let use_direct = buffers.use_direct();
-    let buf: &mut [u8] = buffers.main_mut();
-    fill_secure_random(buf)?;
+    let seed: Seed = Seed::from_os_rng()?;
+    pass_random_seeded(
+        file,
+        device_size,
+        durable,
+        sector,
+        dev_path,
+        progress,
+        buffers,
+        tail_handle,
+        &seed,
+    )
+}
 
-    let mut written_total: u64 = 0;
-    let full_limit: u64 = if use_direct {
-        device_size - (device_size % sector as u64)
-    } else {
-        device_size
+/// Проход случайными данными из потока, заданного сидом прохода.
+/// Тот же сид даёт тот же поток — по нему проход можно проверить чтением.
+#[allow(clippy::too_many_arguments)]
+pub fn pass_random_seeded(
+    file: &mut File,
+    device_size: u64,
+    durable: bool,
+    sector: usize,
+    dev_path: &str,
+    progress: &mut ProgressTracker,
+    buffers: &mut Buffers,
+    tail_handle: Option<&mut File>,
+    seed: &Seed,
+) -> io::Result<()> {
+    let data = PassData::Random {
+        seed: *seed,
+        invert: false,
     };
-
-    write_full_pass(file, buf, &mut written_total, full_limit, progress)?;
-
-    // Если остался «хвост» не кратный сектору — допишем обычным дескриптором.
-    #[cfg(feature = "direct")]
-    if use_direct {
-        let tail: u64 = device_size.saturating_sub(written_total);
-        if tail > 0 {
-            // использовать невыравненный обычный буфер
-            let tbuf = buffers.tail_buf(tail as usize);
-            // для случайных данных — важно не повторять шаблон из aligned-буфера
-            fill_secure_random(tbuf)?;
-
-            if let Some(writer) = tail_handle {
-                writer.seek(SeekFrom::Start(written_total))?;
-                writer.write_all(tbuf)?;
-                progress.record_chunk(tail);
-                if durable {
-                    full_sync(writer)?;
-                } else {
-                    safe_sync(writer)?;
-                }
-            } else {
-                let mut writer = open_device_writable(dev_path, SyncMode::Fast)?;
-                writer.seek(SeekFrom::Start(written_total))?;
-                writer.write_all(tbuf)?;
-                progress.record_chunk(tail);
-                if durable {
-                    full_sync(&writer)?;
-                } else {
-                    safe_sync(&writer)?;
-                }
-            }
-        }
-    }
-    #[cfg(not(feature = "direct"))]
-    let _ = (dev_path, tail_handle);
-
-    progress.finish_line();
-
-    // В конце прохода синхронизируем согласно режиму.
-    if durable {
-        full_sync(file)?; // «жёсткий» flush: Linux fsync, macOS F_FULLFSYNC
-    } else {
-        safe_sync(file)?; // мягкий flush
-    }
-    Ok(())
+    pass_pattern(
+        file,
+        device_size,
+        durable,
+        sector,
+        dev_path,
+        progress,
+        buffers,
+        tail_handle,
+        &data,
+    )
 }
 
 /// Финальный проход нулями.
+#[allow(clippy::too_many_arguments)]
 pub fn pass_zeros(
     file: &mut File,
     device_size: u64,
@@ -306,76 +672,571 @@ pub fn pass_zeros(
     buffers: &mut Buffers,
     tail_handle: Option<&mut File>,
 ) -> io::Result<()> {
+    pass_pattern(
+        file,
+        device_size,
+        durable,
+        sector,
+        dev_path,
+        progress,
+        buffers,
+        tail_handle,
+        &PassData::zeros(),
+    )
+}
+
+/// Генерический проход: пишет содержимое `data` по всему устройству.
+/// Буфер перезаполняется перед каждой записью, «хвост» продолжает ту же
+/// последовательность — многобайтовые шаблоны и поток не рвутся на границах чанков.
+#[allow(clippy::too_many_arguments)]
+pub fn pass_pattern(
+    file: &mut File,
+    device_size: u64,
+    durable: bool,
+    sector: usize,
+    dev_path: &str,
+    progress: &mut ProgressTracker,
+    buffers: &mut Buffers,
+    tail_handle: Option<&mut File>,
+    data: &PassData,
+) -> io::Result<()> {
+    let extents = ExtentSet::full(device_size);
+    let mut target = PassTarget {
+        file,
+        tail_handle,
+        dev_path,
+        extents: &extents,
+        sector,
+        durable,
+    };
+    run_pass(&mut target, data, progress, buffers, 0, None)
+}
+
+/// Устройство, по которому идёт проход: основной дескриптор, дескриптор для
+/// невыровненных краёв (O_DIRECT), стираемые участки и параметры синхронизации.
+pub struct PassTarget<'a> {
+    pub file: &'a mut File,
+    pub tail_handle: Option<&'a mut File>,
+    pub dev_path: &'a str,
+    pub extents: &'a ExtentSet,
+    pub sector: usize,
+    pub durable: bool,
+}
+
+/// Точка сохранения прогресса. Вызывается после каждого чанка с основным
+/// дескриптором и смещением на устройстве, до которого проход дошёл; сама решает,
+/// пора ли делать sync и сохранять журнал. Трекер — для событий посреди
+/// прохода (пауза).
+pub type CheckpointHook<'a> = dyn FnMut(&File, u64, &mut ProgressTracker) -> io::Result<()> + 'a;
+
+/// Проход `data` по участкам `target.extents`, начиная с абсолютного смещения
+/// `start` (0 — с начала; при возобновлении — смещение из журнала).
+/// Содержимое байта зависит только от его смещения на устройстве, поэтому
+/// проверка и возобновление восстанавливают поток с любого места.
+pub fn run_pass(
+    target: &mut PassTarget<'_>,
+    data: &PassData,
+    progress: &mut ProgressTracker,
+    buffers: &mut Buffers,
+    start: u64,
+    mut checkpoint: Option<&mut CheckpointHook<'_>>,
+) -> io::Result<()> {
+    if let PassData::Offload(kind) = data {
+        return run_offload_pass(target, *kind, progress, buffers, start, checkpoint);
+    }
     let use_direct = buffers.use_direct();
-    let buf: &mut [u8] = buffers.main_mut();
-    buf.fill(0);
+    let sector: u64 = target.sector as u64;
+    let extents: Vec<Extent> = target.extents.remaining_from(start).collect();
+    // Шаблон заполняется быстрее, чем пишется, конвейер нужен только потоку ChaCha20.
+    let pipelined: bool = buffers.generators() > 0 && matches!(data, PassData::Random { .. });
+    // io_uring важнее конвейера: данные готовятся, пока ядро пишет предыдущие буферы.
+    let uring: bool = use_direct && buffers.queue_depth() > 0;
+
+    for extent in extents {
+        let mut filler: PassFiller = data.filler_at(extent.start);
+        // В O_DIRECT выровненную середину пишем основным дескриптором,
+        // невыровненные края — обычным.
+        let (mid_start, mid_end): (u64, u64) = if use_direct {
+            extent.aligned_middle(sector)
+        } else {
+            (extent.start, extent.end())
+        };
+
+        if mid_start > extent.start {
+            write_unaligned(
+                target,
+                buffers,
+                progress,
+                &mut filler,
+                extent.start,
+                mid_start - extent.start,
+            )?;
+        }
+        if mid_end > mid_start && uring {
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            write_uring(
+                target.file,
+                buffers,
+                &mut filler,
+                mid_start,
+                mid_end,
+                progress,
+                |file, done, progress| match checkpoint.as_mut() {
+                    Some(hook) => hook(file, done, progress),
+                    None => Ok(()),
+                },
+            )?;
+        } else if mid_end > mid_start && pipelined {
+            target.file.seek(SeekFrom::Start(mid_start))?;
+            write_pipelined(
+                target.file,
+                buffers,
+                data,
+                mid_start,
+                mid_end,
+                progress,
+                |file, done, progress| match checkpoint.as_mut() {
+                    Some(hook) => hook(file, done, progress),
+                    None => Ok(()),
+                },
+            )?;
+            // Генераторы писали со своих смещений; края продолжают поток отсюда.
+            filler = data.filler_at(mid_end);
+        } else if mid_end > mid_start {
+            let mut written_total: u64 = mid_start;
+            target.file.seek(SeekFrom::Start(mid_start))?;
+            write_full_pass(
+                target.file,
+                buffers.main_mut(),
+                &mut written_total,
+                mid_end,
+                progress,
+                |chunk| filler.fill(chunk),
+                |file, done, progress| match checkpoint.as_mut() {
+                    Some(hook) => hook(file, done, progress),
+                    None => Ok(()),
+                },
+            )?;
+        }
+        if extent.end() > mid_end {
+            write_unaligned(
+                target,
+                buffers,
+                progress,
+                &mut filler,
+                mid_end,
+                extent.end() - mid_end,
+            )?;
+        }
+    }
 
-    let mut written_total: u64 = 0;
-    let full_limit: u64 = if use_direct {
-        device_size - (device_size % sector as u64)
+    progress.finish_pass();
+
+    // В конце прохода синхронизируем согласно режиму.
+    if target.durable {
+        full_sync(target.file)?; // «жёсткий» flush: Linux fsync, macOS F_FULLFSYNC
     } else {
-        device_size
-    };
+        safe_sync(target.file)?; // мягкий flush
+    }
+    Ok(())
+}
 
-    write_full_pass(file, buf, &mut written_total, full_limit, progress)?;
-
-    #[cfg(feature = "direct")]
-    if use_direct {
-        let tail: u64 = device_size.saturating_sub(written_total);
-        if tail > 0 {
-            let tbuf = buffers.tail_buf(tail as usize);
-            tbuf.fill(0);
-
-            if let Some(writer) = tail_handle {
-                writer.seek(SeekFrom::Start(written_total))?;
-                writer.write_all(tbuf)?;
-                progress.record_chunk(tail);
-                if durable {
-                    full_sync(writer)?;
-                } else {
-                    safe_sync(writer)?;
-                }
-            } else {
-                let mut writer: File = open_device_writable(dev_path, SyncMode::Fast)?;
-                writer.seek(SeekFrom::Start(written_total))?;
-                writer.write_all(tbuf)?;
-                progress.record_chunk(tail);
-                if durable {
-                    full_sync(&writer)?;
-                } else {
-                    safe_sync(&writer)?;
+/// Проход силами ядра: выровненная по сектору середина каждого участка —
+/// ioctl/`fallocate` кусками по `OFFLOAD_CHUNK`, края — нулями через
+/// `write_unaligned`. Если устройство или ФС не умеют операцию (EOPNOTSUPP),
+/// остаток прохода с текущего смещения пишется нулями как обычный проход.
+fn run_offload_pass(
+    target: &mut PassTarget<'_>,
+    kind: Offload,
+    progress: &mut ProgressTracker,
+    buffers: &mut Buffers,
+    start: u64,
+    mut checkpoint: Option<&mut CheckpointHook<'_>>,
+) -> io::Result<()> {
+    let sector: u64 = target.sector as u64;
+    let zeros: PassData = PassData::zeros();
+    let extents: Vec<Extent> = target.extents.remaining_from(start).collect();
+
+    for extent in extents {
+        let mut filler: PassFiller = zeros.filler();
+        let (mid_start, mid_end): (u64, u64) = extent.aligned_middle(sector);
+        if mid_start > extent.start {
+            write_unaligned(
+                target,
+                buffers,
+                progress,
+                &mut filler,
+                extent.start,
+                mid_start - extent.start,
+            )?;
+        }
+        let mut offset: u64 = mid_start;
+        while offset < mid_end {
+            let len: u64 = (mid_end - offset).min(OFFLOAD_CHUNK);
+            if let Err(e) = offload_range(target.file, kind, offset, len) {
+                if !is_unsupported(&e) {
+                    return Err(e);
                 }
+                progress.note(&trf("wipe.offload_fallback", &[&kind.name(), &e]));
+                return run_pass(target, &zeros, progress, buffers, offset, checkpoint);
+            }
+            offset += len;
+            progress.record_chunk(len);
+            if let Some(hook) = checkpoint.as_mut() {
+                hook(target.file, offset, progress)?;
             }
         }
+        if extent.end() > mid_end {
+            write_unaligned(
+                target,
+                buffers,
+                progress,
+                &mut filler,
+                mid_end,
+                extent.end() - mid_end,
+            )?;
+        }
     }
-    #[cfg(not(feature = "direct"))]
-    let _ = (dev_path, tail_handle);
 
-    progress.finish_line();
+    progress.finish_pass();
+    if target.durable {
+        full_sync(target.file)
+    } else {
+        safe_sync(target.file)
+    }
+}
+
+/// Записать невыровненный край участка обычным (не O_DIRECT) дескриптором
+/// и сразу сбросить его на носитель — журнал не должен опережать данные.
+fn write_unaligned(
+    target: &mut PassTarget<'_>,
+    buffers: &mut Buffers,
+    progress: &mut ProgressTracker,
+    filler: &mut PassFiller,
+    offset: u64,
+    len: u64,
+) -> io::Result<()> {
+    // использовать невыравненный обычный буфер
+    let tbuf = buffers.tail_buf(len as usize);
+    // продолжаем ту же последовательность, шаблон не повторяется и не сбивается
+    filler.fill(tbuf);
 
-    if durable {
-        full_sync(file)?;
+    let mut opened: File;
+    let writer: &mut File = match target.tail_handle.as_deref_mut() {
+        Some(writer) => writer,
+        None => {
+            opened = open_device_writable(target.dev_path, SyncMode::Fast)?;
+            &mut opened
+        }
+    };
+    writer.seek(SeekFrom::Start(offset))?;
+    writer.write_all(tbuf)?;
+    progress.record_chunk(len);
+    if target.durable {
+        full_sync(writer)
     } else {
-        safe_sync(file)?;
+        safe_sync(writer)
     }
-    Ok(())
 }
 
-fn write_full_pass(
+/// Записать байты от текущей позиции `written_total` до `full_limit` чанками по размеру буфера.
+/// `refill` вызывается перед каждой записью и может обновить содержимое чанка,
+/// `after_chunk` — после неё (точка сохранения журнала).
+fn write_full_pass<F, C>(
     file: &mut File,
     buf: &mut [u8],
     written_total: &mut u64,
     full_limit: u64,
     progress: &mut ProgressTracker,
-) -> io::Result<()> {
+    mut refill: F,
+    mut after_chunk: C,
+) -> io::Result<()>
+where
+    F: FnMut(&mut [u8]),
+    C: FnMut(&File, u64, &mut ProgressTracker) -> io::Result<()>,
+{
     while *written_total < full_limit {
         let remaining: u64 = full_limit - *written_total;
         let to_write: usize = remaining.min(buf.len() as u64) as usize;
 
+        refill(&mut buf[..to_write]);
         file.write_all(&buf[..to_write])?;
         *written_total += to_write as u64;
 
         progress.record_chunk(to_write as u64);
+        after_chunk(file, *written_total, progress)?;
     }
     Ok(())
 }
+
+/// Конвейер для случайного прохода: `buffers.generators()` потоков заполняют
+/// буферы из потока прохода, текущий поток пишет их по порядку.
+/// Генератор `w` готовит чанки `w`, `w + N`, `w + 2N`, ... — каждый со своего
+/// смещения (`filler_at`), поэтому содержимое то же, что и без конвейера.
+/// Очереди ограничены `BUFFERS_PER_GENERATOR` буферами на генератор; после
+/// прохода буферы возвращаются в `buffers` и переиспользуются.
+fn write_pipelined<C>(
+    file: &mut File,
+    buffers: &mut Buffers,
+    data: &PassData,
+    from: u64,
+    to: u64,
+    progress: &mut ProgressTracker,
+    mut after_chunk: C,
+) -> io::Result<()>
+where
+    C: FnMut(&File, u64, &mut ProgressTracker) -> io::Result<()>,
+{
+    let workers: usize = buffers.generators().max(1);
+    let chunk: u64 = buffers.main_mut().len() as u64;
+    let mut pool: Vec<Box<[u8]>> = buffers.take_spare(workers * BUFFERS_PER_GENERATOR)?;
+
+    let (result, returned) = thread::scope(|scope| {
+        let mut filled: Vec<Receiver<Box<[u8]>>> = Vec::with_capacity(workers);
+        let mut free: Vec<Sender<Box<[u8]>>> = Vec::with_capacity(workers);
+        let mut handles = Vec::with_capacity(workers);
+        for w in 0..workers {
+            let (filled_tx, filled_rx) = sync_channel::<Box<[u8]>>(BUFFERS_PER_GENERATOR);
+            let (free_tx, free_rx) = channel::<Box<[u8]>>();
+            for buf in pool.drain(..BUFFERS_PER_GENERATOR) {
+                let _ = free_tx.send(buf);
+            }
+            filled.push(filled_rx);
+            free.push(free_tx);
+            handles.push(scope.spawn(move || {
+                let mut index: u64 = w as u64;
+                loop {
+                    let offset: u64 = from + index * chunk;
+                    if offset >= to {
+                        break;
+                    }
+                    let len: usize = (to - offset).min(chunk) as usize;
+                    // Писатель закрыл очередь — проход прерван ошибкой.
+                    let Ok(mut buf) = free_rx.recv() else {
+                        break;
+                    };
+                    data.filler_at(offset).fill(&mut buf[..len]);
+                    if filled_tx.send(buf).is_err() {
+                        break;
+                    }
+                    index += workers as u64;
+                }
+                // Дождаться своих буферов обратно, чтобы вернуть их в запас.
+                let mut own: Vec<Box<[u8]>> = Vec::with_capacity(BUFFERS_PER_GENERATOR);
+                while own.len() < BUFFERS_PER_GENERATOR {
+                    match free_rx.recv() {
+                        Ok(buf) => own.push(buf),
+                        Err(_) => break,
+                    }
+                }
+                own
+            }));
+        }
+
+        let mut result: io::Result<()> = Ok(());
+        let mut offset: u64 = from;
+        let mut index: usize = 0;
+        while offset < to {
+            let w: usize = index % workers;
+            let Ok(buf) = filled[w].recv() else {
+                result = Err(io::Error::other(Message::new("wipe.generator_died")));
+                break;
+            };
+            let len: usize = (to - offset).min(chunk) as usize;
+            let written: io::Result<()> = file.write_all(&buf[..len]);
+            let _ = free[w].send(buf);
+            if let Err(e) = written {
+                result = Err(e);
+                break;
+            }
+            offset += len as u64;
+            progress.record_chunk(len as u64);
+            if let Err(e) = after_chunk(file, offset, progress) {
+                result = Err(e);
+                break;
+            }
+            index += 1;
+        }
+        // Закрываем очереди: генераторы, ждущие буфер, завершатся. Заполненные,
+        // но не записанные после ошибки буферы забираем из `filled` до выхода
+        // генераторов — как `write_uring` дожидается записей в полёте.
+        drop(free);
+        let mut returned: Vec<Box<[u8]>> = filled.iter().flat_map(|rx| rx.iter()).collect();
+        returned.extend(handles.into_iter().filter_map(|h| h.join().ok()).flatten());
+        (result, returned)
+    });
+    buffers.return_spare(returned);
+    result
+}
+
+/// Буфер в полёте у io_uring.
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+struct UringSlot {
+    buf: Box<[u8]>,
+    offset: u64,
+    len: usize,
+    /// Сколько уже записано (короткое завершение дописывается повторно).
+    written: usize,
+    /// С какого места буфера отправлена текущая запись.
+    submitted: usize,
+    done: bool,
+}
+
+/// Запись `[from, to)` через io_uring: до `buffers.queue_depth()` выровненных
+/// буферов в полёте по возрастающим смещениям. Завершения двигают прогресс,
+/// короткие дописываются с начала оборванного сектора, EINTR/EAGAIN повторяются.
+/// Точка сохранения получает смещение, до которого записано всё без пропусков.
+/// При ошибке новые записи не ставятся, но все отправленные дожидаются —
+/// буферы нельзя освобождать, пока ядро их читает.
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+fn write_uring<C>(
+    file: &mut File,
+    buffers: &mut Buffers,
+    filler: &mut PassFiller,
+    from: u64,
+    to: u64,
+    progress: &mut ProgressTracker,
+    mut after_chunk: C,
+) -> io::Result<()>
+where
+    C: FnMut(&File, u64, &mut ProgressTracker) -> io::Result<()>,
+{
+    let mut ring: Ring = buffers
+        .ring
+        .take()
+        .ok_or_else(|| io::Error::other(Message::new("wipe.uring_missing")))?;
+    let depth: usize = buffers.queue_depth().max(1);
+    let sector: usize = buffers.sector.max(1);
+    let chunk: u64 = buffers.main_mut().len() as u64;
+    let fd = file.as_raw_fd();
+    let mut slots: Vec<UringSlot> = buffers
+        .take_spare(depth)?
+        .into_iter()
+        .map(|buf| UringSlot {
+            buf,
+            offset: 0,
+            len: 0,
+            written: 0,
+            submitted: 0,
+            done: true,
+        })
+        .collect();
+    let mut free: Vec<usize> = (0..depth).rev().collect();
+    // Слоты в порядке смещений: по ним считается непрерывно записанное.
+    let mut order: VecDeque<usize> = VecDeque::with_capacity(depth);
+    let mut in_flight: usize = 0;
+    let mut next: u64 = from;
+    let mut failure: Option<io::Error> = None;
+
+    loop {
+        while failure.is_none() && next < to {
+            let Some(i) = free.pop() else {
+                break;
+            };
+            let slot: &mut UringSlot = &mut slots[i];
+            slot.offset = next;
+            slot.len = (to - next).min(chunk) as usize;
+            slot.written = 0;
+            slot.submitted = 0;
+            slot.done = false;
+            filler.fill(&mut slot.buf[..slot.len]);
+            // SAFETY: буфер слота не трогается, пока не придёт его завершение,
+            // и в полёте не больше `depth` записей.
+            unsafe {
+                ring.push_write(
+                    fd,
+                    slot.buf.as_ptr(),
+                    slot.len as u32,
+                    slot.offset,
+                    i as u64,
+                );
+            }
+            order.push_back(i);
+            in_flight += 1;
+            next += slot.len as u64;
+        }
+        if in_flight == 0 {
+            break;
+        }
+        if let Err(e) = ring.submit_and_wait(1) {
+            // Неотправленное снимаем с очереди, отправленное дожидаемся: до тех пор
+            // ядро может читать буферы слотов.
+            in_flight -= ring.discard_unsubmitted();
+            if ring.wait_all(in_flight).is_err() {
+                // Дождаться не вышло — кольцо и буферы живут до конца процесса.
+                std::mem::forget(ring);
+                slots.into_iter().for_each(std::mem::forget);
+                return Err(e);
+            }
+            buffers.return_spare(slots.into_iter().map(|s| s.buf).collect());
+            buffers.ring = Some(ring);
+            return Err(e);
+        }
+        while let Some((user_data, res)) = ring.pop_completion() {
+            let i: usize = user_data as usize;
+            let slot: &mut UringSlot = &mut slots[i];
+            let retry: bool = if res < 0 {
+                let err = io::Error::from_raw_os_error(-res);
+                match err.kind() {
+                    io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock => true,
+                    _ => {
+                        failure.get_or_insert(err);
+                        false
+                    }
+                }
+            } else if res == 0 {
+                failure.get_or_insert(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    Message::new("wipe.write_zero").arg(slot.offset),
+                ));
+                false
+            } else {
+                let end: usize = slot.submitted + res as usize;
+                if end > slot.written {
+                    progress.record_chunk((end - slot.written) as u64);
+                    slot.written = end;
+                }
+                slot.written < slot.len
+            };
+            if retry && failure.is_none() {
+                // O_DIRECT принимает только выровненные смещение и длину: оборванную
+                // запись повторяем с начала сектора, на котором она остановилась.
+                slot.submitted = slot.written / sector * sector;
+                let rest: &[u8] = &slot.buf[slot.submitted..slot.len];
+                // SAFETY: как и выше — слот остаётся в полёте.
+                unsafe {
+                    ring.push_write(
+                        fd,
+                        rest.as_ptr(),
+                        rest.len() as u32,
+                        slot.offset + slot.submitted as u64,
+                        user_data,
+                    );
+                }
+            } else {
+                slot.done = true;
+                in_flight -= 1;
+            }
+        }
+        while let Some(&i) = order.front() {
+            if !slots[i].done {
+                break;
+            }
+            order.pop_front();
+            free.push(i);
+            if failure.is_none() {
+                let end: u64 = slots[i].offset + slots[i].len as u64;
+                if let Err(e) = after_chunk(file, end, progress) {
+                    failure = Some(e);
+                }
+            }
+        }
+    }
+
+    buffers.return_spare(slots.into_iter().map(|s| s.buf).collect());
+    buffers.ring = Some(ring);
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
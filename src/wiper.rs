@@ -0,0 +1,1261 @@
+//! Стирание как библиотека: `Wiper` собирает параметры, `prepare` проверяет
+//! устройство и строит план, `start` запускает запись в фоновом потоке и
+//! возвращает `WipeJob`. Журнал, отчёт и события `--progress=json` ведёт сам
+//! `Wiper`; ошибки возвращаются как `WipeError`, процесс не завершается и в
+//! терминал ничего не печатается — CLI (`app`) только выводит события и итог.
+
+use crate::dev::{
+    BlockSizes, SyncMode, choose_buffer_size, get_block_sizes, get_device_size_bytes,
+    open_device_writable,
+};
+use crate::ed25519::SigningKey;
+use crate::error::{EXIT_FAILURE, Interruption, Refusal, WipeError};
+use crate::estimate::{EstimateOptions, WipeEstimate};
+use crate::events::{DeviceEvents, Json, quote};
+use crate::extents::{ExtentSet, RangeSpec};
+use crate::i18n::{Message, neutral};
+use crate::identity::{DeviceIdentity, device_identity};
+use crate::inuse::{Usage, find_system_disk, find_usage};
+use crate::journal::{Checkpointer, Journal, device_fingerprint};
+use crate::partition::{Partition, PartitionSelector, PartitionTable, read_partition_table};
+use crate::plan::{PassPlan, Scheme};
+use crate::progress::{ChannelSink, JsonSink, ProgressEvent, SilentSink};
+use crate::report::{self, PassRecord, Report, VerifyOutcome, default_operator, hostname};
+use crate::rng::Seed;
+use crate::signals::{self, Control, Signals};
+use crate::verify::{
+    ReadProbe, VerifyReport, failed_read_offset, probe_read_speed, verify_extents,
+};
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::wipe::DEFAULT_QUEUE_DEPTH;
+use crate::wipe::{
+    Buffers, PassData, PassTarget, ProgressTracker, SharedProgress, default_generators, run_pass,
+};
+use std::cell::Cell;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Сколько читать для оценки скорости в пробном запуске.
+const DRY_RUN_PROBE_BYTES: u64 = 64 * 1024 * 1024;
+const DRY_RUN_PROBE_TIME: Duration = Duration::from_secs(2);
+
+/// Параметры стирания одного устройства. По умолчанию — 8 проходов
+/// (`PassPlan::standard`), режим fast, буфер по размеру блока, всё устройство.
+///
+/// ```no_run
+/// use destroyer::plan::PassPlan;
+/// use destroyer::wiper::Wiper;
+///
+/// let job = Wiper::new("/dev/sdX").plan(PassPlan::standard(1)).verify(true).start()?;
+/// while !job.is_finished() {
+///     let p = job.progress();
+///     println!("{}/{} байт", p.total_done, p.total_bytes);
+///     std::thread::sleep(std::time::Duration::from_secs(1));
+/// }
+/// let summary = job.wait()?;
+/// println!("стёрто {} проходов", summary.passes.len());
+/// # Ok::<(), destroyer::error::WipeError>(())
+/// ```
+#[derive(Clone)]
+pub struct Wiper {
+    device_path: String,
+    plan: PassPlan,
+    seed: Option<Seed>,
+    mode: SyncMode,
+    buf_size: Option<usize>,
+    gen_threads: Option<usize>,
+    queue_depth: Option<usize>,
+    verify: bool,
+    verify_each_pass: bool,
+    ranges: Vec<RangeSpec>,
+    excludes: Vec<RangeSpec>,
+    partition: Option<PartitionSelector>,
+    /// Только `--list-partitions`: таблица нужна, проверки использования — нет.
+    list_partitions: bool,
+    unallocated: bool,
+    force: bool,
+    allow_system_disk: bool,
+    dry_run: bool,
+    pause_file: Option<String>,
+    resume: Option<Journal>,
+    checkpoint: Option<PathBuf>,
+    cancel_journal: Option<PathBuf>,
+    report: Option<PathBuf>,
+    signer: Option<Arc<SigningKey>>,
+    operator: Option<String>,
+    scheme: Option<Scheme>,
+    events: Option<DeviceEvents>,
+    signals: Option<Arc<Signals>>,
+    progress: Option<Sender<ProgressEvent>>,
+}
+
+impl Wiper {
+    pub fn new(device_path: &str) -> Self {
+        Self {
+            device_path: device_path.to_string(),
+            plan: PassPlan::standard(8),
+            seed: None,
+            mode: SyncMode::Fast,
+            buf_size: None,
+            gen_threads: None,
+            queue_depth: None,
+            verify: false,
+            verify_each_pass: false,
+            ranges: Vec::new(),
+            excludes: Vec::new(),
+            partition: None,
+            list_partitions: false,
+            unallocated: false,
+            force: false,
+            allow_system_disk: false,
+            dry_run: false,
+            pause_file: None,
+            resume: None,
+            checkpoint: None,
+            cancel_journal: None,
+            report: None,
+            signer: None,
+            operator: None,
+            scheme: None,
+            events: None,
+            signals: None,
+            progress: None,
+        }
+    }
+
+    pub fn device_path(&self) -> &str {
+        &self.device_path
+    }
+
+    pub fn plan(mut self, plan: PassPlan) -> Self {
+        self.plan = plan;
+        self
+    }
+
+    pub fn mode(mut self, mode: SyncMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Размер буфера записи; без него выбирается по размеру блока.
+    pub fn buf_size(mut self, bytes: usize) -> Self {
+        self.buf_size = Some(bytes);
+        self
+    }
+
+    /// Стирать только этот участок (как `--range`, можно несколько раз).
+    pub fn range(mut self, range: RangeSpec) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    /// Не трогать этот участок (как `--exclude`).
+    pub fn exclude(mut self, range: RangeSpec) -> Self {
+        self.excludes.push(range);
+        self
+    }
+
+    /// Стирать один раздел; участки тогда отсчитываются от его начала.
+    pub fn partition(mut self, selector: PartitionSelector) -> Self {
+        self.partition = Some(selector);
+        self
+    }
+
+    /// Только прочитать таблицу разделов (`--list-partitions`): `prepare`
+    /// не проверяет, занято ли устройство, и не пишет событие `plan`.
+    pub fn list_partitions(mut self, on: bool) -> Self {
+        self.list_partitions = on;
+        self
+    }
+
+    /// Стирать только неразмеченное место между разделами.
+    pub fn unallocated(mut self, on: bool) -> Self {
+        self.unallocated = on;
+        self
+    }
+
+    /// Проверить чтением после финального прохода.
+    pub fn verify(mut self, on: bool) -> Self {
+        self.verify = on;
+        self
+    }
+
+    /// Проверить чтением каждый проход.
+    pub fn verify_each_pass(mut self, on: bool) -> Self {
+        self.verify_each_pass = on;
+        self
+    }
+
+    /// Мастер-сид вместо системного ГСЧ — для воспроизводимых прогонов.
+    pub fn seed(mut self, seed: Seed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Потоков генерации случайных данных (0 — в потоке записи).
+    pub fn gen_threads(mut self, threads: usize) -> Self {
+        self.gen_threads = Some(threads);
+        self
+    }
+
+    /// Записей в полёте через io_uring в режиме direct (0 — блокирующая запись).
+    pub fn queue_depth(mut self, depth: usize) -> Self {
+        self.queue_depth = Some(depth);
+        self
+    }
+
+    /// Стирать, даже если устройство используется системой.
+    pub fn force(mut self, on: bool) -> Self {
+        self.force = on;
+        self
+    }
+
+    /// Разрешить стирать системный диск.
+    pub fn allow_system_disk(mut self, on: bool) -> Self {
+        self.allow_system_disk = on;
+        self
+    }
+
+    /// Пробный запуск (`--dry-run`): вместо записи — `estimate`; `start`
+    /// в этом режиме возвращает ошибку.
+    pub fn dry_run(mut self, on: bool) -> Self {
+        self.dry_run = on;
+        self
+    }
+
+    /// Пока файл существует, запись стоит на паузе.
+    pub fn pause_file(mut self, path: &str) -> Self {
+        self.pause_file = Some(path.to_string());
+        self
+    }
+
+    /// Продолжить по журналу: план, сид, участки и позиция берутся из него.
+    pub fn resume(mut self, journal: Journal) -> Self {
+        self.resume = Some(journal);
+        self
+    }
+
+    /// Вести журнал во время записи (`--journal`): после сбоя или остановки
+    /// стирание продолжается через `resume`. После успеха журнал удаляется.
+    pub fn checkpoint(mut self, path: &Path) -> Self {
+        self.checkpoint = Some(path.to_path_buf());
+        self
+    }
+
+    /// Куда сохранить журнал, если стирание остановлено, а `checkpoint`
+    /// не задан. Без этого после остановки журнала не будет.
+    pub fn cancel_journal(mut self, path: &Path) -> Self {
+        self.cancel_journal = Some(path.to_path_buf());
+        self
+    }
+
+    /// Записать отчёт (`--report`) после стирания — и после ошибки или
+    /// остановки тоже; рядом кладётся текстовый вид.
+    pub fn report(mut self, path: &Path) -> Self {
+        self.report = Some(path.to_path_buf());
+        self
+    }
+
+    /// Подписать отчёт этим ключом Ed25519.
+    pub fn sign_report(mut self, key: Arc<SigningKey>) -> Self {
+        self.signer = Some(key);
+        self
+    }
+
+    /// Кто стирал — для отчёта; по умолчанию пользователь процесса.
+    pub fn operator(mut self, name: &str) -> Self {
+        self.operator = Some(name.to_string());
+        self
+    }
+
+    /// Схема, из которой получен план, — для отчёта.
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Писать события `--progress=json` этого устройства: план, проходы,
+    /// проверки, ошибку и итог.
+    pub fn events(mut self, events: DeviceEvents) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Флаги остановки и паузы, общие с вызывающим (например, с обработчиками
+    /// сигналов `signals::install`). Без них у каждого запуска свои.
+    pub fn signals(mut self, signals: Arc<Signals>) -> Self {
+        self.signals = Some(signals);
+        self
+    }
+
+    /// Отправлять события прогресса (`ProgressEvent`) в канал: начало и конец
+    /// проходов и проверок, байты, скорость, ETA.
+    pub fn progress_events(mut self, tx: Sender<ProgressEvent>) -> Self {
+        self.progress = Some(tx);
+        self
+    }
+
+    /// Проверить устройство и построить план, ничего не записывая. С `events`
+    /// пишет событие `plan`, а при ошибке — `error` и `result`.
+    pub fn prepare(&self) -> Result<DevicePlan, WipeError> {
+        let result: Result<DevicePlan, WipeError> = self.inspect();
+        if let Some(ev) = &self.events {
+            match &result {
+                Ok(dp) if !self.list_partitions => ev.emit(self.plan_event(dp, ev)),
+                Ok(_) => {}
+                Err(f) => emit_failure(ev, f),
+            }
+        }
+        result
+    }
+
+    fn inspect(&self) -> Result<DevicePlan, WipeError> {
+        let device_path: &str = &self.device_path;
+        let resumed: Option<&Journal> = self.resume.as_ref();
+        let device_size: u64 = get_device_size_bytes(device_path).map_err(|e| {
+            WipeError::from_os(Message::new("wiper.size_failed"), e, WipeError::Size)
+        })?;
+        let fingerprint: String = device_fingerprint(device_path).map_err(|e| {
+            WipeError::from_os(Message::new("wiper.identify_failed"), e, WipeError::Open)
+        })?;
+        if let Some(journal) = resumed
+            && (journal.device_size != device_size || journal.fingerprint != fingerprint)
+        {
+            return Err(WipeError::Invalid(
+                Message::new("wiper.journal_mismatch")
+                    .arg(journal.device_path.clone())
+                    .arg(journal.device_size)
+                    .arg(journal.fingerprint.clone())
+                    .arg(device_path.to_string())
+                    .arg(device_size)
+                    .arg(fingerprint.clone()),
+            ));
+        }
+
+        let bs: BlockSizes = get_block_sizes(device_path).unwrap_or(BlockSizes {
+            logical: 512,
+            physical: 4096,
+        });
+        let table: Option<PartitionTable> =
+            if self.partition.is_some() || self.list_partitions || self.unallocated {
+                let table: PartitionTable =
+                    read_partition_table(device_path, device_size, bs.logical as u64).map_err(
+                        |e| WipeError::io(Message::new("wiper.partition_table_failed").arg(e)),
+                    )?;
+                Some(table)
+            } else {
+                None
+            };
+        let partition: Option<Partition> = match (&self.partition, &table) {
+            (Some(selector), Some(table)) => {
+                Some(table.find(selector).map_err(WipeError::Invalid)?.clone())
+            }
+            _ => None,
+        };
+        let extents: ExtentSet = match resumed {
+            Some(journal) => journal.extents.clone(),
+            None => {
+                // Внутри раздела диапазоны отсчитываются от его начала.
+                let (base, len): (u64, u64) = match &partition {
+                    Some(p) => (p.start, p.len),
+                    None => (0, device_size),
+                };
+                let selected: ExtentSet = ExtentSet::build(len, &self.ranges, &self.excludes)
+                    .map_err(|e| WipeError::Invalid(Message::new("wiper.bad_extents").arg(e)))?
+                    .offset_by(base);
+                match &table {
+                    Some(table) if self.unallocated => {
+                        let gaps: ExtentSet = selected.intersect(&table.unallocated());
+                        if gaps.total() == 0 {
+                            return Err(WipeError::Invalid(Message::new("wiper.no_unallocated")));
+                        }
+                        gaps
+                    }
+                    _ => selected,
+                }
+            }
+        };
+        // Проверяем до обратного отсчёта: после него отменять уже поздно.
+        if !self.allow_system_disk && !self.list_partitions {
+            let system: Vec<Usage> = find_system_disk(device_path).map_err(|e| {
+                WipeError::Refused(Refusal::Unchecked(
+                    Message::new("wiper.system_check_failed").arg(e),
+                ))
+            })?;
+            if !system.is_empty() {
+                return Err(WipeError::Refused(Refusal::SystemDisk(system)));
+            }
+        }
+        // С --force только предупреждаем, поэтому и сбой проверки не мешает.
+        let in_use: Vec<Usage> = match find_usage(device_path, &extents) {
+            _ if self.list_partitions => Vec::new(),
+            Ok(usages) => usages,
+            Err(_) if self.force => Vec::new(),
+            Err(e) => {
+                return Err(WipeError::Refused(Refusal::Unchecked(
+                    Message::new("wiper.usage_check_failed").arg(e),
+                )));
+            }
+        };
+        if !in_use.is_empty() && !self.force {
+            return Err(WipeError::Refused(Refusal::InUse(in_use)));
+        }
+        let plan: PassPlan = match resumed {
+            Some(journal) => journal.plan.clone(),
+            None => self.plan.clone(),
+        };
+        let seed: Seed = match (resumed, self.seed) {
+            (Some(journal), _) => journal.seed,
+            (None, Some(seed)) => seed,
+            (None, None) => Seed::from_os_rng()
+                .map_err(|e| WipeError::Rng(Message::new("wiper.rng_failed").arg(e)))?,
+        };
+        let (first_pass, first_offset): (usize, u64) = match resumed {
+            Some(journal) => (journal.pass, journal.offset),
+            None => (1, 0),
+        };
+        Ok(DevicePlan {
+            device_path: device_path.to_string(),
+            device_size,
+            fingerprint,
+            bs,
+            buf_size: choose_buffer_size(bs, self.buf_size),
+            table,
+            partition,
+            extents,
+            plan,
+            seed,
+            first_pass,
+            first_offset,
+            identity: device_identity(device_path),
+            in_use,
+        })
+    }
+
+    /// Событие `plan`: что и как будет стёрто, до подтверждения.
+    fn plan_event(&self, dp: &DevicePlan, ev: &DeviceEvents) -> Json {
+        let passes: Vec<PassData> = dp.plan.resolve(&dp.seed);
+        let kinds: Vec<String> = dp
+            .plan
+            .passes()
+            .iter()
+            .zip(&passes)
+            .enumerate()
+            .map(|(i, (kind, data))| {
+                format!(
+                    "{{\"pass\":{},\"kind\":{},\"describe\":{}}}",
+                    i + 1,
+                    quote(&kind.spec()),
+                    quote(&neutral(&data.describe()))
+                )
+            })
+            .collect();
+        let extents: Vec<String> = dp
+            .extents
+            .extents()
+            .iter()
+            .map(|e| format!("[{},{}]", e.start, e.len))
+            .collect();
+        let identity: String = match &dp.identity {
+            Some(id) => {
+                let opt = |v: &Option<String>| v.as_deref().map_or("null".to_string(), quote);
+                format!(
+                    "{{\"name\":{},\"vendor\":{},\"model\":{},\"serial\":{},\"transport\":{}}}",
+                    quote(&id.name),
+                    opt(&id.vendor),
+                    opt(&id.model),
+                    opt(&id.serial),
+                    opt(&id.transport)
+                )
+            }
+            None => "null".to_string(),
+        };
+        ev.event("plan")
+            .num("device_size", dp.device_size)
+            .num("pass_bytes", dp.extents.total())
+            .num("total_passes", passes.len() as u64)
+            .str("plan", &dp.plan.spec())
+            .raw("passes", &format!("[{}]", kinds.join(",")))
+            .raw("extents", &format!("[{}]", extents.join(",")))
+            .str("mode", self.mode.label())
+            .num("buf_size", dp.buf_size as u64)
+            .num("first_pass", dp.first_pass as u64)
+            .num("first_offset", dp.first_offset)
+            .bool("verify", self.verify)
+            .bool("verify_each_pass", self.verify_each_pass)
+            .bool("dry_run", self.dry_run)
+            .raw("identity", &identity)
+    }
+
+    /// Пробный запуск: проходы в байтах и скорость пробного чтения начала
+    /// стираемых участков. Устройство на запись не открывается. С `events`
+    /// пишет `estimate` и `result` со статусом `dry_run`.
+    pub fn estimate(&self, dp: &DevicePlan) -> Result<DryRun, WipeError> {
+        let result: Result<DryRun, WipeError> = self.probe(dp);
+        if let Some(ev) = &self.events {
+            match &result {
+                Ok(dry) => {
+                    let speed: Option<f64> = dry.probe.speed();
+                    ev.emit(
+                        ev.event("estimate")
+                            .num("write_bytes", dry.estimate.write_bytes())
+                            .num("offload_bytes", dry.estimate.offload_bytes())
+                            .num("verify_bytes", dry.estimate.verify_bytes())
+                            .float("read_rate_bps", speed.unwrap_or(f64::NAN))
+                            .secs("eta_s", speed.and_then(|s| dry.estimate.duration(s))),
+                    );
+                    ev.result("dry_run", 0);
+                }
+                Err(f) => emit_failure(ev, f),
+            }
+        }
+        result
+    }
+
+    fn probe(&self, dp: &DevicePlan) -> Result<DryRun, WipeError> {
+        let estimate = WipeEstimate::new(
+            &dp.plan.resolve(&dp.seed),
+            &dp.extents,
+            &EstimateOptions {
+                first_pass: dp.first_pass,
+                first_offset: dp.first_offset,
+                verify: self.verify,
+                verify_each_pass: self.verify_each_pass,
+                target_is_file: is_file(&dp.device_path),
+            },
+        );
+        let mut buffers = Buffers::new(dp.buf_size, self.mode.is_direct(), dp.bs.sector() as usize)
+            .map_err(|e| WipeError::io(Message::new("wiper.read_buffer_failed").arg(e)))?;
+        let probe: ReadProbe = probe_read_speed(
+            &dp.device_path,
+            &dp.extents,
+            self.mode,
+            dp.bs.sector() as usize,
+            &mut buffers,
+            DRY_RUN_PROBE_BYTES,
+            DRY_RUN_PROBE_TIME,
+        )
+        .map_err(|e| WipeError::from_os(Message::new("wiper.probe_failed"), e, WipeError::io))?;
+        Ok(DryRun { estimate, probe })
+    }
+
+    /// Выполнить все проходы (и проверки) на одном устройстве.
+    fn wipe(
+        &self,
+        dp: &DevicePlan,
+        mut checkpoint: Option<&mut Checkpointer>,
+        shared: &Arc<SharedProgress>,
+        log: &mut Vec<PassRecord>,
+        signals: &Arc<Signals>,
+    ) -> Result<(), WipeError> {
+        let sector: usize = dp.bs.sector() as usize;
+        let use_direct: bool = self.mode.is_direct();
+        let generators: usize = self.gen_threads.unwrap_or_else(default_generators);
+        let extents: &ExtentSet = &dp.extents;
+        let passes: Vec<PassData> = dp.plan.resolve(&dp.seed);
+        let total_passes: usize = passes.len();
+        let mut progress: ProgressTracker =
+            ProgressTracker::with_sink(total_passes, extents.total(), Box::new(SilentSink));
+        progress.share(Arc::clone(shared));
+        self.attach_sinks(&mut progress);
+        let mut buffers = Buffers::new(dp.buf_size, use_direct, sector)
+            .map_err(|e| WipeError::io(Message::new("wiper.buffer_failed").arg(e)))?
+            .with_generators(generators);
+        #[cfg(all(target_os = "linux", feature = "io-uring"))]
+        if use_direct {
+            let depth: usize = self.queue_depth.unwrap_or(DEFAULT_QUEUE_DEPTH);
+            if depth > 0
+                && let Err(e) = buffers.enable_uring(depth)
+            {
+                progress.note(&crate::i18n::trf("wiper.uring_unavailable", &[&e]));
+            }
+        }
+        let mut main_handle: File = open_device(&dp.device_path, self.mode)?;
+        #[cfg(feature = "direct")]
+        let mut tail_handle: Option<File> = if use_direct {
+            Some(open_device(&dp.device_path, SyncMode::Fast)?)
+        } else {
+            None
+        };
+        let mut control = Control::new(Arc::clone(signals), self.pause_file.as_deref());
+
+        for (pass_idx, data) in passes.iter().enumerate().skip(dp.first_pass - 1) {
+            let pass_no: usize = pass_idx + 1;
+            let is_final: bool = pass_no == total_passes;
+            let start: u64 = if pass_no == dp.first_pass {
+                dp.first_offset
+            } else {
+                0
+            };
+            if let Err(e) = control.check(start)
+                && let Some(stop) = signals::stopped(&e)
+            {
+                return Err(WipeError::Cancelled(Interruption {
+                    signal: stop.signal,
+                    pass: pass_no,
+                    offset: start,
+                }));
+            }
+            if start > 0 {
+                progress.resume(pass_no, extents.bytes_before(start));
+            } else {
+                progress.start_pass(pass_no);
+            }
+            if let Some(ev) = &self.events {
+                ev.emit(
+                    ev.event("pass_start")
+                        .num("pass", pass_no as u64)
+                        .num("total_passes", total_passes as u64)
+                        .str("describe", &neutral(&data.describe()))
+                        .num("start_offset", start),
+                );
+            }
+            let pass_started = Instant::now();
+            let pass_started_at: SystemTime = SystemTime::now();
+            if let Some(cp) = checkpoint.as_deref_mut() {
+                cp.start_pass(pass_no, start).map_err(|e| WipeError::Io {
+                    pass: Some(pass_no),
+                    offset: Some(start),
+                    message: Message::new("wiper.journal_failed")
+                        .arg(cp.path().display().to_string())
+                        .arg(e),
+                })?;
+            }
+            let tail_ref: Option<&mut File> = {
+                #[cfg(feature = "direct")]
+                {
+                    tail_handle.as_mut()
+                }
+                #[cfg(not(feature = "direct"))]
+                {
+                    None
+                }
+            };
+            let mut target = PassTarget {
+                file: &mut main_handle,
+                tail_handle: tail_ref,
+                dev_path: &dp.device_path,
+                extents,
+                sector,
+                durable: self.mode.is_durable(),
+            };
+            // Докуда запись подтверждена: при ошибке следующий чанк — с этого байта.
+            let confirmed: Cell<u64> = Cell::new(start);
+            let mut hook =
+                |f: &File, done: u64, progress: &mut ProgressTracker| -> io::Result<()> {
+                    confirmed.set(done);
+                    control.between_chunks(f, done, progress)?;
+                    match checkpoint.as_deref_mut() {
+                        Some(cp) => cp.after_chunk(f, done),
+                        None => Ok(()),
+                    }
+                };
+            run_pass(
+                &mut target,
+                data,
+                &mut progress,
+                &mut buffers,
+                start,
+                Some(&mut hook),
+            )
+            .map_err(|e| match signals::stopped(&e) {
+                Some(stop) => WipeError::Cancelled(Interruption {
+                    signal: stop.signal,
+                    pass: pass_no,
+                    offset: stop.offset,
+                }),
+                None => WipeError::Io {
+                    pass: Some(pass_no),
+                    offset: Some(confirmed.get()),
+                    message: Message::new("wiper.write_failed")
+                        .arg(pass_no)
+                        .arg(confirmed.get())
+                        .arg(e),
+                },
+            })?;
+            let pass_bytes: u64 = extents.total() - extents.bytes_before(start);
+            if let Some(ev) = &self.events {
+                ev.emit(
+                    ev.event("pass_end")
+                        .num("pass", pass_no as u64)
+                        .num("bytes", pass_bytes)
+                        .secs("elapsed_s", Some(pass_started.elapsed())),
+                );
+            }
+            log.push(PassRecord {
+                pass_no,
+                kind: dp.plan.passes()[pass_idx].spec(),
+                describe: data.describe(),
+                started: pass_started_at,
+                finished: SystemTime::now(),
+                bytes: pass_bytes,
+                verify: None,
+            });
+            if self.verify_each_pass || (self.verify && is_final) {
+                let outcome =
+                    self.verify_pass(dp, &mut buffers, data, pass_no, shared, &mut control);
+                let verified: Option<VerifyOutcome> = match &outcome {
+                    Ok(v) => Some(v.clone()),
+                    Err(WipeError::Verify(report)) => Some(VerifyOutcome::Failed {
+                        bytes_checked: report.bytes_checked,
+                        mismatched_bytes: report.mismatched_bytes,
+                    }),
+                    Err(_) => None,
+                };
+                if let (Some(tx), Some(outcome)) = (&self.progress, &verified) {
+                    let _ = tx.send(ProgressEvent::Verified {
+                        pass: pass_no,
+                        outcome: outcome.clone(),
+                    });
+                }
+                log.last_mut().expect("запись прохода").verify = verified;
+                outcome?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Приёмники прогресса сверх общего счётчика: `--progress=json` и канал.
+    fn attach_sinks(&self, progress: &mut ProgressTracker) {
+        if let Some(ev) = &self.events {
+            progress.add_sink(Box::new(JsonSink::new(ev.clone())));
+        }
+        if let Some(tx) = &self.progress {
+            progress.add_sink(Box::new(ChannelSink::new(tx.clone())));
+        }
+    }
+
+    /// Перечитать стёртые участки после прохода и сверить с записанным.
+    fn verify_pass(
+        &self,
+        dp: &DevicePlan,
+        buffers: &mut Buffers,
+        data: &PassData,
+        pass_no: usize,
+        shared: &Arc<SharedProgress>,
+        control: &mut Control,
+    ) -> Result<VerifyOutcome, WipeError> {
+        if let PassData::Offload(kind) = data
+            && !kind.leaves_zeros_on_device()
+            && !is_file(&dp.device_path)
+        {
+            if let Some(ev) = &self.events {
+                ev.emit(
+                    ev.event("verify_result")
+                        .num("pass", pass_no as u64)
+                        .raw("ok", "null")
+                        .bool("skipped", true),
+                );
+            }
+            return Ok(VerifyOutcome::Skipped);
+        }
+        let mut verify_progress: ProgressTracker =
+            ProgressTracker::with_sink(dp.plan.len(), dp.extents.total(), Box::new(SilentSink));
+        self.attach_sinks(&mut verify_progress);
+        verify_progress.start_verify(pass_no);
+        verify_progress.share(Arc::clone(shared));
+        let report: VerifyReport = verify_extents(
+            &dp.device_path,
+            &dp.extents,
+            self.mode,
+            dp.bs.sector() as usize,
+            buffers,
+            &mut verify_progress,
+            data,
+            Some(control),
+        )
+        .map_err(|e| match signals::stopped(&e) {
+            // Проход записан целиком — продолжать со следующего.
+            Some(stop) => WipeError::Cancelled(Interruption {
+                signal: stop.signal,
+                pass: pass_no + 1,
+                offset: 0,
+            }),
+            None => WipeError::Io {
+                pass: Some(pass_no),
+                offset: failed_read_offset(&e),
+                message: Message::new("wiper.verify_read_failed").arg(pass_no).arg(e),
+            },
+        })?;
+        if let Some(ev) = &self.events {
+            let ranges: Vec<String> = report
+                .mismatches
+                .iter()
+                .map(|m| format!("{{\"offset\":{},\"len\":{}}}", m.offset, m.len))
+                .collect();
+            ev.emit(
+                ev.event("verify_result")
+                    .num("pass", pass_no as u64)
+                    .bool("ok", report.is_ok())
+                    .bool("skipped", false)
+                    .num("bytes_checked", report.bytes_checked)
+                    .num("mismatched_bytes", report.mismatched_bytes)
+                    .raw("mismatches", &format!("[{}]", ranges.join(",")))
+                    .bool("truncated", report.truncated),
+            );
+        }
+        if !report.is_ok() {
+            return Err(WipeError::Verify(report));
+        }
+        Ok(VerifyOutcome::Passed {
+            bytes_checked: report.bytes_checked,
+        })
+    }
+
+    /// Подготовить устройство и запустить стирание в фоновом потоке.
+    /// Ошибки подготовки (занято, системный диск, участки) возвращаются сразу.
+    pub fn start(self) -> Result<WipeJob, WipeError> {
+        let dp: DevicePlan = self.prepare()?;
+        self.start_planned(dp)
+    }
+
+    /// Запустить стирание по плану, уже полученному из `prepare` (например,
+    /// после подтверждения пользователем).
+    pub fn start_planned(self, dp: DevicePlan) -> Result<WipeJob, WipeError> {
+        if self.dry_run {
+            return Err(WipeError::Invalid(Message::new("wiper.dry_run_start")));
+        }
+        let progress: Arc<SharedProgress> = Arc::new(SharedProgress::default());
+        let signals: Arc<Signals> = self.signals.clone().unwrap_or_default();
+        let total_passes: usize = dp.plan.len();
+        let pass_bytes: u64 = dp.extents.total();
+        let device_path: String = dp.device_path.clone();
+        let worker_progress: Arc<SharedProgress> = Arc::clone(&progress);
+        let worker_signals: Arc<Signals> = Arc::clone(&signals);
+        let handle: JoinHandle<WipeOutcome> =
+            thread::spawn(move || self.run(dp, &worker_progress, &worker_signals));
+        Ok(WipeJob {
+            device_path,
+            total_passes,
+            pass_bytes,
+            progress,
+            signals,
+            handle,
+        })
+    }
+
+    /// Тело рабочего потока: проходы, затем отчёт, журнал и событие `result`.
+    fn run(
+        self,
+        dp: DevicePlan,
+        progress: &Arc<SharedProgress>,
+        signals: &Arc<Signals>,
+    ) -> WipeOutcome {
+        let started_at = Instant::now();
+        let started: SystemTime = SystemTime::now();
+        let mut passes: Vec<PassRecord> = Vec::new();
+        let mut checkpoint: Option<Checkpointer> = self
+            .checkpoint
+            .clone()
+            .map(|path| Checkpointer::new(path, dp.journal()));
+        let result: Result<(), WipeError> =
+            self.wipe(&dp, checkpoint.as_mut(), progress, &mut passes, signals);
+        let report: Option<Result<SavedReport, Message>> = self
+            .report
+            .as_ref()
+            .map(|path| self.save_report(path, &self.build_report(&dp, &passes, started, &result)));
+        let journal: Option<JournalOutcome> = self.settle_journal(&dp, checkpoint, &result);
+        let outcome = WipeOutcome {
+            device_path: dp.device_path.clone(),
+            result: result.map(|()| WipeSummary {
+                device_path: dp.device_path,
+                device_size: dp.device_size,
+                fingerprint: dp.fingerprint,
+                plan: dp.plan,
+                extents: dp.extents,
+                passes,
+                started,
+                elapsed: started_at.elapsed(),
+            }),
+            report,
+            journal,
+        };
+        if let Some(ev) = &self.events {
+            match &outcome.result {
+                Ok(_) => ev.result("ok", outcome.exit_code()),
+                Err(f) => emit_failure(ev, f),
+            }
+        }
+        outcome
+    }
+
+    /// Отчёт по итогам стирания одного устройства.
+    fn build_report(
+        &self,
+        dp: &DevicePlan,
+        passes: &[PassRecord],
+        started: SystemTime,
+        outcome: &Result<(), WipeError>,
+    ) -> Report {
+        let (status, message): (&str, Option<Message>) = match outcome {
+            Ok(()) => ("ok", None),
+            Err(f) => (f.status(), Some(Message::wrap(f.clone()))),
+        };
+        Report {
+            operator: self.operator.clone().or_else(default_operator),
+            host: hostname(),
+            device_path: dp.device_path.clone(),
+            device_size: dp.device_size,
+            fingerprint: dp.fingerprint.clone(),
+            identity: dp.identity.clone(),
+            is_file: is_file(&dp.device_path),
+            plan: dp.plan.spec(),
+            scheme: self.scheme.map(|s| s.name().to_string()),
+            mode: self.mode.label().to_string(),
+            extents: dp
+                .extents
+                .extents()
+                .iter()
+                .map(|e| (e.start, e.len))
+                .collect(),
+            has_discard: dp
+                .plan
+                .resolve(&dp.seed)
+                .iter()
+                .any(|d| matches!(d, PassData::Offload(k) if !k.leaves_zeros_on_device())),
+            verify: self.verify,
+            verify_each_pass: self.verify_each_pass,
+            resumed_from: (dp.first_pass > 1 || dp.first_offset > 0)
+                .then_some((dp.first_pass, dp.first_offset)),
+            passes: passes.to_vec(),
+            started,
+            finished: SystemTime::now(),
+            status: status.to_string(),
+            message,
+        }
+    }
+
+    /// Записать отчёт; ошибка записи не отменяет стирания.
+    fn save_report(&self, path: &Path, report: &Report) -> Result<SavedReport, Message> {
+        match report::write(report, path, self.signer.as_deref()) {
+            Ok(text) => Ok(SavedReport {
+                path: path.to_path_buf(),
+                text,
+                signed: self.signer.is_some(),
+            }),
+            Err(e) => Err(Message::new("wiper.report_failed")
+                .arg(path.display().to_string())
+                .arg(e)),
+        }
+    }
+
+    /// Журнал после стирания: удалить после успеха, сохранить с точки
+    /// остановки (в `checkpoint` или `cancel_journal`), оставить после сбоя
+    /// записи. Если при остановке записаны все проходы, журнал не нужен.
+    fn settle_journal(
+        &self,
+        dp: &DevicePlan,
+        checkpoint: Option<Checkpointer>,
+        result: &Result<(), WipeError>,
+    ) -> Option<JournalOutcome> {
+        let remove = |cp: Checkpointer| match cp.finish() {
+            Ok(()) => JournalOutcome::Done,
+            Err(e) => JournalOutcome::Failed(Message::new("wiper.journal_remove_failed").arg(e)),
+        };
+        match result {
+            Ok(()) => checkpoint.map(remove),
+            Err(WipeError::Cancelled(i)) if i.pass > dp.plan.len() => {
+                Some(checkpoint.map_or(JournalOutcome::Done, remove))
+            }
+            Err(WipeError::Cancelled(i)) => {
+                let mut cp: Checkpointer = checkpoint.or_else(|| {
+                    self.cancel_journal
+                        .clone()
+                        .map(|path| Checkpointer::new(path, dp.journal()))
+                })?;
+                Some(match cp.start_pass(i.pass, i.offset) {
+                    Ok(()) => JournalOutcome::Saved(cp.path().to_path_buf()),
+                    Err(e) => JournalOutcome::Failed(
+                        Message::new("wiper.journal_write_failed")
+                            .arg(cp.path().display().to_string())
+                            .arg(e),
+                    ),
+                })
+            }
+            Err(WipeError::Io { pass: Some(_), .. }) => {
+                checkpoint.map(|cp| JournalOutcome::Saved(cp.path().to_path_buf()))
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+/// Событие `error` и итог `result` по устройству.
+fn emit_failure(ev: &DeviceEvents, failure: &WipeError) {
+    ev.emit(failure.json_fields(ev.event("error")));
+    ev.result(failure.status(), failure.exit_code());
+}
+
+fn is_file(device_path: &str) -> bool {
+    std::fs::metadata(device_path).is_ok_and(|m| m.is_file())
+}
+
+/// Что известно об устройстве перед записью: размер, участки, план и сид.
+#[derive(Clone, Debug)]
+pub struct DevicePlan {
+    device_path: String,
+    device_size: u64,
+    fingerprint: String,
+    bs: BlockSizes,
+    buf_size: usize,
+    table: Option<PartitionTable>,
+    partition: Option<Partition>,
+    extents: ExtentSet,
+    plan: PassPlan,
+    seed: Seed,
+    first_pass: usize,
+    first_offset: u64,
+    identity: Option<DeviceIdentity>,
+    in_use: Vec<Usage>,
+}
+
+impl DevicePlan {
+    pub fn device_path(&self) -> &str {
+        &self.device_path
+    }
+
+    pub fn device_size(&self) -> u64 {
+        self.device_size
+    }
+
+    /// Отпечаток устройства для сверки журнала (`journal::device_fingerprint`).
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// Логический и физический размер блока.
+    pub fn block_sizes(&self) -> BlockSizes {
+        self.bs
+    }
+
+    /// Размер буфера записи в байтах.
+    pub fn buf_size(&self) -> usize {
+        self.buf_size
+    }
+
+    /// Таблица разделов; читается только для раздела, неразмеченного места
+    /// и `list_partitions`.
+    pub fn table(&self) -> Option<&PartitionTable> {
+        self.table.as_ref()
+    }
+
+    /// Выбранный раздел (`Wiper::partition`).
+    pub fn partition(&self) -> Option<&Partition> {
+        self.partition.as_ref()
+    }
+
+    /// Стираемые участки в байтах от начала устройства.
+    pub fn extents(&self) -> &ExtentSet {
+        &self.extents
+    }
+
+    pub fn plan(&self) -> &PassPlan {
+        &self.plan
+    }
+
+    /// Мастер-сид: заданный, из журнала или из системного ГСЧ.
+    pub fn seed(&self) -> Seed {
+        self.seed
+    }
+
+    /// С какого прохода (с 1) и байта начнётся запись; не `(1, 0)` — только
+    /// при продолжении по журналу.
+    pub fn first_pass(&self) -> usize {
+        self.first_pass
+    }
+
+    pub fn first_offset(&self) -> u64 {
+        self.first_offset
+    }
+
+    /// Производитель, модель, серийный номер и разделы; для файла — `None`.
+    pub fn identity(&self) -> Option<&DeviceIdentity> {
+        self.identity.as_ref()
+    }
+
+    /// Найденные использования устройства; непусто только с `force`.
+    pub fn in_use(&self) -> &[Usage] {
+        &self.in_use
+    }
+
+    /// Журнал для стирания по плану `dp` с его начальной точки.
+    pub fn journal(&self) -> Journal {
+        Journal {
+            device_path: self.device_path.clone(),
+            device_size: self.device_size,
+            fingerprint: self.fingerprint.clone(),
+            plan: self.plan.clone(),
+            extents: self.extents.clone(),
+            seed: self.seed,
+            pass: self.first_pass,
+            offset: self.first_offset,
+        }
+    }
+}
+
+fn open_device(device_path: &str, mode: SyncMode) -> Result<File, WipeError> {
+    open_device_writable(device_path, mode)
+        .map_err(|e| WipeError::from_os(Message::new("wiper.open_failed"), e, WipeError::Open))
+}
+
+/// Итог пробного запуска (`Wiper::estimate`).
+#[derive(Clone, Debug)]
+pub struct DryRun {
+    /// Сколько будет записано и проверено по проходам.
+    pub estimate: WipeEstimate,
+    /// Пробное чтение начала стираемых участков — для оценки времени.
+    pub probe: ReadProbe,
+}
+
+/// Итог успешного стирания.
+#[derive(Clone, Debug)]
+pub struct WipeSummary {
+    pub device_path: String,
+    pub device_size: u64,
+    pub fingerprint: String,
+    pub plan: PassPlan,
+    pub extents: ExtentSet,
+    /// Проходы, выполненные этим запуском (при возобновлении — не все).
+    pub passes: Vec<PassRecord>,
+    pub started: SystemTime,
+    pub elapsed: Duration,
+}
+
+/// Записанный отчёт.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedReport {
+    pub path: PathBuf,
+    /// Текстовый вид рядом с JSON.
+    pub text: PathBuf,
+    pub signed: bool,
+}
+
+/// Что стало с журналом после стирания.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalOutcome {
+    /// Журнал сохранён с точки остановки или оставлен после сбоя записи:
+    /// стирание продолжается через `Wiper::resume`.
+    Saved(PathBuf),
+    /// Продолжать нечего: всё записано, журнал удалён.
+    Done,
+    /// Журнал не удалось записать или удалить.
+    Failed(Message),
+}
+
+/// Чем закончилось фоновое стирание (`WipeJob::finish`).
+#[derive(Debug)]
+pub struct WipeOutcome {
+    pub device_path: String,
+    pub result: Result<WipeSummary, WipeError>,
+    /// Отчёт, если задан `Wiper::report`; ошибка записи не отменяет стирания.
+    pub report: Option<Result<SavedReport, Message>>,
+    /// Журнал, если он вёлся или сохранён при остановке.
+    pub journal: Option<JournalOutcome>,
+}
+
+impl WipeOutcome {
+    /// Код выхода CLI: код ошибки стирания, 1 — если стёрто, но отчёт
+    /// записать не удалось, иначе 0.
+    pub fn exit_code(&self) -> i32 {
+        match &self.result {
+            Err(f) => f.exit_code(),
+            Ok(_) if matches!(self.report, Some(Err(_))) => EXIT_FAILURE,
+            Ok(_) => 0,
+        }
+    }
+}
+
+/// Состояние фонового стирания для опроса.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JobProgress {
+    /// Текущий проход с 1; 0 — запись ещё не началась.
+    pub pass: usize,
+    pub total_passes: usize,
+    /// Идёт проверка чтением после прохода.
+    pub verifying: bool,
+    pub pass_done: u64,
+    pub pass_bytes: u64,
+    pub total_done: u64,
+    pub total_bytes: u64,
+}
+
+/// Стирание в фоновом потоке, запущенное `Wiper::start`.
+pub struct WipeJob {
+    device_path: String,
+    total_passes: usize,
+    pass_bytes: u64,
+    progress: Arc<SharedProgress>,
+    signals: Arc<Signals>,
+    handle: JoinHandle<WipeOutcome>,
+}
+
+impl WipeJob {
+    pub fn device_path(&self) -> &str {
+        &self.device_path
+    }
+
+    pub fn progress(&self) -> JobProgress {
+        let snap = self.progress.snapshot();
+        JobProgress {
+            pass: snap.pass,
+            total_passes: self.total_passes,
+            verifying: snap.verifying,
+            pass_done: snap.pass_done,
+            pass_bytes: self.pass_bytes,
+            total_done: snap.total_done,
+            total_bytes: self.pass_bytes.saturating_mul(self.total_passes as u64),
+        }
+    }
+
+    /// Счётчик прогресса, который обновляет поток записи, — для общей
+    /// таблицы `multi::Board`.
+    pub fn shared_progress(&self) -> Arc<SharedProgress> {
+        Arc::clone(&self.progress)
+    }
+
+    /// Попросить остановиться на границе чанка. `wait` вернёт
+    /// `WipeError::Cancelled` с `signal: None` и позицией остановки.
+    pub fn cancel(&self) {
+        self.signals.cancel();
+    }
+
+    /// Приостановить запись на границе чанка; всё записанное сбрасывается
+    /// на носитель. Отмена работает и на паузе.
+    pub fn pause(&self) {
+        self.signals.pause();
+    }
+
+    /// Продолжить после `pause`.
+    pub fn resume(&self) {
+        self.signals.resume();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Дождаться конца стирания: результат, отчёт и журнал.
+    pub fn finish(self) -> WipeOutcome {
+        let WipeJob {
+            device_path,
+            handle,
+            ..
+        } = self;
+        handle.join().unwrap_or_else(|_| WipeOutcome {
+            device_path,
+            result: Err(WipeError::io(Message::new("wiper.thread_panicked"))),
+            report: None,
+            journal: None,
+        })
+    }
+
+    /// Дождаться конца стирания; только результат.
+    pub fn wait(self) -> Result<WipeSummary, WipeError> {
+        self.finish().result
+    }
+}
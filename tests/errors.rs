@@ -1,21 +1,29 @@
 #![cfg(feature = "test-support")]
 
 // tests/errors.rs
+#[allow(dead_code)]
 #[path = "../src/dev.rs"]
 mod dev;
+#[allow(dead_code)]
+#[path = "../src/i18n.rs"]
+mod i18n;
 
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
 
 //
 // -------- CLI argument error cases (process::exit) --------
 // These are run as subprocesses so that exit(1) doesn't kill the test runner.
 //
 
+// Проверки ниже сверяют русский текст: язык задаём явно, а не из окружения.
+fn destroyer() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_destroyer"));
+    cmd.env("LC_ALL", "ru_RU.UTF-8");
+    cmd
+}
+
 fn run(args: &[&str]) -> Output {
-    Command::new(env!("CARGO_BIN_EXE_destroyer"))
-        .args(args)
-        .output()
-        .expect("binary build")
+    destroyer().args(args).output().expect("binary build")
 }
 
 #[test]
@@ -68,6 +76,400 @@ fn cli_negative_buf_fails() {
     );
 }
 
+#[test]
+fn cli_unknown_scheme_fails() {
+    let out = run(&["/dev/null", "--scheme", "wat"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("dod3"), "stderr: {stderr}");
+}
+
+#[test]
+fn cli_scheme_with_pass_count_fails() {
+    let out = run(&["/dev/null", "3", "--scheme", "dod3"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+}
+
+#[test]
+fn cli_bad_passes_spec_fails() {
+    let out = run(&["/dev/null", "--passes-spec", "random,0xZZ"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("0xZZ"), "stderr: {stderr}");
+}
+
+#[test]
+fn cli_two_partition_selectors_fail() {
+    let out = run(&["/dev/null", "--partition", "1", "--partition-label", "DATA"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let out = run(&["/dev/null", "--partition", "1", "--unallocated"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let out = run(&["/dev/null", "--partition", "0"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+}
+
+#[test]
+fn cli_final_with_pass_count_and_spec() {
+    let path = std::env::temp_dir().join(format!("destroyer-final-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0u8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+    let plan_of = |args: &[&str]| {
+        let mut full: Vec<&str> = vec![image, "--dry-run"];
+        full.extend(args);
+        let out = run(&full);
+        assert!(out.status.success(), "{args:?}: {out:?}");
+        let stdout = String::from_utf8_lossy(&out.stdout).into_owned();
+        stdout
+            .lines()
+            .find_map(|l| l.strip_prefix("План: ").map(str::to_string))
+            .unwrap_or_else(|| panic!("{args:?}: {stdout}"))
+    };
+
+    // С числом проходов --final заменяет последний проход.
+    assert_eq!(plan_of(&["3"]), "random*2,zero");
+    assert_eq!(plan_of(&["3", "--final", "0x55"]), "random*2,0x55");
+    assert_eq!(plan_of(&["3", "--final", "none"]), "random*3");
+    // С --passes-spec --final дописывается в конец, а none отклоняется.
+    let spec: [&str; 2] = ["--passes-spec", "random,0xAA"];
+    assert_eq!(plan_of(&spec), "random,0xAA");
+    assert_eq!(
+        plan_of(&[spec[0], spec[1], "--final", "zero"]),
+        "random,0xAA,zero"
+    );
+    let out = run(&[image, spec[0], spec[1], "--final", "none", "--dry-run"]);
+    assert_eq!(out.status.code(), Some(1), "{out:?}");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--final none"), "stderr: {stderr}");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn cli_resume_rejects_plan_arguments() {
+    for extra in [["--final", "zero"], ["--final", "none"], ["--seed", "1"]] {
+        let mut args: Vec<&str> = vec!["--resume", "/tmp/destroyer-missing.journal"];
+        args.extend(extra);
+        let out = run(&args);
+        assert!(
+            !out.status.success(),
+            "expected failure, got {:?}",
+            out.status
+        );
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("При --resume"), "{extra:?}: {stderr}");
+    }
+}
+
+#[test]
+fn cli_bad_range_unit_fails() {
+    let out = run(&["/dev/null", "--range", "0:64X"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--range"), "stderr: {stderr}");
+}
+
+#[test]
+fn cli_from_end_range_overflow_fails() {
+    for flag in ["--range", "--exclude"] {
+        let out = run(&["/dev/null", "1", flag, "-4096:18446744073709551615"]);
+        assert_eq!(out.status.code(), Some(1), "{flag}: {out:?}");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains(flag), "{flag}: {stderr}");
+        assert!(!stderr.contains("panicked"), "{flag}: {stderr}");
+    }
+}
+
+#[test]
+fn cli_journal_with_several_devices_fails() {
+    let out = run(&["/dev/null", "/dev/zero", "--journal", "/tmp/x.journal"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("одним устройством"), "stderr: {stderr}");
+    let out = run(&["/dev/null", "/dev/null"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+}
+
+#[test]
+fn cli_queue_depth_requires_direct_mode() {
+    let out = run(&["/dev/null", "1", "--queue-depth", "8"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+    let out = run(&["/dev/null", "1", "--mode", "direct", "--queue-depth", "-1"]);
+    assert!(
+        !out.status.success(),
+        "expected failure, got {:?}",
+        out.status
+    );
+}
+
+#[test]
+fn cli_non_tty_requires_yes() {
+    let path = std::env::temp_dir().join(format!("destroyer-errors-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0xA5u8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+
+    let out = destroyer()
+        .args([image, "1", "--quiet"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary build");
+    assert_eq!(out.status.code(), Some(1), "{:?}", out.status);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--yes"), "stderr: {stderr}");
+    assert!(std::fs::read(&path).unwrap().iter().all(|&b| b == 0xA5));
+
+    let out = destroyer()
+        .args([image, "1", "--quiet", "--yes"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary build");
+    assert!(out.status.success(), "{:?}", out);
+    assert!(std::fs::read(&path).unwrap().iter().all(|&b| b == 0));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn cli_dry_run_writes_nothing() {
+    let path = std::env::temp_dir().join(format!("destroyer-dry-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0x5Au8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+
+    let out = destroyer()
+        .args([image, "--scheme", "dod3", "--verify", "--dry-run"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("binary build");
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("Всего записать: 196608"),
+        "stdout: {stdout}"
+    );
+    assert!(stdout.contains("Оценка времени"), "stdout: {stdout}");
+    assert!(std::fs::read(&path).unwrap().iter().all(|&b| b == 0x5A));
+
+    let out = run(&[image, "1", "--range", "128K:1K", "--dry-run"]);
+    assert_eq!(out.status.code(), Some(1), "{:?}", out.status);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn cli_lang_selects_message_language() {
+    let stderr_of = |out: Output| String::from_utf8_lossy(&out.stderr).into_owned();
+
+    let out = destroyer()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(["/dev/null", "--wat"])
+        .output()
+        .expect("binary build");
+    assert_eq!(out.status.code(), Some(1));
+    assert!(stderr_of(out).contains("Unknown flag: --wat"));
+
+    // --lang сильнее окружения.
+    let out = run(&["/dev/null", "--wat", "--lang", "zh-CN"]);
+    assert!(stderr_of(out).contains("未知选项：--wat"));
+
+    // Пустая и незнакомая локали пропускаются, берётся следующая переменная.
+    let out = destroyer()
+        .env("LC_ALL", "")
+        .env("LC_MESSAGES", "de_DE.UTF-8")
+        .env("LANG", "en_GB.UTF-8")
+        .args(["/dev/null", "--wat"])
+        .output()
+        .expect("binary build");
+    assert!(stderr_of(out).contains("Unknown flag"));
+
+    let out = run(&["/dev/null", "1", "--lang=xx"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(stderr_of(out).contains("Неизвестный язык --lang: xx"));
+
+    let path = std::env::temp_dir().join(format!("destroyer-lang-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0x5Au8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+    let out = run(&[image, "--scheme", "dod3", "--dry-run", "--lang", "en"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("Total to write: 196608"),
+        "stdout: {stdout}"
+    );
+
+    // Имена событий и поля JSON от языка не зависят.
+    let events = |lang: &str| -> Vec<String> {
+        let out = run(&[image, "1", "--yes", "--progress=json", "--lang", lang]);
+        assert!(out.status.success(), "{:?}", out);
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .map(|line| line.split(",\"").take(3).collect::<Vec<_>>().join(","))
+            .collect()
+    };
+    let ru = events("ru");
+    assert!(
+        ru.iter().any(|e| e.ends_with(r#"event":"result""#)),
+        "{ru:?}"
+    );
+    assert_eq!(events("zh-CN"), ru);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn cli_progress_json_streams_events_on_stdout() {
+    let path = std::env::temp_dir().join(format!("destroyer-json-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0x5Au8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+
+    let out = run(&[image, "2", "--verify", "--yes", "--progress=json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let events: Vec<&str> = stdout
+        .lines()
+        .map(|line| {
+            assert!(
+                line.starts_with(r#"{"schema":"destroyer.progress","v":3,"event":""#)
+                    && line.ends_with('}'),
+                "не событие: {line}"
+            );
+            let rest = &line[r#"{"schema":"destroyer.progress","v":3,"event":""#.len()..];
+            &rest[..rest.find('"').unwrap()]
+        })
+        .collect();
+    assert_eq!(events.first(), Some(&"plan"), "{stdout}");
+    assert_eq!(events.last(), Some(&"result"), "{stdout}");
+    for name in ["pass_start", "progress", "pass_end", "verify_result"] {
+        assert!(events.contains(&name), "нет {name}: {stdout}");
+    }
+    assert_eq!(events.iter().filter(|e| **e == "pass_end").count(), 2);
+    assert!(
+        stdout.contains(r#""status":"ok","exit_code":0"#),
+        "{stdout}"
+    );
+    // Текст для человека ушёл в stderr.
+    assert!(String::from_utf8_lossy(&out.stderr).contains("успешно очищено"));
+
+    let out = run(&[
+        "/nonexistent/destroyer.img",
+        "1",
+        "--yes",
+        "--progress=json",
+    ]);
+    assert_eq!(out.status.code(), Some(3));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains(r#""event":"error","ts":"#)
+            && stdout.contains(r#""kind":"open","exit_code":3,"message":"#),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains(r#""status":"failed","exit_code":3"#),
+        "{stdout}"
+    );
+
+    let out = run(&[image, "--progress-fd", "3"]);
+    assert_eq!(out.status.code(), Some(1));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn cli_progress_json_does_not_depend_on_locale() {
+    let path = std::env::temp_dir().join(format!("destroyer-neutral-{}.img", std::process::id()));
+    std::fs::write(&path, vec![0x5Au8; 64 * 1024]).unwrap();
+    let image: &str = path.to_str().unwrap();
+
+    // Поток событий без меток времени и замеров скорости.
+    let stream = |locale: &str, args: &[&str]| -> Vec<String> {
+        let out = destroyer()
+            .env("LC_ALL", locale)
+            .args(args)
+            .output()
+            .expect("binary build");
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter(|line| line.starts_with('{') && !line.contains(r#""event":"progress""#))
+            .map(|line| {
+                line.split(',')
+                    .filter(|field| {
+                        ![
+                            "\"ts\":",
+                            "\"elapsed_s\":",
+                            "\"eta_s\":",
+                            "\"read_rate_bps\":",
+                        ]
+                        .iter()
+                        .any(|name| field.starts_with(name))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect()
+    };
+    let runs: [&[&str]; 3] = [
+        &[image, "--scheme", "dod3", "--dry-run", "--progress=json"],
+        &[
+            image,
+            "--passes-spec",
+            "zero,random",
+            "--yes",
+            "--progress=json",
+        ],
+        &[
+            "/nonexistent/destroyer.img",
+            "1",
+            "--yes",
+            "--progress=json",
+        ],
+    ];
+    for args in runs {
+        let en = stream("en_US.UTF-8", args);
+        let joined: String = en.join("\n");
+        assert!(joined.is_ascii(), "{joined}");
+        assert!(
+            joined.contains(r#""describe":"zeros""#) || joined.contains(r#""message":"Failed"#),
+            "{joined}"
+        );
+        assert_eq!(stream("ru_RU.UTF-8", args), en, "{args:?}");
+        assert_eq!(stream("zh_CN.UTF-8", args), en, "{args:?}");
+    }
+    let _ = std::fs::remove_file(&path);
+}
+
 //
 // -------- dev helpers error cases --------
 //
@@ -79,3 +481,147 @@ fn cli_negative_buf_fails() {
 //     let res = dev::alloc_aligned(len, align);
 //     assert!(res.is_err(), "align должен быть степенью двойки");
 // }
+
+#[test]
+fn cli_report_is_signed_and_verified() {
+    let dir = std::env::temp_dir();
+    let pid = std::process::id();
+    let image = dir.join(format!("destroyer-report-{pid}.img"));
+    let key = dir.join(format!("destroyer-report-{pid}.key"));
+    let json = dir.join(format!("destroyer-report-{pid}.json"));
+    std::fs::write(&image, vec![0x5Au8; 64 * 1024]).unwrap();
+    std::fs::write(
+        &key,
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60\n",
+    )
+    .unwrap();
+    let (image_s, key_s, json_s) = (
+        image.to_str().unwrap(),
+        key.to_str().unwrap(),
+        json.to_str().unwrap(),
+    );
+
+    // --sign-key без --report — ошибка до начала работы.
+    let out = run(&[image_s, "1", "--yes", "--sign-key", key_s]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--report"));
+
+    let out = run(&[
+        image_s,
+        "1",
+        "--yes",
+        "--verify",
+        "--report",
+        json_s,
+        "--sign-key",
+        key_s,
+        "--operator",
+        "tester",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let doc = std::fs::read_to_string(&json).unwrap();
+    assert!(doc.contains(r#""operator": "tester""#), "{doc}");
+    assert!(doc.contains(r#""algorithm": "ed25519""#), "{doc}");
+    let text = dir.join(format!("destroyer-report-{pid}.txt"));
+    assert!(
+        std::fs::read_to_string(&text)
+            .unwrap()
+            .contains("NIST SP 800-88")
+    );
+
+    // Без --pub-key ключ взят из самого отчёта: подпись верна, но не доверена.
+    let out = run(&["verify-report", json_s]);
+    assert_eq!(out.status.code(), Some(11), "{:?}", out);
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--pub-key"));
+    let marker = r#""public_key": ""#;
+    let start = doc.find(marker).unwrap() + marker.len();
+    let pub_key = dir.join(format!("destroyer-report-{pid}.pub"));
+    std::fs::write(&pub_key, &doc[start..start + 64]).unwrap();
+    let pub_key_s = pub_key.to_str().unwrap();
+    let out = run(&["verify-report", json_s, "--pub-key", pub_key_s]);
+    assert_eq!(out.status.code(), Some(0), "{:?}", out);
+
+    std::fs::write(&json, doc.replacen("tester", "Tester", 1)).unwrap();
+    let out = run(&["verify-report", json_s, "--pub-key", pub_key_s]);
+    assert_eq!(out.status.code(), Some(1), "{:?}", out);
+    assert!(String::from_utf8_lossy(&out.stderr).contains("НЕ верна"));
+
+    for p in [image, key, json, text, pub_key] {
+        let _ = std::fs::remove_file(p);
+    }
+}
+
+#[test]
+fn cli_sigint_while_paused_saves_journal_and_resumes() {
+    use std::io::{BufRead, BufReader};
+
+    let dir = std::env::temp_dir();
+    let pid = std::process::id();
+    let name = format!("destroyer-sigint-{pid}.img");
+    let image = dir.join(&name);
+    let pause = dir.join(format!("destroyer-sigint-{pid}.pause"));
+    let journal = std::path::Path::new("/var/tmp").join(format!("destroyer-{name}.journal"));
+    let journal = if journal.parent().unwrap().is_dir() {
+        journal
+    } else {
+        dir.join(format!("destroyer-{name}.journal"))
+    };
+    std::fs::write(&image, vec![0x5Au8; 4 * 1024 * 1024]).unwrap();
+    std::fs::write(&pause, b"").unwrap();
+    let _ = std::fs::remove_file(&journal);
+
+    // Файл паузы есть с самого начала: запись встаёт после первого чанка.
+    let mut child = destroyer()
+        .args([
+            image.to_str().unwrap(),
+            "2",
+            "--yes",
+            "--quiet",
+            "--buf",
+            "65536",
+            "--pause-file",
+            pause.to_str().unwrap(),
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("binary build");
+    let mut stderr = BufReader::new(child.stderr.take().unwrap());
+    let mut seen = String::new();
+    loop {
+        let mut line = String::new();
+        assert!(stderr.read_line(&mut line).unwrap() > 0, "{seen}");
+        seen.push_str(&line);
+        if line.contains("Пауза на байте") {
+            break;
+        }
+    }
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let mut rest = String::new();
+    std::io::Read::read_to_string(&mut stderr, &mut rest).unwrap();
+    let out = child.wait().unwrap();
+    assert_eq!(out.code(), Some(130), "{seen}{rest}");
+    assert!(rest.contains("Прервано SIGINT"), "{rest}");
+    assert!(rest.contains("проход 1/2"), "{rest}");
+    assert!(rest.contains("участки 0..65536"), "{rest}");
+    assert!(rest.contains("--resume"), "{rest}");
+
+    let text = std::fs::read_to_string(&journal).unwrap();
+    assert!(
+        text.contains("pass=1\n") && text.contains("offset=65536\n"),
+        "{text}"
+    );
+
+    // Продолжение по журналу доводит стирание до конца и удаляет журнал.
+    let out = run(&["--resume", journal.to_str().unwrap(), "--yes", "--verify"]);
+    assert!(out.status.success(), "{:?}", out);
+    assert!(!journal.exists());
+    assert!(std::fs::read(&image).unwrap().iter().all(|&b| b == 0));
+
+    let _ = std::fs::remove_file(&image);
+    let _ = std::fs::remove_file(&pause);
+}